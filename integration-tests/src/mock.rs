@@ -127,6 +127,14 @@ impl pallet_posts::Config for TestRuntime {
     type RuntimeEvent = RuntimeEvent;
     type MaxCommentDepth = MaxCommentDepth;
     type IsPostBlocked = MockModeration;
+    type PostDeletionHandler = Reactions;
+    type PostVisibilityHandler = ();
+    type PostDeposit = frame_support::traits::ConstU64<0>;
+    type MaxPinnedPostsPerSpace = frame_support::traits::ConstU32<3>;
+    type MaxPostRevisions = frame_support::traits::ConstU32<3>;
+    type MaxScheduledPostsPerBlock = frame_support::traits::ConstU32<3>;
+    type MaxPollOptions = frame_support::traits::ConstU32<3>;
+    type BackerStakesProvider = ();
     type WeightInfo = ();
 }
 
@@ -135,10 +143,13 @@ impl pallet_profiles::Config for TestRuntime {
     type SpacePermissionsProvider = Spaces;
     type SpacesProvider = Spaces;
     type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
 }
 
 impl pallet_reactions::Config for TestRuntime {
     type RuntimeEvent = RuntimeEvent;
+    type ReactionDeposit = frame_support::traits::ConstU64<0>;
     type WeightInfo = ();
 }
 
@@ -149,6 +160,8 @@ parameter_types! {
 impl pallet_roles::Config for TestRuntime {
     type RuntimeEvent = RuntimeEvent;
     type MaxUsersToProcessPerDeleteRole = MaxUsersToProcessPerDeleteRole;
+    type Currency = Balances;
+    type RoleDeposit = frame_support::traits::ConstU64<0>;
     type SpacePermissionsProvider = Spaces;
     type SpaceFollows = SpaceFollows;
     type IsAccountBlocked = MockModeration;
@@ -158,6 +171,8 @@ impl pallet_roles::Config for TestRuntime {
 
 impl pallet_space_follows::Config for TestRuntime {
     type RuntimeEvent = RuntimeEvent;
+    type FollowDeposit = frame_support::traits::ConstU64<0>;
+    type InvitationDeposit = frame_support::traits::ConstU64<0>;
     type WeightInfo = pallet_space_follows::weights::SubstrateWeight<TestRuntime>;
 }
 
@@ -180,6 +195,17 @@ impl pallet_spaces::Config for TestRuntime {
     type IsAccountBlocked = MockModeration;
     type IsContentBlocked = MockModeration;
     type MaxSpacesPerAccount = ConstU32<100>;
+    type MaxSubspaceDepth = ConstU32<10>;
+    type DomainsProvider = Domains;
+    type MinHandleLen = ConstU32<5>;
+    type MaxHandleLen = ConstU32<50>;
+    type ProfileManager = Profiles;
+    type CreatorStakingProvider = ();
+    type SpaceDeletionHandler = (Posts, Roles, SpaceFollows);
+    type SpaceSettingsRegistry = Posts;
+    type Currency = Balances;
+    type SpaceDeposit = frame_support::traits::ConstU64<0>;
+    type MaxSpaceCoOwners = frame_support::traits::ConstU32<3>;
     type WeightInfo = ();
 }
 
@@ -13,7 +13,7 @@
 
 use std::sync::Arc;
 
-use subsocial_parachain_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use subsocial_parachain_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Nonce};
 
 use sc_client_api::AuxStore;
 pub use sc_rpc::{DenyUnsafe, SubscriptionTaskExecutor};
@@ -50,7 +50,8 @@ where
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: pallet_creator_staking_rpc::CreatorStakingRuntimeApi<Block, AccountId, Balance>,
 	C::Api: pallet_domains_rpc::DomainsRuntimeApi<Block, Balance>,
-	C::Api: pallet_posts_rpc::PostsRuntimeApi<Block, AccountId>,
+	C::Api: pallet_posts_rpc::PostsRuntimeApi<Block, AccountId, BlockNumber, Balance>,
+	C::Api: pallet_spaces_rpc::SpacesRuntimeApi<Block, AccountId, BlockNumber>,
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
 	C::Api: BlockBuilder<Block>,
 	P: TransactionPool + Sync + Send + 'static,
@@ -59,6 +60,7 @@ where
 	use pallet_creator_staking_rpc::{CreatorStaking, CreatorStakingApiServer};
 	use pallet_domains_rpc::{Domains, DomainsApiServer};
 	use pallet_posts_rpc::{Posts, PostsApiServer};
+	use pallet_spaces_rpc::{Spaces, SpacesApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
 	let mut module = RpcExtension::new(());
@@ -68,7 +70,8 @@ where
 	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
 	module.merge(CreatorStaking::new(client.clone()).into_rpc())?;
 	module.merge(Domains::new(client.clone()).into_rpc())?;
-	module.merge(Posts::new(client).into_rpc())?;
+	module.merge(Posts::new(client.clone()).into_rpc())?;
+	module.merge(Spaces::new(client).into_rpc())?;
 
 	Ok(module)
 }
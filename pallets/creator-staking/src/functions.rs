@@ -8,7 +8,9 @@ use crate::pallet::*;
 use frame_support::{pallet_prelude::*, traits::{Currency, ReservableCurrency, LockableCurrency, WithdrawReasons}};
 use sp_runtime::{traits::{AccountIdConversion, Zero}, Perbill, Saturating};
 use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
-use subsocial_support::traits::{CreatorStakingProvider, SpacePermissionsProvider};
+use subsocial_support::traits::{
+    BackerStakesProvider, CreatorStakingProvider, SpacePermissionsProvider,
+};
 
 impl<T: Config> Pallet<T> {
     /// `Err` if pallet disabled for maintenance, `Ok` otherwise
@@ -491,3 +493,17 @@ impl<T: Config> CreatorStakingProvider<T::AccountId> for Pallet<T> {
         Self::is_creator_active(creator_id)
     }
 }
+
+/// Implementation of `BackerStakesProvider` for `creator-staking` pallet.
+///
+/// This is used in posts pallet to give backers of a creator access to token-gated posts.
+/// Stakes on creators that are no longer active are not taken into account.
+impl<T: Config> BackerStakesProvider<T::AccountId, BalanceOf<T>> for Pallet<T> {
+    fn get_backer_stake(backer: &T::AccountId, creator_id: CreatorId) -> BalanceOf<T> {
+        if !Self::is_creator_active(creator_id) {
+            return Zero::zero()
+        }
+
+        Self::backer_stakes(backer, creator_id).current_stake()
+    }
+}
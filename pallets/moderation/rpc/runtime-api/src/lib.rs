@@ -0,0 +1,32 @@
+// Copyright (C) DAPPFORCE PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
+//
+// Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
+// Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
+
+//! Runtime API definition for moderation pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_runtime::traits::MaybeDisplay;
+use sp_std::vec::Vec;
+
+use pallet_moderation::{EntityId, ModerationRecord, Report};
+use subsocial_support::SpaceId;
+
+sp_api::decl_runtime_apis! {
+    pub trait ModerationApi<AccountId, BlockNumber, Moment, Balance> where
+        AccountId: Codec + MaybeDisplay,
+        BlockNumber: Codec,
+        Moment: Codec,
+        Balance: Codec + MaybeDisplay,
+    {
+        fn get_open_reports(space_id: SpaceId) -> Vec<Report<AccountId, BlockNumber, Moment, Balance>>;
+
+        fn get_moderation_history(
+            entity: EntityId<AccountId>,
+            scope: SpaceId,
+        ) -> Vec<ModerationRecord<AccountId, BlockNumber, Moment>>;
+    }
+}
@@ -0,0 +1,295 @@
+#![cfg(feature = "runtime-benchmarks")]
+// Copyright (C) DAPPFORCE PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
+//
+// Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
+// Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
+
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::{ensure, traits::EnsureOrigin};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, Zero};
+use subsocial_support::Content;
+
+use super::*;
+
+fn auto_hide_settings<T: Config>() -> AutoHideSettingsOf<T> {
+    AutoHideSettings {
+        reporters_threshold: 1,
+        min_reporting_age: Some(Zero::zero()),
+        followers_only: false,
+    }
+}
+
+fn create_dummy_space_and_post<T: Config>(owner: T::AccountId) -> Result<(SpaceId, PostId), &'static str> {
+    let space_id = pallet_spaces::NextSpaceId::<T>::get();
+    let post_id = pallet_posts::NextPostId::<T>::get();
+
+    <T as pallet_spaces::Config>::Currency::make_free_balance_be(
+        &owner,
+        pallet_spaces::types::BalanceOf::<T>::max_value(),
+    );
+
+    ensure!(pallet_spaces::Pallet::<T>::create_space(
+        RawOrigin::Signed(owner.clone()).into(),
+        Content::None,
+        None,
+    ).is_ok(), "Space didn't get created");
+
+    ensure!(pallet_posts::Pallet::<T>::create_post(
+        RawOrigin::Signed(owner).into(),
+        Some(space_id),
+        pallet_posts::PostExtension::RegularPost,
+        Content::None,
+    ).is_ok(), "Post didn't get created");
+
+    Ok((space_id, post_id))
+}
+
+fn add_dummy_appeals<T: Config>(entity: &EntityIdOf<T>, space_id: SpaceId, count: u32) {
+    for i in 0 .. count {
+        let appellant: T::AccountId = account("appellant", i, 0);
+        let appeal_id = NextAppealId::<T>::get();
+        let deposit = T::AppealDeposit::get();
+
+        <T as Config>::Currency::make_free_balance_be(&appellant, BalanceOf::<T>::max_value());
+        let _ = <T as Config>::Currency::reserve(&appellant, deposit);
+
+        AppealById::<T>::insert(appeal_id, Appeal {
+            id: appeal_id,
+            created: new_who_and_when::<T>(appellant.clone()),
+            entity: entity.clone(),
+            scope: space_id,
+            reason: Content::None,
+            deposit,
+        });
+        AppealIdByAccount::<T>::insert((entity.clone(), space_id), appellant, appeal_id);
+        AppealIdsBySpaceId::<T>::mutate(space_id, |appeal_ids| {
+            let _ = appeal_ids.try_push(appeal_id);
+        });
+        NextAppealId::<T>::mutate(|n| *n += 1);
+    }
+}
+
+benchmarks! {
+
+    suggest_entity_status {
+        let owner: T::AccountId = account("owner", 24, 0);
+        let (space_id, post_id) = create_dummy_space_and_post::<T>(owner.clone())?;
+        let entity = EntityId::Post(post_id);
+    }: _(RawOrigin::Signed(owner.clone()), entity.clone(), space_id, Some(EntityStatus::Blocked))
+    verify {
+        ensure!(
+            SuggestedStatusByAccount::<T>::contains_key(space_id, (entity, owner)),
+            "Status wasn't suggested"
+        );
+    }
+
+    update_entity_status {
+        let a in 0 .. T::MaxOpenAppealsPerSpace::get();
+        let owner: T::AccountId = account("owner", 24, 0);
+        let (space_id, post_id) = create_dummy_space_and_post::<T>(owner.clone())?;
+        let entity = EntityId::Post(post_id);
+
+        StatusByEntityInSpace::<T>::insert(space_id, &entity, EntityStatus::Blocked);
+        add_dummy_appeals::<T>(&entity, space_id, a);
+    }: _(RawOrigin::Signed(owner), entity.clone(), space_id, Some(EntityStatus::Allowed), Content::None)
+    verify {
+        ensure!(
+            StatusByEntityInSpace::<T>::get(space_id, entity) == Some(EntityStatus::Allowed),
+            "Status wasn't updated"
+        );
+        ensure!(AppealIdsBySpaceId::<T>::get(space_id).is_empty(), "Appeals weren't closed");
+    }
+
+    report_entity {
+        let owner: T::AccountId = account("owner", 24, 0);
+        let reporter: T::AccountId = account("reporter", 2, 0);
+        let (space_id, post_id) = create_dummy_space_and_post::<T>(owner)?;
+        let entity = EntityId::Post(post_id);
+        let report_id = NextReportId::<T>::get();
+
+        AutoHideSettingsBySpaceId::<T>::insert(space_id, auto_hide_settings::<T>());
+        <T as Config>::Currency::make_free_balance_be(&reporter, BalanceOf::<T>::max_value());
+    }: _(RawOrigin::Signed(reporter), entity, space_id, Content::None)
+    verify {
+        ensure!(ReportById::<T>::contains_key(report_id), "Report wasn't created");
+        ensure!(AutoHiddenPostScope::<T>::contains_key(post_id), "Post wasn't auto-hidden");
+    }
+
+    resolve_report {
+        let owner: T::AccountId = account("owner", 24, 0);
+        let reporter: T::AccountId = account("reporter", 2, 0);
+        let (space_id, post_id) = create_dummy_space_and_post::<T>(owner.clone())?;
+        let report_id = NextReportId::<T>::get();
+
+        <T as Config>::Currency::make_free_balance_be(&reporter, BalanceOf::<T>::max_value());
+        Pallet::<T>::report_entity(
+            RawOrigin::Signed(reporter).into(),
+            EntityId::Post(post_id),
+            space_id,
+            Content::None,
+        )?;
+    }: _(RawOrigin::Signed(owner), report_id, true)
+    verify {
+        ensure!(!ReportById::<T>::contains_key(report_id), "Report wasn't resolved");
+    }
+
+    update_global_entity_status {
+        let origin = T::GlobalModerationOrigin::try_successful_origin().unwrap();
+        let entity = EntityId::Account(account("blocked", 1, 0));
+    }: _<T::RuntimeOrigin>(origin, entity.clone(), Some(EntityStatus::Blocked))
+    verify {
+        ensure!(
+            GlobalStatusByEntity::<T>::get(entity) == Some(EntityStatus::Blocked),
+            "Global status wasn't updated"
+        );
+    }
+
+    create_moderation_list {
+        let owner: T::AccountId = account("owner", 24, 0);
+        let (space_id, _) = create_dummy_space_and_post::<T>(owner.clone())?;
+        let list_id = NextModerationListId::<T>::get();
+    }: _(RawOrigin::Signed(owner), space_id, Content::None)
+    verify {
+        ensure!(ModerationListById::<T>::contains_key(list_id), "Moderation list wasn't created");
+    }
+
+    update_list_entity_status {
+        let owner: T::AccountId = account("owner", 24, 0);
+        let (space_id, post_id) = create_dummy_space_and_post::<T>(owner.clone())?;
+        let list_id = NextModerationListId::<T>::get();
+        Pallet::<T>::create_moderation_list(RawOrigin::Signed(owner.clone()).into(), space_id, Content::None)?;
+        let entity = EntityId::Post(post_id);
+    }: _(RawOrigin::Signed(owner), list_id, entity.clone(), Some(EntityStatus::Blocked))
+    verify {
+        ensure!(
+            StatusByEntityInList::<T>::get(list_id, entity) == Some(EntityStatus::Blocked),
+            "List status wasn't updated"
+        );
+    }
+
+    subscribe_to_moderation_list {
+        let owner: T::AccountId = account("owner", 24, 0);
+        let (space_id, _) = create_dummy_space_and_post::<T>(owner.clone())?;
+        let list_id = NextModerationListId::<T>::get();
+        Pallet::<T>::create_moderation_list(RawOrigin::Signed(owner.clone()).into(), space_id, Content::None)?;
+    }: _(RawOrigin::Signed(owner), space_id, list_id)
+    verify {
+        ensure!(
+            SubscribedListIdsBySpaceId::<T>::get(space_id).contains(&list_id),
+            "Space wasn't subscribed to the list"
+        );
+    }
+
+    unsubscribe_from_moderation_list {
+        let owner: T::AccountId = account("owner", 24, 0);
+        let (space_id, _) = create_dummy_space_and_post::<T>(owner.clone())?;
+        let list_id = NextModerationListId::<T>::get();
+        Pallet::<T>::create_moderation_list(RawOrigin::Signed(owner.clone()).into(), space_id, Content::None)?;
+        Pallet::<T>::subscribe_to_moderation_list(RawOrigin::Signed(owner.clone()).into(), space_id, list_id)?;
+    }: _(RawOrigin::Signed(owner), space_id, list_id)
+    verify {
+        ensure!(
+            !SubscribedListIdsBySpaceId::<T>::get(space_id).contains(&list_id),
+            "Space wasn't unsubscribed from the list"
+        );
+    }
+
+    set_auto_hide_settings {
+        let owner: T::AccountId = account("owner", 24, 0);
+        let (space_id, _) = create_dummy_space_and_post::<T>(owner.clone())?;
+    }: _(RawOrigin::Signed(owner), space_id, Some(auto_hide_settings::<T>()))
+    verify {
+        ensure!(
+            AutoHideSettingsBySpaceId::<T>::contains_key(space_id),
+            "Auto-hide settings weren't set"
+        );
+    }
+
+    restore_auto_hidden_post {
+        let r in 1 .. T::MaxOpenReportsPerSpace::get();
+        let owner: T::AccountId = account("owner", 24, 0);
+        let (space_id, post_id) = create_dummy_space_and_post::<T>(owner.clone())?;
+
+        for i in 0 .. r {
+            let reporter: T::AccountId = account("reporter", i, 0);
+            AutoHideReportersByPostId::<T>::insert(post_id, reporter, ());
+        }
+        AutoHideReportersCount::<T>::insert(post_id, r);
+        AutoHiddenPostScope::<T>::insert(post_id, space_id);
+        Posts::<T>::mutate_post_by_id(post_id, |post| Posts::<T>::set_post_hidden(post, true))?;
+    }: _(RawOrigin::Signed(owner), post_id, Content::None)
+    verify {
+        ensure!(!AutoHiddenPostScope::<T>::contains_key(post_id), "Post wasn't restored");
+    }
+
+    appeal_entity_status {
+        let owner: T::AccountId = account("owner", 24, 0);
+        let post_owner: T::AccountId = account("post_owner", 2, 0);
+        let (space_id, _) = create_dummy_space_and_post::<T>(owner.clone())?;
+        let (_, post_id) = create_dummy_space_and_post::<T>(post_owner.clone())?;
+        let entity = EntityId::Post(post_id);
+        let appeal_id = NextAppealId::<T>::get();
+
+        StatusByEntityInSpace::<T>::insert(space_id, &entity, EntityStatus::Blocked);
+        <T as Config>::Currency::make_free_balance_be(&post_owner, BalanceOf::<T>::max_value());
+    }: _(RawOrigin::Signed(post_owner), entity, space_id, Content::None)
+    verify {
+        ensure!(AppealById::<T>::contains_key(appeal_id), "Appeal wasn't created");
+    }
+
+    resolve_appeal {
+        let a in 0 .. T::MaxOpenAppealsPerSpace::get() - 1;
+        let owner: T::AccountId = account("owner", 24, 0);
+        let blocked: T::AccountId = account("blocked", 2, 0);
+        let (space_id, _) = create_dummy_space_and_post::<T>(owner.clone())?;
+        let entity = EntityId::Account(blocked.clone());
+
+        StatusByEntityInSpace::<T>::insert(space_id, &entity, EntityStatus::Blocked);
+        add_dummy_appeals::<T>(&entity, space_id, a);
+        let appeal_id = NextAppealId::<T>::get();
+        <T as Config>::Currency::make_free_balance_be(&blocked, BalanceOf::<T>::max_value());
+        Pallet::<T>::appeal_entity_status(
+            RawOrigin::Signed(blocked).into(),
+            entity.clone(),
+            space_id,
+            Content::None,
+        )?;
+    }: _(RawOrigin::Signed(owner), appeal_id, true, Content::None)
+    verify {
+        ensure!(!AppealById::<T>::contains_key(appeal_id), "Appeal wasn't resolved");
+        ensure!(
+            !StatusByEntityInSpace::<T>::contains_key(space_id, entity),
+            "Status wasn't removed"
+        );
+        ensure!(AppealIdsBySpaceId::<T>::get(space_id).is_empty(), "Appeals weren't closed");
+    }
+
+    forget_reporting_age {
+        let owner: T::AccountId = account("owner", 24, 0);
+        let reporter: T::AccountId = account("reporter", 2, 0);
+        let (space_id, post_id) = create_dummy_space_and_post::<T>(owner)?;
+
+        <T as Config>::Currency::make_free_balance_be(&reporter, BalanceOf::<T>::max_value());
+        Pallet::<T>::report_entity(
+            RawOrigin::Signed(reporter.clone()).into(),
+            EntityId::Post(post_id),
+            space_id,
+            Content::None,
+        )?;
+    }: _(RawOrigin::Signed(reporter.clone()))
+    verify {
+        ensure!(
+            !FirstReportByAccount::<T>::contains_key(reporter),
+            "Reporting age wasn't forgotten"
+        );
+    }
+
+    impl_benchmark_test_suite!(
+        Pallet,
+        crate::mock::ExtBuilder::default().build(),
+        crate::mock::Test,
+    );
+}
@@ -0,0 +1,471 @@
+// Copyright (C) DAPPFORCE PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
+//
+// Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
+// Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
+
+use frame_support::{dispatch::DispatchError, traits::Get, weights::Weight};
+use sp_runtime::traits::Saturating;
+
+use pallet_spaces::types::Space;
+use subsocial_support::traits::{
+    IsContentBlocked, IsPostBlocked, IsSpaceBlocked, PostVisibilityHandler, SpaceDeletionHandler,
+};
+
+use super::*;
+
+impl<T: Config> Pallet<T> {
+    /// Check that `who` can change the status of `entity` in a given space (scope)
+    /// and that `entity` is a valid moderation target there.
+    pub(crate) fn ensure_can_moderate_entity(
+        who: &T::AccountId,
+        entity: &EntityIdOf<T>,
+        scope: SpaceId,
+        permission: SpacePermission,
+        error: DispatchError,
+    ) -> DispatchResult {
+        let space = Spaces::<T>::require_space(scope)?;
+
+        ensure!(
+            T::IsAccountBlocked::is_allowed_account(who.clone(), scope),
+            ModerationError::AccountIsBlocked
+        );
+
+        Self::ensure_entity_can_be_moderated(entity, &space)?;
+
+        Spaces::<T>::ensure_account_has_space_permission(who.clone(), &space, permission, error)
+    }
+
+    /// Check that `who` has the `UpdateEntityStatus` permission in a given space.
+    pub(crate) fn ensure_can_update_statuses_in_space(
+        who: &T::AccountId,
+        space_id: SpaceId,
+    ) -> Result<Space<T>, DispatchError> {
+        let space = Spaces::<T>::require_space(space_id)?;
+
+        ensure!(
+            T::IsAccountBlocked::is_allowed_account(who.clone(), space_id),
+            ModerationError::AccountIsBlocked
+        );
+
+        Spaces::<T>::ensure_account_has_space_permission(
+            who.clone(),
+            &space,
+            SpacePermission::UpdateEntityStatus,
+            Error::<T>::NoPermissionToUpdateEntityStatus.into(),
+        )?;
+
+        Ok(space)
+    }
+
+    /// Check that `entity` exists and can be moderated within a given space.
+    pub(crate) fn ensure_entity_can_be_moderated(
+        entity: &EntityIdOf<T>,
+        space: &Space<T>,
+    ) -> DispatchResult {
+        match entity {
+            EntityId::Account(account) => {
+                ensure!(!space.is_owner(account), Error::<T>::CannotModerateSpaceOwner);
+            },
+            EntityId::Space(space_id) => {
+                ensure!(*space_id != space.id, Error::<T>::SpaceCannotModerateItself);
+            },
+            _ => (),
+        }
+
+        Self::ensure_entity_exists(entity)
+    }
+
+    /// Check that `entity` exists, or that it is a non-empty valid content.
+    pub(crate) fn ensure_entity_exists(entity: &EntityIdOf<T>) -> DispatchResult {
+        match entity {
+            EntityId::Content(content) => {
+                ensure!(content.is_some(), Error::<T>::ContentIsEmpty);
+                ensure_content_is_valid(content.clone())
+            },
+            EntityId::Account(_) => Ok(()),
+            EntityId::Space(space_id) => Spaces::<T>::ensure_space_exists(*space_id),
+            EntityId::Post(post_id) => Posts::<T>::ensure_post_exists(*post_id),
+        }
+    }
+
+    /// Check that `who` can either suggest or update entity statuses within a given space.
+    pub(crate) fn ensure_account_is_moderator(
+        who: &T::AccountId,
+        space: &Space<T>,
+        error: DispatchError,
+    ) -> DispatchResult {
+        Spaces::<T>::ensure_account_has_space_permission(
+            who.clone(),
+            space,
+            SpacePermission::UpdateEntityStatus,
+            error.clone(),
+        )
+        .or_else(|_| {
+            Spaces::<T>::ensure_account_has_space_permission(
+                who.clone(),
+                space,
+                SpacePermission::SuggestEntityStatus,
+                error,
+            )
+        })
+    }
+
+    /// Get `Report` by id from the storage or return `ReportNotFound` error.
+    pub fn require_report(report_id: ReportId) -> Result<ReportOf<T>, DispatchError> {
+        Ok(Self::report_by_id(report_id).ok_or(Error::<T>::ReportNotFound)?)
+    }
+
+    /// Get `ModerationList` by id from the storage or return `ModerationListNotFound` error.
+    pub fn require_moderation_list(
+        list_id: ModerationListId,
+    ) -> Result<ModerationList<T>, DispatchError> {
+        Ok(Self::moderation_list_by_id(list_id).ok_or(Error::<T>::ModerationListNotFound)?)
+    }
+
+    /// Get `Appeal` by id from the storage or return `AppealNotFound` error.
+    pub fn require_appeal(appeal_id: AppealId) -> Result<Appeal<T>, DispatchError> {
+        Ok(Self::appeal_by_id(appeal_id).ok_or(Error::<T>::AppealNotFound)?)
+    }
+
+    /// Check that `who` is affected by the status of `entity` and thus can appeal it.
+    pub(crate) fn ensure_can_appeal(who: &T::AccountId, entity: &EntityIdOf<T>) -> DispatchResult {
+        let can_appeal = match entity {
+            EntityId::Content(_) => true,
+            EntityId::Account(account) => account == who,
+            EntityId::Space(space_id) => Spaces::<T>::require_space(*space_id)?.is_owner(who),
+            EntityId::Post(post_id) => Posts::<T>::require_post(*post_id)?.is_owner(who),
+        };
+
+        ensure!(can_appeal, Error::<T>::NotAllowedToAppeal);
+        Ok(())
+    }
+
+    /// Close all open appeals against the status of `entity` in a given space
+    /// and return their deposits. Returns the number of closed appeals.
+    pub(crate) fn close_entity_appeals(entity: &EntityIdOf<T>, scope: SpaceId) -> u32 {
+        let mut closed_ids = Vec::new();
+        let appeals = AppealIdByAccount::<T>::drain_prefix((entity.clone(), scope));
+        for (appellant, appeal_id) in appeals {
+            if let Some(appeal) = AppealById::<T>::take(appeal_id) {
+                <T as Config>::Currency::unreserve(&appellant, appeal.deposit);
+            }
+            closed_ids.push(appeal_id);
+        }
+
+        if !closed_ids.is_empty() {
+            AppealIdsBySpaceId::<T>::mutate(scope, |appeal_ids| {
+                appeal_ids.retain(|id| !closed_ids.contains(id))
+            });
+        }
+
+        closed_ids.len() as u32
+    }
+
+    /// Add a record to the moderation history of `entity` in a given space,
+    /// dropping the oldest record if the history is full.
+    pub(crate) fn record_moderation_decision(
+        who: T::AccountId,
+        entity: &EntityIdOf<T>,
+        scope: SpaceId,
+        decision: ModerationDecision,
+        reason: Content,
+    ) {
+        let record = ModerationRecord { decided: new_who_and_when::<T>(who), decision, reason };
+
+        ModerationHistory::<T>::mutate(scope, entity, |history| {
+            if history.is_full() {
+                history.remove(0);
+            }
+            let _ = history.try_push(record);
+        });
+    }
+
+    /// Get the moderation history of an entity in a given space, from the oldest record.
+    pub fn get_moderation_history(
+        entity: EntityIdOf<T>,
+        scope: SpaceId,
+    ) -> Vec<ModerationRecordOf<T>> {
+        Self::moderation_history(scope, entity).into_inner()
+    }
+
+    /// Get all unresolved reports in a given space, from the oldest to the newest.
+    pub fn get_open_reports(space_id: SpaceId) -> Vec<ReportOf<T>> {
+        Self::report_ids_by_space_id(space_id)
+            .into_iter()
+            .filter_map(Self::report_by_id)
+            .collect()
+    }
+
+    /// Count `reporter` towards auto-hiding of a reported post and hide the post
+    /// once the reporters threshold of its space is reached.
+    pub(crate) fn maybe_auto_hide_post(
+        reporter: &T::AccountId,
+        post_id: PostId,
+        space: &Space<T>,
+    ) -> DispatchResult {
+        let settings = match Self::auto_hide_settings_by_space_id(space.id) {
+            Some(settings) => settings,
+            None => return Ok(()),
+        };
+
+        let post = Posts::<T>::require_post(post_id)?;
+        if post.hidden || post.try_get_space_id() != Some(space.id) {
+            return Ok(())
+        }
+
+        if AutoHideReportersByPostId::<T>::contains_key(post_id, reporter) ||
+            !Self::is_qualified_reporter(reporter, space, &settings)
+        {
+            return Ok(())
+        }
+
+        AutoHideReportersByPostId::<T>::insert(post_id, reporter, ());
+        let reporters_count = AutoHideReportersCount::<T>::mutate(post_id, |count| {
+            *count = count.saturating_add(1);
+            *count
+        });
+
+        if reporters_count >= settings.reporters_threshold {
+            Posts::<T>::mutate_post_by_id(post_id, |post| Posts::<T>::set_post_hidden(post, true))?;
+            AutoHiddenPostScope::<T>::insert(post_id, space.id);
+
+            Self::deposit_event(Event::PostAutoHidden {
+                post_id,
+                scope: space.id,
+                reason: AutoHideReason::ReportsThresholdReached { reporters_count },
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Check whether a report by `reporter` counts towards auto-hiding in a given space.
+    fn is_qualified_reporter(
+        reporter: &T::AccountId,
+        space: &Space<T>,
+        settings: &AutoHideSettingsOf<T>,
+    ) -> bool {
+        if settings.followers_only && !space.is_follower(reporter) {
+            return false
+        }
+
+        if let Some(min_age) = settings.min_reporting_age {
+            let now = frame_system::Pallet::<T>::block_number();
+            let first_report_block = Self::first_report_by_account(reporter)
+                .map_or(now, |first_report| first_report.block);
+            let age = now.saturating_sub(first_report_block);
+            if age < min_age {
+                return false
+            }
+        }
+
+        true
+    }
+
+    /// Forget the reporters counted towards auto-hiding of a given post.
+    /// The number of reporters is bounded by the reporters threshold of the space.
+    pub(crate) fn reset_auto_hide_reporters(post_id: PostId) {
+        AutoHiddenPostScope::<T>::remove(post_id);
+        let reporters_count = AutoHideReportersCount::<T>::take(post_id);
+        if reporters_count > 0 {
+            let _ = AutoHideReportersByPostId::<T>::clear_prefix(post_id, reporters_count, None);
+        }
+    }
+
+    /// An entity is blocked in a space if it is blocked globally, or in this space.
+    /// If the space has no status for the entity, the moderation lists
+    /// the space is subscribed to are consulted.
+    fn is_entity_blocked(entity: EntityIdOf<T>, scope: SpaceId) -> bool {
+        if Self::global_status_by_entity(&entity) == Some(EntityStatus::Blocked) {
+            return true
+        }
+
+        if let Some(status) = Self::status_by_entity_in_space(scope, &entity) {
+            return status == EntityStatus::Blocked
+        }
+
+        let is_blocked_by_lists = Self::subscribed_list_ids_by_space_id(scope)
+            .into_iter()
+            .any(|list_id| {
+                Self::status_by_entity_in_list(list_id, &entity) == Some(EntityStatus::Blocked)
+            });
+
+        // Lists are managed outside of this space, so they must not lock its owner out.
+        if is_blocked_by_lists {
+            if let EntityId::Account(account) = &entity {
+                return Spaces::<T>::space_by_id(scope).map_or(true, |space| !space.is_owner(account))
+            }
+        }
+
+        is_blocked_by_lists
+    }
+}
+
+impl<T: Config> IsAccountBlocked<T::AccountId> for Pallet<T> {
+    fn is_blocked_account(account: T::AccountId, scope: SpaceId) -> bool {
+        Self::is_entity_blocked(EntityId::Account(account), scope)
+    }
+
+    fn is_allowed_account(account: T::AccountId, scope: SpaceId) -> bool {
+        !Self::is_blocked_account(account, scope)
+    }
+}
+
+impl<T: Config> IsSpaceBlocked for Pallet<T> {
+    fn is_blocked_space(space_id: SpaceId, scope: SpaceId) -> bool {
+        Self::is_entity_blocked(EntityId::Space(space_id), scope)
+    }
+
+    fn is_allowed_space(space_id: SpaceId, scope: SpaceId) -> bool {
+        !Self::is_blocked_space(space_id, scope)
+    }
+}
+
+impl<T: Config> IsPostBlocked<PostId> for Pallet<T> {
+    fn is_blocked_post(post_id: PostId, scope: SpaceId) -> bool {
+        Self::is_entity_blocked(EntityId::Post(post_id), scope)
+    }
+
+    fn is_allowed_post(post_id: PostId, scope: SpaceId) -> bool {
+        !Self::is_blocked_post(post_id, scope)
+    }
+}
+
+impl<T: Config> IsContentBlocked for Pallet<T> {
+    fn is_blocked_content(content: Content, scope: SpaceId) -> bool {
+        if content.is_none() {
+            return false
+        }
+
+        Self::is_entity_blocked(EntityId::Content(content), scope)
+    }
+
+    fn is_allowed_content(content: Content, scope: SpaceId) -> bool {
+        !Self::is_blocked_content(content, scope)
+    }
+}
+
+impl<T: Config> SpaceDeletionHandler for Pallet<T> {
+    /// Close the open reports and appeals of a deleted space, returning their deposits,
+    /// then remove the statuses, suggestions and moderation history of entities in the space,
+    /// the moderation lists managed in the space, and its moderation settings.
+    fn cleanup_deleted_space(space_id: SpaceId, limit: u32) -> u32 {
+        let mut removed = 0;
+
+        while removed < limit {
+            let report_id = match Self::report_ids_by_space_id(space_id).last() {
+                Some(report_id) => *report_id,
+                None => break,
+            };
+
+            if let Some(report) = ReportById::<T>::take(report_id) {
+                let reporter = &report.created.account;
+                <T as Config>::Currency::unreserve(reporter, report.deposit);
+                ReportIdByAccount::<T>::remove((report.reported_entity, space_id), reporter);
+            }
+            ReportIdsBySpaceId::<T>::mutate(space_id, |report_ids| report_ids.pop());
+            removed += 1;
+        }
+
+        while removed < limit {
+            let appeal_id = match Self::appeal_ids_by_space_id(space_id).last() {
+                Some(appeal_id) => *appeal_id,
+                None => break,
+            };
+
+            if let Some(appeal) = AppealById::<T>::take(appeal_id) {
+                let appellant = &appeal.created.account;
+                <T as Config>::Currency::unreserve(appellant, appeal.deposit);
+                AppealIdByAccount::<T>::remove((appeal.entity, space_id), appellant);
+            }
+            AppealIdsBySpaceId::<T>::mutate(space_id, |appeal_ids| appeal_ids.pop());
+            removed += 1;
+        }
+
+        if removed < limit {
+            let remaining = (limit - removed) as usize;
+            removed +=
+                StatusByEntityInSpace::<T>::drain_prefix(space_id).take(remaining).count() as u32;
+        }
+
+        if removed < limit {
+            let remaining = (limit - removed) as usize;
+            removed += SuggestedStatusByAccount::<T>::drain_prefix(space_id).take(remaining).count()
+                as u32;
+        }
+
+        if removed < limit {
+            let remaining = (limit - removed) as usize;
+            removed +=
+                ModerationHistory::<T>::drain_prefix(space_id).take(remaining).count() as u32;
+        }
+
+        // The lists of a deleted space can no longer be updated, so they are removed
+        // after unsubscribing other spaces from them.
+        while removed < limit {
+            let list_id = match Self::moderation_list_ids_by_space_id(space_id).last() {
+                Some(list_id) => *list_id,
+                None => break,
+            };
+
+            let remaining = (limit - removed) as usize;
+            let subscriber_space_ids = SpaceSubscribedToList::<T>::drain_prefix(list_id)
+                .take(remaining)
+                .map(|(subscriber_space_id, _)| subscriber_space_id)
+                .collect::<Vec<_>>();
+            removed += subscriber_space_ids.len() as u32;
+
+            for subscriber_space_id in subscriber_space_ids {
+                SubscribedListIdsBySpaceId::<T>::mutate(subscriber_space_id, |list_ids| {
+                    list_ids.retain(|id| *id != list_id)
+                });
+            }
+
+            if removed < limit {
+                let remaining = (limit - removed) as usize;
+                removed +=
+                    StatusByEntityInList::<T>::drain_prefix(list_id).take(remaining).count() as u32;
+            }
+
+            if removed >= limit {
+                break;
+            }
+
+            ModerationListById::<T>::remove(list_id);
+            ModerationListIdsBySpaceId::<T>::mutate(space_id, |list_ids| list_ids.pop());
+            removed += 1;
+        }
+
+        if removed < limit {
+            for list_id in SubscribedListIdsBySpaceId::<T>::take(space_id) {
+                SpaceSubscribedToList::<T>::remove(list_id, space_id);
+            }
+
+            ReportIdsBySpaceId::<T>::remove(space_id);
+            AppealIdsBySpaceId::<T>::remove(space_id);
+            ModerationListIdsBySpaceId::<T>::remove(space_id);
+            AutoHideSettingsBySpaceId::<T>::remove(space_id);
+        }
+
+        removed
+    }
+
+    /// An item is either a report, an appeal, an entity status, suggestion or history record,
+    /// a moderation list of the space, or a subscriber of such a list.
+    fn cleanup_deleted_space_weight(limit: u32) -> Weight {
+        let item_weight = T::DbWeight::get().reads_writes(3, 4);
+        let subscribed_lists_weight =
+            T::DbWeight::get().writes(T::MaxSubscribedListsPerSpace::get().into());
+        T::DbWeight::get()
+            .reads_writes(4, 5)
+            .saturating_add(subscribed_lists_weight)
+            .saturating_add(item_weight.saturating_mul(limit.into()))
+    }
+}
+
+impl<T: Config> PostVisibilityHandler for Pallet<T> {
+    fn on_post_unhidden(post_id: PostId) {
+        Self::reset_auto_hide_reporters(post_id);
+    }
+}
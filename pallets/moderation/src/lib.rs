@@ -0,0 +1,1010 @@
+// Copyright (C) DAPPFORCE PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
+//
+// Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
+// Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
+
+//! # Moderation Module
+//!
+//! The Moderation module allows space moderators to block or explicitly allow accounts,
+//! spaces, posts and content within a given space (a scope).
+//!
+//! Members of a space that have the `SuggestEntityStatus` permission can suggest a status
+//! for an entity, while those with the `UpdateEntityStatus` permission set the status that
+//! other pallets check through the traits in `subsocial_support::traits::moderation`.
+//!
+//! Accounts, spaces, posts and content can also be blocked globally, across all spaces,
+//! by `GlobalModerationOrigin` (e.g. for legal takedowns).
+//!
+//! Moderators of a space can also curate moderation lists, which other spaces can subscribe to
+//! in order to reuse the same statuses instead of repeating them in each space.
+//! The lists of a deleted space are removed, and the spaces subscribed to them unsubscribed.
+//!
+//! Every change of an entity status in a space is recorded in the moderation history of this
+//! entity, together with the moderator who made it and the reason. A blocked account,
+//! or the owner of a blocked post or space, can appeal the status, and moderators decide
+//! whether to accept the appeal and remove the status. An appeal reserves a deposit from the
+//! appellant, which is returned if the appeal is accepted and slashed otherwise.
+//!
+//! Any account can report an entity within a space. A report reserves a deposit from the
+//! reporter, which is returned if a moderator upholds the report and slashed otherwise.
+//!
+//! A space can enable auto-hide: once a post or comment is reported by a given number of
+//! distinct reporters, it is hidden automatically until a moderator restores it.
+//! A space may count only the reporters whose reporting age, counted from their first report,
+//! is high enough. Keeping the first report of an account reserves a deposit from it.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode};
+use frame_support::{
+    dispatch::DispatchResult,
+    ensure,
+    traits::{Currency, EnsureOrigin, ReservableCurrency},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
+
+use pallet_permissions::SpacePermission;
+use pallet_posts::Pallet as Posts;
+use pallet_spaces::Pallet as Spaces;
+use subsocial_support::{
+    ensure_content_is_valid, new_who_and_when, traits::IsAccountBlocked, Content,
+    ModerationError, PostId, SpaceId, WhoAndWhen, WhoAndWhenOf,
+};
+
+pub use pallet::*;
+pub mod functions;
+
+pub mod types;
+pub use types::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod weights;
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+
+    use frame_support::pallet_prelude::*;
+    use frame_system::pallet_prelude::*;
+
+    use crate::weights::WeightInfo;
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config + pallet_posts::Config + pallet_spaces::Config {
+        /// The overarching event type.
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The origin which may update statuses of entities globally, i.e. across all spaces.
+        type GlobalModerationOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+
+        /// The currency mechanism, used to reserve report deposits.
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// The amount reserved from a reporter until their report is resolved.
+        #[pallet::constant]
+        type ReportDeposit: Get<BalanceOf<Self>>;
+
+        /// The amount reserved from a reporter on their first report, for keeping the block
+        /// which their reporting age is counted from. It is returned when the reporter
+        /// forgets their reporting age.
+        #[pallet::constant]
+        type ReportingAgeDeposit: Get<BalanceOf<Self>>;
+
+        /// The maximum number of unresolved reports in a single space.
+        #[pallet::constant]
+        type MaxOpenReportsPerSpace: Get<u32>;
+
+        /// The maximum number of moderation lists a single space can subscribe to.
+        ///
+        /// Every check whether an account, space, post or content is blocked in a space may read
+        /// a status from each list that space is subscribed to. The weights of calls in other
+        /// pallets do not account for these reads, so this bound must be kept small.
+        #[pallet::constant]
+        type MaxSubscribedListsPerSpace: Get<u32>;
+
+        /// The amount reserved from an appellant until their appeal is resolved.
+        #[pallet::constant]
+        type AppealDeposit: Get<BalanceOf<Self>>;
+
+        /// The maximum number of unresolved appeals in a single space.
+        #[pallet::constant]
+        type MaxOpenAppealsPerSpace: Get<u32>;
+
+        /// The maximum number of moderation records kept for an entity in a single space.
+        /// The oldest records are dropped first.
+        #[pallet::constant]
+        type MaxModerationHistoryLength: Get<u32>;
+
+        /// Weight information for extrinsics in this pallet.
+        type WeightInfo: WeightInfo;
+    }
+
+    #[pallet::pallet]
+    #[pallet::without_storage_info]
+    pub struct Pallet<T>(_);
+
+    /// Get the status of an entity in a given space (scope).
+    #[pallet::storage]
+    #[pallet::getter(fn status_by_entity_in_space)]
+    pub type StatusByEntityInSpace<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        SpaceId,
+        Blake2_128Concat,
+        EntityIdOf<T>,
+        EntityStatus,
+    >;
+
+    /// Get the global status of an entity, which applies to all spaces.
+    #[pallet::storage]
+    #[pallet::getter(fn global_status_by_entity)]
+    pub type GlobalStatusByEntity<T: Config> =
+        StorageMap<_, Blake2_128Concat, EntityIdOf<T>, EntityStatus>;
+
+    /// Get the status suggested by an account for an entity in a given space (scope).
+    #[pallet::storage]
+    #[pallet::getter(fn suggested_status_by_account)]
+    pub type SuggestedStatusByAccount<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        SpaceId,
+        Blake2_128Concat,
+        (EntityIdOf<T>, T::AccountId),
+        SuggestedStatus<T>,
+    >;
+
+    #[pallet::type_value]
+    pub fn DefaultForNextReportId() -> ReportId {
+        FIRST_REPORT_ID
+    }
+
+    /// The next report id.
+    #[pallet::storage]
+    #[pallet::getter(fn next_report_id)]
+    pub type NextReportId<T: Config> =
+        StorageValue<_, ReportId, ValueQuery, DefaultForNextReportId>;
+
+    /// Get the details of an unresolved report by its id.
+    #[pallet::storage]
+    #[pallet::getter(fn report_by_id)]
+    pub type ReportById<T: Config> = StorageMap<_, Twox64Concat, ReportId, ReportOf<T>>;
+
+    /// Get the ids of all unresolved reports in a given space.
+    #[pallet::storage]
+    #[pallet::getter(fn report_ids_by_space_id)]
+    pub type ReportIdsBySpaceId<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        SpaceId,
+        BoundedVec<ReportId, T::MaxOpenReportsPerSpace>,
+        ValueQuery,
+    >;
+
+    /// Get the id of an unresolved report made by an account on an entity in a given space.
+    #[pallet::storage]
+    #[pallet::getter(fn report_id_by_account)]
+    pub type ReportIdByAccount<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (EntityIdOf<T>, SpaceId),
+        Twox64Concat,
+        T::AccountId,
+        ReportId,
+    >;
+
+    #[pallet::type_value]
+    pub fn DefaultForNextModerationListId() -> ModerationListId {
+        FIRST_MODERATION_LIST_ID
+    }
+
+    /// The next moderation list id.
+    #[pallet::storage]
+    #[pallet::getter(fn next_moderation_list_id)]
+    pub type NextModerationListId<T: Config> =
+        StorageValue<_, ModerationListId, ValueQuery, DefaultForNextModerationListId>;
+
+    /// Get the details of a moderation list by its id.
+    #[pallet::storage]
+    #[pallet::getter(fn moderation_list_by_id)]
+    pub type ModerationListById<T: Config> =
+        StorageMap<_, Twox64Concat, ModerationListId, ModerationList<T>>;
+
+    /// Get the ids of all moderation lists managed by moderators of a given space.
+    #[pallet::storage]
+    #[pallet::getter(fn moderation_list_ids_by_space_id)]
+    pub type ModerationListIdsBySpaceId<T: Config> =
+        StorageMap<_, Twox64Concat, SpaceId, Vec<ModerationListId>, ValueQuery>;
+
+    /// Get the status of an entity in a given moderation list.
+    #[pallet::storage]
+    #[pallet::getter(fn status_by_entity_in_list)]
+    pub type StatusByEntityInList<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        ModerationListId,
+        Blake2_128Concat,
+        EntityIdOf<T>,
+        EntityStatus,
+    >;
+
+    /// Get the ids of all moderation lists a given space is subscribed to.
+    #[pallet::storage]
+    #[pallet::getter(fn subscribed_list_ids_by_space_id)]
+    pub type SubscribedListIdsBySpaceId<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        SpaceId,
+        BoundedVec<ModerationListId, T::MaxSubscribedListsPerSpace>,
+        ValueQuery,
+    >;
+
+    /// Whether a given space is subscribed to a given moderation list.
+    #[pallet::storage]
+    #[pallet::getter(fn is_space_subscribed_to_list)]
+    pub type SpaceSubscribedToList<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        ModerationListId,
+        Twox64Concat,
+        SpaceId,
+        bool,
+        ValueQuery,
+    >;
+
+    #[pallet::type_value]
+    pub fn DefaultForNextAppealId() -> AppealId {
+        FIRST_APPEAL_ID
+    }
+
+    /// The next appeal id.
+    #[pallet::storage]
+    #[pallet::getter(fn next_appeal_id)]
+    pub type NextAppealId<T: Config> =
+        StorageValue<_, AppealId, ValueQuery, DefaultForNextAppealId>;
+
+    /// Get the details of an unresolved appeal by its id.
+    #[pallet::storage]
+    #[pallet::getter(fn appeal_by_id)]
+    pub type AppealById<T: Config> = StorageMap<_, Twox64Concat, AppealId, Appeal<T>>;
+
+    /// Get the ids of all unresolved appeals in a given space.
+    #[pallet::storage]
+    #[pallet::getter(fn appeal_ids_by_space_id)]
+    pub type AppealIdsBySpaceId<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        SpaceId,
+        BoundedVec<AppealId, T::MaxOpenAppealsPerSpace>,
+        ValueQuery,
+    >;
+
+    /// Get the id of an unresolved appeal made by an account on an entity in a given space.
+    #[pallet::storage]
+    #[pallet::getter(fn appeal_id_by_account)]
+    pub type AppealIdByAccount<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (EntityIdOf<T>, SpaceId),
+        Twox64Concat,
+        T::AccountId,
+        AppealId,
+    >;
+
+    /// Get the moderation history of an entity in a given space (scope), from the oldest record.
+    #[pallet::storage]
+    #[pallet::getter(fn moderation_history)]
+    pub type ModerationHistory<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        SpaceId,
+        Blake2_128Concat,
+        EntityIdOf<T>,
+        BoundedVec<ModerationRecordOf<T>, T::MaxModerationHistoryLength>,
+        ValueQuery,
+    >;
+
+    /// Get the auto-hide settings of a given space.
+    #[pallet::storage]
+    #[pallet::getter(fn auto_hide_settings_by_space_id)]
+    pub type AutoHideSettingsBySpaceId<T: Config> =
+        StorageMap<_, Twox64Concat, SpaceId, AutoHideSettingsOf<T>>;
+
+    /// Accounts counted towards auto-hiding of a given post since it was last restored.
+    #[pallet::storage]
+    pub type AutoHideReportersByPostId<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, PostId, Blake2_128Concat, T::AccountId, ()>;
+
+    /// Get the number of accounts counted towards auto-hiding of a given post.
+    #[pallet::storage]
+    #[pallet::getter(fn auto_hide_reporters_count)]
+    pub type AutoHideReportersCount<T: Config> =
+        StorageMap<_, Twox64Concat, PostId, u32, ValueQuery>;
+
+    /// Get the space (scope) in which a given post was hidden automatically.
+    #[pallet::storage]
+    #[pallet::getter(fn auto_hidden_post_scope)]
+    pub type AutoHiddenPostScope<T: Config> = StorageMap<_, Twox64Concat, PostId, SpaceId>;
+
+    /// Get the first report of an account, which its reporting age is counted from.
+    /// Every entry is backed by a deposit of the account.
+    #[pallet::storage]
+    #[pallet::getter(fn first_report_by_account)]
+    pub type FirstReportByAccount<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, FirstReportOf<T>>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        EntityStatusSuggested {
+            account: T::AccountId,
+            entity: EntityIdOf<T>,
+            scope: SpaceId,
+            status: Option<EntityStatus>,
+        },
+        EntityStatusUpdated {
+            account: T::AccountId,
+            entity: EntityIdOf<T>,
+            scope: SpaceId,
+            status: Option<EntityStatus>,
+        },
+        GlobalEntityStatusUpdated {
+            entity: EntityIdOf<T>,
+            status: Option<EntityStatus>,
+        },
+        EntityReported {
+            account: T::AccountId,
+            entity: EntityIdOf<T>,
+            scope: SpaceId,
+            report_id: ReportId,
+        },
+        ReportResolved {
+            account: T::AccountId,
+            report_id: ReportId,
+            upheld: bool,
+        },
+        ModerationListCreated {
+            account: T::AccountId,
+            list_id: ModerationListId,
+            owner_space: SpaceId,
+        },
+        ListEntityStatusUpdated {
+            account: T::AccountId,
+            list_id: ModerationListId,
+            entity: EntityIdOf<T>,
+            status: Option<EntityStatus>,
+        },
+        SubscribedToModerationList {
+            account: T::AccountId,
+            space_id: SpaceId,
+            list_id: ModerationListId,
+        },
+        UnsubscribedFromModerationList {
+            account: T::AccountId,
+            space_id: SpaceId,
+            list_id: ModerationListId,
+        },
+        AutoHideSettingsUpdated {
+            account: T::AccountId,
+            space_id: SpaceId,
+            settings: Option<AutoHideSettingsOf<T>>,
+        },
+        PostAutoHidden {
+            post_id: PostId,
+            scope: SpaceId,
+            reason: AutoHideReason,
+        },
+        AutoHiddenPostRestored {
+            account: T::AccountId,
+            post_id: PostId,
+            scope: SpaceId,
+            reason: Content,
+        },
+        EntityStatusAppealed {
+            account: T::AccountId,
+            entity: EntityIdOf<T>,
+            scope: SpaceId,
+            appeal_id: AppealId,
+        },
+        AppealResolved {
+            account: T::AccountId,
+            appeal_id: AppealId,
+            accepted: bool,
+        },
+        ReportingAgeForgotten {
+            account: T::AccountId,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The entity has no status in this scope.
+        EntityHasNoStatus,
+        /// The entity has no global status.
+        EntityHasNoGlobalStatus,
+        /// Cannot moderate empty content.
+        ContentIsEmpty,
+        /// A space cannot be moderated within its own scope.
+        SpaceCannotModerateItself,
+        /// The owner of a space cannot be moderated within this space.
+        CannotModerateSpaceOwner,
+        /// Account has no permission to suggest a new entity status.
+        NoPermissionToSuggestEntityStatus,
+        /// Account has no permission to update an entity status.
+        NoPermissionToUpdateEntityStatus,
+        /// Report was not found by id.
+        ReportNotFound,
+        /// Account has already reported this entity in this space.
+        AlreadyReportedEntity,
+        /// There are too many unresolved reports in this space.
+        TooManyOpenReports,
+        /// Account has no permission to resolve reports in this space.
+        NoPermissionToResolveReports,
+        /// Moderation list was not found by id.
+        ModerationListNotFound,
+        /// The entity has no status in this moderation list.
+        EntityHasNoStatusInList,
+        /// This space is already subscribed to this moderation list.
+        AlreadySubscribedToList,
+        /// This space is not subscribed to this moderation list.
+        NotSubscribedToList,
+        /// This space is subscribed to too many moderation lists.
+        TooManySubscribedLists,
+        /// Reporters threshold must be greater than zero
+        /// and not greater than `MaxOpenReportsPerSpace`.
+        InvalidReportersThreshold,
+        /// This space has no auto-hide settings.
+        NoAutoHideSettings,
+        /// The post was not hidden automatically.
+        PostIsNotAutoHidden,
+        /// Appeal was not found by id.
+        AppealNotFound,
+        /// Only a blocked entity can be appealed.
+        EntityIsNotBlocked,
+        /// Account is not allowed to appeal the status of this entity.
+        NotAllowedToAppeal,
+        /// Account has already appealed the status of this entity in this space.
+        AlreadyAppealedEntity,
+        /// There are too many unresolved appeals in this space.
+        TooManyOpenAppeals,
+        /// Account has never reported an entity, or has forgotten its reporting age.
+        ReportingAgeNotFound,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Suggest a status for an entity within a space (scope).
+        /// `None` status means that the caller suggests to remove the current status.
+        #[pallet::call_index(0)]
+        #[pallet::weight(<T as Config>::WeightInfo::suggest_entity_status())]
+        pub fn suggest_entity_status(
+            origin: OriginFor<T>,
+            entity: EntityIdOf<T>,
+            scope: SpaceId,
+            status: Option<EntityStatus>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::ensure_can_moderate_entity(
+                &who,
+                &entity,
+                scope,
+                SpacePermission::SuggestEntityStatus,
+                Error::<T>::NoPermissionToSuggestEntityStatus.into(),
+            )?;
+
+            SuggestedStatusByAccount::<T>::insert(
+                scope,
+                (entity.clone(), who.clone()),
+                SuggestedStatus::new(who.clone(), status),
+            );
+
+            Self::deposit_event(Event::EntityStatusSuggested { account: who, entity, scope, status });
+            Ok(())
+        }
+
+        /// Set a status of an entity within a space (scope).
+        /// `None` status removes the current status of the entity.
+        /// The change is recorded in the moderation history of the entity along with `reason`.
+        /// Open appeals against the status of the entity are closed once it is no longer blocked.
+        #[pallet::call_index(1)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::update_entity_status(T::MaxOpenAppealsPerSpace::get())
+        )]
+        pub fn update_entity_status(
+            origin: OriginFor<T>,
+            entity: EntityIdOf<T>,
+            scope: SpaceId,
+            status: Option<EntityStatus>,
+            reason: Content,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            ensure_content_is_valid(reason.clone())?;
+
+            Self::ensure_can_moderate_entity(
+                &who,
+                &entity,
+                scope,
+                SpacePermission::UpdateEntityStatus,
+                Error::<T>::NoPermissionToUpdateEntityStatus.into(),
+            )?;
+
+            match status {
+                Some(new_status) => StatusByEntityInSpace::<T>::insert(scope, &entity, new_status),
+                None => {
+                    ensure!(
+                        StatusByEntityInSpace::<T>::contains_key(scope, &entity),
+                        Error::<T>::EntityHasNoStatus
+                    );
+                    StatusByEntityInSpace::<T>::remove(scope, &entity);
+                },
+            }
+
+            Self::record_moderation_decision(
+                who.clone(),
+                &entity,
+                scope,
+                ModerationDecision::StatusUpdated(status),
+                reason,
+            );
+
+            let closed_appeals = match status {
+                Some(EntityStatus::Blocked) => 0,
+                _ => Self::close_entity_appeals(&entity, scope),
+            };
+
+            Self::deposit_event(Event::EntityStatusUpdated { account: who, entity, scope, status });
+            Ok(Some(<T as Config>::WeightInfo::update_entity_status(closed_appeals)).into())
+        }
+
+        /// Report an entity that violates the rules of a space (scope).
+        /// `ReportDeposit` is reserved from the caller until the report is resolved.
+        ///
+        /// A reported post of this space is hidden once it reaches the reporters threshold
+        /// set with `set_auto_hide_settings`.
+        #[pallet::call_index(2)]
+        #[pallet::weight(<T as Config>::WeightInfo::report_entity())]
+        pub fn report_entity(
+            origin: OriginFor<T>,
+            entity: EntityIdOf<T>,
+            scope: SpaceId,
+            reason: Content,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let space = Spaces::<T>::require_space(scope)?;
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(who.clone(), scope),
+                ModerationError::AccountIsBlocked
+            );
+            Self::ensure_entity_can_be_moderated(&entity, &space)?;
+            ensure_content_is_valid(reason.clone())?;
+
+            ensure!(
+                !ReportIdByAccount::<T>::contains_key((entity.clone(), scope), &who),
+                Error::<T>::AlreadyReportedEntity
+            );
+
+            let report_id = Self::next_report_id();
+            ReportIdsBySpaceId::<T>::try_mutate(scope, |report_ids| {
+                report_ids.try_push(report_id).map_err(|_| Error::<T>::TooManyOpenReports)
+            })?;
+
+            let deposit = T::ReportDeposit::get();
+            <T as Config>::Currency::reserve(&who, deposit)?;
+
+            let report = Report {
+                id: report_id,
+                created: new_who_and_when::<T>(who.clone()),
+                reported_entity: entity.clone(),
+                reported_within: scope,
+                reason,
+                deposit,
+            };
+
+            ReportById::<T>::insert(report_id, report);
+            ReportIdByAccount::<T>::insert((entity.clone(), scope), &who, report_id);
+            NextReportId::<T>::mutate(|n| *n += 1);
+
+            if !FirstReportByAccount::<T>::contains_key(&who) {
+                let deposit = T::ReportingAgeDeposit::get();
+                <T as Config>::Currency::reserve(&who, deposit)?;
+                FirstReportByAccount::<T>::insert(
+                    &who,
+                    FirstReport { block: frame_system::Pallet::<T>::block_number(), deposit },
+                );
+            }
+
+            if let EntityId::Post(post_id) = &entity {
+                Self::maybe_auto_hide_post(&who, *post_id, &space)?;
+            }
+
+            Self::deposit_event(Event::EntityReported { account: who, entity, scope, report_id });
+            Ok(())
+        }
+
+        /// Resolve a report. If the report is upheld, its deposit is returned to the reporter,
+        /// otherwise the deposit is slashed.
+        ///
+        /// Changing the status of the reported entity is done separately
+        /// with `update_entity_status`.
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::resolve_report())]
+        pub fn resolve_report(
+            origin: OriginFor<T>,
+            report_id: ReportId,
+            upheld: bool,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let report = Self::require_report(report_id)?;
+            let space = Spaces::<T>::require_space(report.reported_within)?;
+
+            Self::ensure_account_is_moderator(
+                &who,
+                &space,
+                Error::<T>::NoPermissionToResolveReports.into(),
+            )?;
+
+            let reporter = &report.created.account;
+            if upheld {
+                <T as Config>::Currency::unreserve(reporter, report.deposit);
+            } else {
+                let _ = <T as Config>::Currency::slash_reserved(reporter, report.deposit);
+            }
+
+            ReportIdsBySpaceId::<T>::mutate(report.reported_within, |report_ids| {
+                report_ids.retain(|id| *id != report_id)
+            });
+            ReportIdByAccount::<T>::remove((report.reported_entity, report.reported_within), reporter);
+            ReportById::<T>::remove(report_id);
+
+            Self::deposit_event(Event::ReportResolved { account: who, report_id, upheld });
+            Ok(())
+        }
+
+        /// Set a global status of an entity, which applies to all spaces
+        /// and cannot be overridden by space moderators.
+        /// `None` status removes the current global status of the entity.
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::WeightInfo::update_global_entity_status())]
+        pub fn update_global_entity_status(
+            origin: OriginFor<T>,
+            entity: EntityIdOf<T>,
+            status: Option<EntityStatus>,
+        ) -> DispatchResult {
+            T::GlobalModerationOrigin::ensure_origin(origin)?;
+
+            Self::ensure_entity_exists(&entity)?;
+
+            match status {
+                Some(new_status) => GlobalStatusByEntity::<T>::insert(&entity, new_status),
+                None => {
+                    ensure!(
+                        GlobalStatusByEntity::<T>::contains_key(&entity),
+                        Error::<T>::EntityHasNoGlobalStatus
+                    );
+                    GlobalStatusByEntity::<T>::remove(&entity);
+                },
+            }
+
+            Self::deposit_event(Event::GlobalEntityStatusUpdated { entity, status });
+            Ok(())
+        }
+
+        /// Create a new moderation list managed by moderators of a given space.
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as Config>::WeightInfo::create_moderation_list())]
+        pub fn create_moderation_list(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            content: Content,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure_content_is_valid(content.clone())?;
+            Self::ensure_can_update_statuses_in_space(&who, space_id)?;
+
+            let list_id = Self::next_moderation_list_id();
+            let list = ModerationList::<T>::new(list_id, who.clone(), space_id, content);
+
+            ModerationListById::<T>::insert(list_id, list);
+            ModerationListIdsBySpaceId::<T>::mutate(space_id, |ids| ids.push(list_id));
+            NextModerationListId::<T>::mutate(|n| *n += 1);
+
+            Self::deposit_event(Event::ModerationListCreated {
+                account: who,
+                list_id,
+                owner_space: space_id,
+            });
+            Ok(())
+        }
+
+        /// Set a status of an entity in a moderation list.
+        /// `None` status removes the current status of the entity from the list.
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as Config>::WeightInfo::update_list_entity_status())]
+        pub fn update_list_entity_status(
+            origin: OriginFor<T>,
+            list_id: ModerationListId,
+            entity: EntityIdOf<T>,
+            status: Option<EntityStatus>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let list = Self::require_moderation_list(list_id)?;
+            Self::ensure_can_update_statuses_in_space(&who, list.owner_space)?;
+            Self::ensure_entity_exists(&entity)?;
+
+            match status {
+                Some(new_status) => StatusByEntityInList::<T>::insert(list_id, &entity, new_status),
+                None => {
+                    ensure!(
+                        StatusByEntityInList::<T>::contains_key(list_id, &entity),
+                        Error::<T>::EntityHasNoStatusInList
+                    );
+                    StatusByEntityInList::<T>::remove(list_id, &entity);
+                },
+            }
+
+            Self::deposit_event(Event::ListEntityStatusUpdated { account: who, list_id, entity, status });
+            Ok(())
+        }
+
+        /// Make a space use statuses from a moderation list
+        /// in addition to the space's own statuses.
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config>::WeightInfo::subscribe_to_moderation_list())]
+        pub fn subscribe_to_moderation_list(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            list_id: ModerationListId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::ensure_can_update_statuses_in_space(&who, space_id)?;
+            ensure!(
+                ModerationListById::<T>::contains_key(list_id),
+                Error::<T>::ModerationListNotFound
+            );
+
+            SubscribedListIdsBySpaceId::<T>::try_mutate(space_id, |list_ids| {
+                ensure!(!list_ids.contains(&list_id), Error::<T>::AlreadySubscribedToList);
+                list_ids.try_push(list_id).map_err(|_| Error::<T>::TooManySubscribedLists)
+            })?;
+            SpaceSubscribedToList::<T>::insert(list_id, space_id, true);
+
+            Self::deposit_event(Event::SubscribedToModerationList { account: who, space_id, list_id });
+            Ok(())
+        }
+
+        /// Stop using statuses from a moderation list in a space.
+        /// The space's own statuses are left as they are.
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::unsubscribe_from_moderation_list())]
+        pub fn unsubscribe_from_moderation_list(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            list_id: ModerationListId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::ensure_can_update_statuses_in_space(&who, space_id)?;
+
+            SubscribedListIdsBySpaceId::<T>::try_mutate(space_id, |list_ids| {
+                ensure!(list_ids.contains(&list_id), Error::<T>::NotSubscribedToList);
+                list_ids.retain(|id| *id != list_id);
+                Ok::<(), DispatchError>(())
+            })?;
+            SpaceSubscribedToList::<T>::remove(list_id, space_id);
+
+            Self::deposit_event(Event::UnsubscribedFromModerationList {
+                account: who,
+                space_id,
+                list_id,
+            });
+            Ok(())
+        }
+
+        /// Enable auto-hide of posts in a space, or change its settings.
+        /// `None` settings disable auto-hide.
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_auto_hide_settings())]
+        pub fn set_auto_hide_settings(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            settings: Option<AutoHideSettingsOf<T>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::ensure_can_update_statuses_in_space(&who, space_id)?;
+
+            match &settings {
+                Some(new_settings) => {
+                    ensure!(
+                        new_settings.reporters_threshold > 0 &&
+                            new_settings.reporters_threshold <= T::MaxOpenReportsPerSpace::get(),
+                        Error::<T>::InvalidReportersThreshold
+                    );
+                    AutoHideSettingsBySpaceId::<T>::insert(space_id, new_settings);
+                },
+                None => {
+                    ensure!(
+                        AutoHideSettingsBySpaceId::<T>::contains_key(space_id),
+                        Error::<T>::NoAutoHideSettings
+                    );
+                    AutoHideSettingsBySpaceId::<T>::remove(space_id);
+                },
+            }
+
+            Self::deposit_event(Event::AutoHideSettingsUpdated { account: who, space_id, settings });
+            Ok(())
+        }
+
+        /// Make an automatically hidden post visible again and reset its reporters count.
+        #[pallet::call_index(10)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::restore_auto_hidden_post(T::MaxOpenReportsPerSpace::get())
+        )]
+        pub fn restore_auto_hidden_post(
+            origin: OriginFor<T>,
+            post_id: PostId,
+            reason: Content,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let scope = Self::auto_hidden_post_scope(post_id).ok_or(Error::<T>::PostIsNotAutoHidden)?;
+            Self::ensure_can_update_statuses_in_space(&who, scope)?;
+            ensure_content_is_valid(reason.clone())?;
+
+            // Auto-hide state of the post is reset by `on_post_unhidden`.
+            Posts::<T>::mutate_post_by_id(post_id, |post| {
+                Posts::<T>::set_post_hidden(post, false)
+            })?;
+
+            Self::deposit_event(Event::AutoHiddenPostRestored { account: who, post_id, scope, reason });
+            Ok(())
+        }
+
+        /// Appeal the status of a blocked entity within a space (scope).
+        ///
+        /// A blocked account can appeal its own status, while the status of a post or a space
+        /// can be appealed by its owner. Blocked content can be appealed by any account.
+        /// `AppealDeposit` is reserved from the caller until the appeal is resolved.
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as Config>::WeightInfo::appeal_entity_status())]
+        pub fn appeal_entity_status(
+            origin: OriginFor<T>,
+            entity: EntityIdOf<T>,
+            scope: SpaceId,
+            reason: Content,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Spaces::<T>::ensure_space_exists(scope)?;
+            ensure_content_is_valid(reason.clone())?;
+            ensure!(
+                Self::status_by_entity_in_space(scope, &entity) == Some(EntityStatus::Blocked),
+                Error::<T>::EntityIsNotBlocked
+            );
+            Self::ensure_can_appeal(&who, &entity)?;
+
+            ensure!(
+                !AppealIdByAccount::<T>::contains_key((entity.clone(), scope), &who),
+                Error::<T>::AlreadyAppealedEntity
+            );
+
+            let appeal_id = Self::next_appeal_id();
+            AppealIdsBySpaceId::<T>::try_mutate(scope, |appeal_ids| {
+                appeal_ids.try_push(appeal_id).map_err(|_| Error::<T>::TooManyOpenAppeals)
+            })?;
+
+            let deposit = T::AppealDeposit::get();
+            <T as Config>::Currency::reserve(&who, deposit)?;
+
+            let appeal = Appeal {
+                id: appeal_id,
+                created: new_who_and_when::<T>(who.clone()),
+                entity: entity.clone(),
+                scope,
+                reason,
+                deposit,
+            };
+
+            AppealById::<T>::insert(appeal_id, appeal);
+            AppealIdByAccount::<T>::insert((entity.clone(), scope), &who, appeal_id);
+            NextAppealId::<T>::mutate(|n| *n += 1);
+
+            Self::deposit_event(Event::EntityStatusAppealed {
+                account: who,
+                entity,
+                scope,
+                appeal_id,
+            });
+            Ok(())
+        }
+
+        /// Decide on an appeal. If the appeal is accepted, the status of the entity is removed,
+        /// the deposit is returned to the appellant and other open appeals against this status
+        /// are closed with their deposits returned. Otherwise, the deposit is slashed.
+        /// The decision is recorded in the moderation history of the entity along with `reason`.
+        #[pallet::call_index(12)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::resolve_appeal(T::MaxOpenAppealsPerSpace::get())
+        )]
+        pub fn resolve_appeal(
+            origin: OriginFor<T>,
+            appeal_id: AppealId,
+            accepted: bool,
+            reason: Content,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let appeal = Self::require_appeal(appeal_id)?;
+            Self::ensure_can_update_statuses_in_space(&who, appeal.scope)?;
+            ensure_content_is_valid(reason.clone())?;
+            ensure!(
+                Self::status_by_entity_in_space(appeal.scope, &appeal.entity) ==
+                    Some(EntityStatus::Blocked),
+                Error::<T>::EntityIsNotBlocked
+            );
+
+            let appellant = &appeal.created.account;
+            AppealIdsBySpaceId::<T>::mutate(appeal.scope, |appeal_ids| {
+                appeal_ids.retain(|id| *id != appeal_id)
+            });
+            AppealIdByAccount::<T>::remove((appeal.entity.clone(), appeal.scope), appellant);
+            AppealById::<T>::remove(appeal_id);
+
+            let decision = if accepted {
+                <T as Config>::Currency::unreserve(appellant, appeal.deposit);
+                StatusByEntityInSpace::<T>::remove(appeal.scope, &appeal.entity);
+                ModerationDecision::AppealAccepted(appeal_id)
+            } else {
+                let _ = <T as Config>::Currency::slash_reserved(appellant, appeal.deposit);
+                ModerationDecision::AppealRejected(appeal_id)
+            };
+
+            Self::record_moderation_decision(
+                who.clone(),
+                &appeal.entity,
+                appeal.scope,
+                decision,
+                reason,
+            );
+
+            let closed_appeals =
+                if accepted { Self::close_entity_appeals(&appeal.entity, appeal.scope) } else { 0 };
+
+            Self::deposit_event(Event::AppealResolved { account: who, appeal_id, accepted });
+            Ok(Some(<T as Config>::WeightInfo::resolve_appeal(closed_appeals)).into())
+        }
+
+        /// Forget the first report of the caller and return the deposit reserved for it.
+        ///
+        /// The reporting age of the caller starts over with their next report.
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as Config>::WeightInfo::forget_reporting_age())]
+        pub fn forget_reporting_age(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let first_report =
+                FirstReportByAccount::<T>::take(&who).ok_or(Error::<T>::ReportingAgeNotFound)?;
+            <T as Config>::Currency::unreserve(&who, first_report.deposit);
+
+            Self::deposit_event(Event::ReportingAgeForgotten { account: who });
+            Ok(())
+        }
+    }
+}
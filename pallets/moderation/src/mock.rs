@@ -0,0 +1,261 @@
+// Copyright (C) DAPPFORCE PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
+//
+// Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
+// Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
+
+use frame_support::{dispatch::DispatchResult, ensure, parameter_types, traits::Everything};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_io::TestExternalities;
+use sp_runtime::{traits::{BlakeTwo256, IdentityLookup}, BuildStorage, DispatchError};
+
+use pallet_permissions::{
+    default_permissions::DefaultSpacePermissions, PermissionChecker, SpacePermission,
+    SpacePermissionsContext,
+};
+use subsocial_support::{SpaceId, User};
+
+pub(crate) use crate as pallet_moderation;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub(super) type AccountId = u64;
+pub(super) type Balance = u64;
+
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Timestamp: pallet_timestamp,
+        Balances: pallet_balances,
+        Permissions: pallet_permissions,
+        Spaces: pallet_spaces,
+        SpaceFollows: pallet_space_follows,
+        Posts: pallet_posts,
+        Roles: pallet_roles,
+        Moderation: pallet_moderation,
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u64 = 250;
+    pub const SS58Prefix: u8 = 42;
+}
+
+impl frame_system::Config for Test {
+    type BaseCallFilter = Everything;
+    type BlockWeights = ();
+    type BlockLength = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeCall = RuntimeCall;
+    type Nonce = u64;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type Block = Block;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type DbWeight = ();
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+}
+
+parameter_types! {
+    pub const MinimumPeriod: u64 = 5;
+}
+
+impl pallet_timestamp::Config for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = MinimumPeriod;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Test {
+    type Balance = Balance;
+    type DustRemoval = ();
+    type RuntimeEvent = RuntimeEvent;
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type ReserveIdentifier = ();
+    type MaxHolds = ();
+    type MaxFreezes = ();
+    type FreezeIdentifier = ();
+    type RuntimeHoldReason = ();
+}
+
+impl pallet_permissions::Config for Test {
+    type DefaultSpacePermissions = DefaultSpacePermissions;
+}
+
+pub struct DefaultPermissionChecker;
+
+impl PermissionChecker for DefaultPermissionChecker {
+    type AccountId = AccountId;
+
+    fn ensure_user_has_space_permission(
+        _user: User<Self::AccountId>,
+        ctx: SpacePermissionsContext,
+        permission: SpacePermission,
+        error: DispatchError,
+    ) -> DispatchResult {
+        ensure!(Permissions::has_user_a_space_permission(ctx, permission).unwrap_or(false), error);
+        Ok(())
+    }
+
+    fn has_user_role(_user: User<Self::AccountId>, _space_id: SpaceId, _role_id: u64) -> bool {
+        false
+    }
+}
+
+parameter_types! {
+    pub const MaxSpacesPerAccount: u32 = 4096;
+    pub const MaxSubspaceDepth: u32 = 10;
+    pub const MinHandleLen: u32 = 5;
+    pub const MaxHandleLen: u32 = 50;
+}
+
+impl pallet_spaces::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Roles = DefaultPermissionChecker;
+    type SpaceFollows = SpaceFollows;
+    type IsAccountBlocked = Moderation;
+    type IsContentBlocked = Moderation;
+    type MaxSpacesPerAccount = MaxSpacesPerAccount;
+    type MaxSubspaceDepth = MaxSubspaceDepth;
+    type DomainsProvider = ();
+    type MinHandleLen = MinHandleLen;
+    type MaxHandleLen = MaxHandleLen;
+    type ProfileManager = ();
+    type CreatorStakingProvider = ();
+    type SpaceDeletionHandler = (Posts, SpaceFollows, Moderation);
+    type SpaceSettingsRegistry = Posts;
+    type Currency = Balances;
+    type SpaceDeposit = frame_support::traits::ConstU64<0>;
+    type MaxSpaceCoOwners = frame_support::traits::ConstU32<3>;
+    type WeightInfo = ();
+}
+
+impl pallet_space_follows::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type FollowDeposit = frame_support::traits::ConstU64<0>;
+    type InvitationDeposit = frame_support::traits::ConstU64<0>;
+    type WeightInfo = ();
+}
+
+parameter_types! {
+    pub const MaxCommentDepth: u32 = 10;
+}
+
+impl pallet_posts::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type MaxCommentDepth = MaxCommentDepth;
+    type IsPostBlocked = Moderation;
+    type PostDeletionHandler = ();
+    type PostVisibilityHandler = Moderation;
+    type PostDeposit = frame_support::traits::ConstU64<0>;
+    type MaxPinnedPostsPerSpace = frame_support::traits::ConstU32<3>;
+    type MaxPostRevisions = frame_support::traits::ConstU32<3>;
+    type MaxScheduledPostsPerBlock = frame_support::traits::ConstU32<3>;
+    type MaxPollOptions = frame_support::traits::ConstU32<3>;
+    type BackerStakesProvider = ();
+    type WeightInfo = ();
+}
+
+impl pallet_roles::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type MaxUsersToProcessPerDeleteRole = frame_support::traits::ConstU16<40>;
+    type SpacePermissionsProvider = Spaces;
+    type SpaceFollows = SpaceFollows;
+    type IsAccountBlocked = Moderation;
+    type IsContentBlocked = Moderation;
+    type Currency = Balances;
+    type RoleDeposit = frame_support::traits::ConstU64<0>;
+    type WeightInfo = ();
+}
+
+pub(crate) const REPORT_DEPOSIT: Balance = 10;
+pub(crate) const REPORTING_AGE_DEPOSIT: Balance = 2;
+pub(crate) const APPEAL_DEPOSIT: Balance = 5;
+
+parameter_types! {
+    pub const ReportDeposit: Balance = REPORT_DEPOSIT;
+    pub const ReportingAgeDeposit: Balance = REPORTING_AGE_DEPOSIT;
+    pub static MaxOpenReportsPerSpace: u32 = 10;
+    pub const MaxSubscribedListsPerSpace: u32 = 2;
+    pub const AppealDeposit: Balance = APPEAL_DEPOSIT;
+    pub const MaxOpenAppealsPerSpace: u32 = 10;
+    pub const MaxModerationHistoryLength: u32 = 3;
+}
+
+impl pallet_moderation::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type GlobalModerationOrigin = EnsureRoot<AccountId>;
+    type Currency = Balances;
+    type ReportDeposit = ReportDeposit;
+    type ReportingAgeDeposit = ReportingAgeDeposit;
+    type MaxOpenReportsPerSpace = MaxOpenReportsPerSpace;
+    type MaxSubscribedListsPerSpace = MaxSubscribedListsPerSpace;
+    type AppealDeposit = AppealDeposit;
+    type MaxOpenAppealsPerSpace = MaxOpenAppealsPerSpace;
+    type MaxModerationHistoryLength = MaxModerationHistoryLength;
+    type WeightInfo = ();
+}
+
+pub struct ExtBuilder {
+    balances: Vec<(AccountId, Balance)>,
+    max_open_reports_per_space: u32,
+}
+
+impl Default for ExtBuilder {
+    fn default() -> Self {
+        ExtBuilder { balances: Vec::new(), max_open_reports_per_space: 10 }
+    }
+}
+
+impl ExtBuilder {
+    pub(crate) fn balances(mut self, balances: Vec<(AccountId, Balance)>) -> Self {
+        self.balances = balances;
+        self
+    }
+
+    pub(crate) fn max_open_reports_per_space(mut self, max_open_reports_per_space: u32) -> Self {
+        self.max_open_reports_per_space = max_open_reports_per_space;
+        self
+    }
+
+    fn set_configs(&self) {
+        MAX_OPEN_REPORTS_PER_SPACE.with(|x| *x.borrow_mut() = self.max_open_reports_per_space);
+    }
+
+    pub(crate) fn build(self) -> TestExternalities {
+        self.set_configs();
+
+        let mut storage = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+        pallet_balances::GenesisConfig::<Test> { balances: self.balances }
+            .assimilate_storage(&mut storage)
+            .unwrap();
+
+        let mut ext: TestExternalities = storage.into();
+        ext.execute_with(|| {
+            System::set_block_number(1);
+        });
+
+        ext
+    }
+}
@@ -0,0 +1,1389 @@
+// Copyright (C) DAPPFORCE PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
+//
+// Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
+// Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
+
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight};
+use sp_runtime::DispatchError;
+
+use pallet_permissions::SpacePermission;
+use pallet_posts::PostUpdate;
+use pallet_roles::RoleUpdate;
+use pallet_spaces::types::SpaceUpdate;
+use subsocial_support::{
+    mock_functions::{another_valid_content_ipfs, valid_content_ipfs},
+    traits::{IsAccountBlocked, IsContentBlocked, IsPostBlocked, IsSpaceBlocked},
+    Content, ModerationError, PostId, SpaceId,
+};
+
+use crate::{
+    mock::*, AppealById, AppealId, AppealIdByAccount, AppealIdsBySpaceId, AutoHiddenPostScope,
+    AutoHideReason, AutoHideSettings, EntityId, EntityStatus, Error, Event, GlobalStatusByEntity,
+    ModerationDecision, ModerationListById, ModerationListId, ModerationListIdsBySpaceId,
+    ReportById, ReportId, ModerationHistory, ReportIdByAccount, ReportIdsBySpaceId,
+    StatusByEntityInList, StatusByEntityInSpace, SubscribedListIdsBySpaceId,
+    SuggestedStatusByAccount,
+};
+
+const SPACE_OWNER: AccountId = 1;
+const ACCOUNT: AccountId = 2;
+const REPORTER: AccountId = 3;
+const BALANCE: Balance = 100;
+
+const REPORTERS: [AccountId; 3] = [5, 6, 7];
+
+fn create_space(owner: AccountId) -> SpaceId {
+    let space_id = pallet_spaces::NextSpaceId::<Test>::get();
+    assert_ok!(Spaces::create_space(RuntimeOrigin::signed(owner), Content::None, None));
+    space_id
+}
+
+fn create_post(owner: AccountId, space_id: SpaceId, content: Content) -> Result<PostId, DispatchError> {
+    let post_id = pallet_posts::NextPostId::<Test>::get();
+    Posts::create_post(
+        RuntimeOrigin::signed(owner),
+        Some(space_id),
+        pallet_posts::PostExtension::RegularPost,
+        content,
+    )?;
+    Ok(post_id)
+}
+
+fn block(entity: EntityId<AccountId>, scope: SpaceId) {
+    assert_ok!(Moderation::update_entity_status(
+        RuntimeOrigin::signed(SPACE_OWNER),
+        entity,
+        scope,
+        Some(EntityStatus::Blocked),
+        Content::None,
+    ));
+}
+
+#[test]
+fn update_entity_status_should_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let entity = EntityId::Account(ACCOUNT);
+
+        block(entity.clone(), space_id);
+
+        assert_eq!(StatusByEntityInSpace::<Test>::get(space_id, &entity), Some(EntityStatus::Blocked));
+        System::assert_last_event(
+            Event::EntityStatusUpdated {
+                account: SPACE_OWNER,
+                entity,
+                scope: space_id,
+                status: Some(EntityStatus::Blocked),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn update_entity_status_should_remove_status_when_none_is_given() {
+    ExtBuilder::default().build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let entity = EntityId::Account(ACCOUNT);
+
+        block(entity.clone(), space_id);
+        assert_ok!(Moderation::update_entity_status(
+            RuntimeOrigin::signed(SPACE_OWNER),
+            entity.clone(),
+            space_id,
+            None,
+            Content::None,
+        ));
+
+        assert!(!StatusByEntityInSpace::<Test>::contains_key(space_id, &entity));
+        assert_noop!(
+            Moderation::update_entity_status(
+                RuntimeOrigin::signed(SPACE_OWNER),
+                entity,
+                space_id,
+                None,
+                Content::None,
+            ),
+            Error::<Test>::EntityHasNoStatus
+        );
+    });
+}
+
+#[test]
+fn update_entity_status_should_fail_when_caller_has_no_permission() {
+    ExtBuilder::default().build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+
+        assert_noop!(
+            Moderation::update_entity_status(
+                RuntimeOrigin::signed(ACCOUNT),
+                EntityId::Account(3),
+                space_id,
+                Some(EntityStatus::Blocked),
+                Content::None,
+            ),
+            Error::<Test>::NoPermissionToUpdateEntityStatus
+        );
+    });
+}
+
+#[test]
+fn update_entity_status_should_fail_for_invalid_targets() {
+    ExtBuilder::default().build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let update = |entity| {
+            Moderation::update_entity_status(
+                RuntimeOrigin::signed(SPACE_OWNER),
+                entity,
+                space_id,
+                Some(EntityStatus::Blocked),
+                Content::None,
+            )
+        };
+
+        assert_noop!(update(EntityId::Account(SPACE_OWNER)), Error::<Test>::CannotModerateSpaceOwner);
+        assert_noop!(update(EntityId::Space(space_id)), Error::<Test>::SpaceCannotModerateItself);
+        assert_noop!(update(EntityId::Content(Content::None)), Error::<Test>::ContentIsEmpty);
+        assert_noop!(update(EntityId::Post(999)), pallet_posts::Error::<Test>::PostNotFound);
+        assert_noop!(
+            Moderation::update_entity_status(
+                RuntimeOrigin::signed(SPACE_OWNER),
+                EntityId::Account(ACCOUNT),
+                999,
+                Some(EntityStatus::Blocked),
+                Content::None,
+            ),
+            pallet_spaces::Error::<Test>::SpaceNotFound
+        );
+    });
+}
+
+#[test]
+fn suggest_entity_status_should_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let entity = EntityId::Account(ACCOUNT);
+
+        assert_ok!(Moderation::suggest_entity_status(
+            RuntimeOrigin::signed(SPACE_OWNER),
+            entity.clone(),
+            space_id,
+            Some(EntityStatus::Blocked),
+        ));
+
+        let suggestion = SuggestedStatusByAccount::<Test>::get(space_id, (entity.clone(), SPACE_OWNER))
+            .expect("status should be suggested");
+        assert_eq!(suggestion.status, Some(EntityStatus::Blocked));
+        assert_eq!(suggestion.suggested.account, SPACE_OWNER);
+
+        // A suggestion doesn't change the actual status.
+        assert!(!StatusByEntityInSpace::<Test>::contains_key(space_id, &entity));
+    });
+}
+
+#[test]
+fn suggest_entity_status_should_fail_when_caller_has_no_permission() {
+    ExtBuilder::default().build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+
+        assert_noop!(
+            Moderation::suggest_entity_status(
+                RuntimeOrigin::signed(ACCOUNT),
+                EntityId::Account(3),
+                space_id,
+                Some(EntityStatus::Blocked),
+            ),
+            Error::<Test>::NoPermissionToSuggestEntityStatus
+        );
+    });
+}
+
+#[test]
+fn moderation_traits_should_reflect_entity_statuses() {
+    ExtBuilder::default().build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let other_space_id = create_space(ACCOUNT);
+        let post_id = create_post(SPACE_OWNER, space_id, Content::None).unwrap();
+
+        block(EntityId::Account(ACCOUNT), space_id);
+        block(EntityId::Space(other_space_id), space_id);
+        block(EntityId::Post(post_id), space_id);
+        block(EntityId::Content(valid_content_ipfs()), space_id);
+
+        assert!(Moderation::is_blocked_account(ACCOUNT, space_id));
+        assert!(Moderation::is_blocked_space(other_space_id, space_id));
+        assert!(Moderation::is_blocked_post(post_id, space_id));
+        assert!(Moderation::is_blocked_content(valid_content_ipfs(), space_id));
+        assert!(Moderation::is_allowed_content(Content::None, space_id));
+
+        // Statuses are scoped to a space.
+        assert!(Moderation::is_allowed_account(ACCOUNT, other_space_id));
+        assert!(Moderation::is_allowed_post(post_id, other_space_id));
+    });
+}
+
+#[test]
+fn blocked_account_and_content_should_be_rejected_by_posts() {
+    ExtBuilder::default().build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+
+        block(EntityId::Content(valid_content_ipfs()), space_id);
+        assert_noop!(
+            create_post(SPACE_OWNER, space_id, valid_content_ipfs()),
+            DispatchError::from(ModerationError::ContentIsBlocked)
+        );
+
+        block(EntityId::Account(ACCOUNT), space_id);
+        assert_noop!(
+            create_post(ACCOUNT, space_id, Content::None),
+            DispatchError::from(ModerationError::AccountIsBlocked)
+        );
+        assert_noop!(
+            Moderation::suggest_entity_status(
+                RuntimeOrigin::signed(ACCOUNT),
+                EntityId::Account(3),
+                space_id,
+                Some(EntityStatus::Blocked),
+            ),
+            DispatchError::from(ModerationError::AccountIsBlocked)
+        );
+    });
+}
+
+#[test]
+fn blocked_account_should_not_follow_space_on_behalf_of_another_space() {
+    ExtBuilder::default().build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let follower_space_id = create_space(ACCOUNT);
+
+        block(EntityId::Account(ACCOUNT), space_id);
+        assert_noop!(
+            SpaceFollows::follow_space_as_space(
+                RuntimeOrigin::signed(ACCOUNT),
+                follower_space_id,
+                space_id,
+            ),
+            DispatchError::from(ModerationError::AccountIsBlocked)
+        );
+    });
+}
+
+fn report(reporter: AccountId, entity: EntityId<AccountId>, scope: SpaceId) -> ReportId {
+    let report_id = Moderation::next_report_id();
+    assert_ok!(Moderation::report_entity(
+        RuntimeOrigin::signed(reporter),
+        entity,
+        scope,
+        valid_content_ipfs(),
+    ));
+    report_id
+}
+
+#[test]
+fn report_entity_should_work() {
+    ExtBuilder::default().balances(vec![(REPORTER, BALANCE)]).build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let entity = EntityId::Account(ACCOUNT);
+
+        let report_id = report(REPORTER, entity.clone(), space_id);
+
+        let report = ReportById::<Test>::get(report_id).expect("report should be created");
+        assert_eq!(report.created.account, REPORTER);
+        assert_eq!(report.reported_entity, entity);
+        assert_eq!(report.reported_within, space_id);
+        assert_eq!(report.reason, valid_content_ipfs());
+        assert_eq!(report.deposit, REPORT_DEPOSIT);
+
+        assert_eq!(ReportIdsBySpaceId::<Test>::get(space_id).into_inner(), vec![report_id]);
+        assert_eq!(ReportIdByAccount::<Test>::get((entity.clone(), space_id), REPORTER), Some(report_id));
+        assert_eq!(Balances::reserved_balance(REPORTER), REPORT_DEPOSIT + REPORTING_AGE_DEPOSIT);
+        assert_eq!(Moderation::first_report_by_account(REPORTER).map(|r| r.block), Some(1));
+        assert_eq!(Moderation::get_open_reports(space_id), vec![report]);
+
+        System::assert_last_event(
+            Event::EntityReported { account: REPORTER, entity, scope: space_id, report_id }.into(),
+        );
+    });
+}
+
+#[test]
+fn report_entity_should_fail_when_entity_already_reported_by_account() {
+    ExtBuilder::default().balances(vec![(REPORTER, BALANCE)]).build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        report(REPORTER, EntityId::Account(ACCOUNT), space_id);
+
+        assert_noop!(
+            Moderation::report_entity(
+                RuntimeOrigin::signed(REPORTER),
+                EntityId::Account(ACCOUNT),
+                space_id,
+                Content::None,
+            ),
+            Error::<Test>::AlreadyReportedEntity
+        );
+    });
+}
+
+#[test]
+fn report_entity_should_fail_when_queue_is_full() {
+    ExtBuilder::default()
+        .balances(vec![(REPORTER, BALANCE)])
+        .max_open_reports_per_space(1)
+        .build()
+        .execute_with(|| {
+            let space_id = create_space(SPACE_OWNER);
+            report(REPORTER, EntityId::Account(ACCOUNT), space_id);
+
+            assert_noop!(
+                Moderation::report_entity(
+                    RuntimeOrigin::signed(REPORTER),
+                    EntityId::Account(4),
+                    space_id,
+                    Content::None,
+                ),
+                Error::<Test>::TooManyOpenReports
+            );
+        });
+}
+
+#[test]
+fn report_entity_should_fail_when_reporter_cannot_pay_deposit() {
+    ExtBuilder::default().build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+
+        assert_noop!(
+            Moderation::report_entity(
+                RuntimeOrigin::signed(REPORTER),
+                EntityId::Account(ACCOUNT),
+                space_id,
+                Content::None,
+            ),
+            pallet_balances::Error::<Test>::InsufficientBalance
+        );
+    });
+}
+
+#[test]
+fn forget_reporting_age_should_release_deposit() {
+    ExtBuilder::default().balances(vec![(REPORTER, BALANCE)]).build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let report_id = report(REPORTER, EntityId::Account(ACCOUNT), space_id);
+        assert_ok!(Moderation::resolve_report(RuntimeOrigin::signed(SPACE_OWNER), report_id, true));
+
+        assert_ok!(Moderation::forget_reporting_age(RuntimeOrigin::signed(REPORTER)));
+
+        assert!(Moderation::first_report_by_account(REPORTER).is_none());
+        assert_eq!(Balances::reserved_balance(REPORTER), 0);
+        assert_eq!(Balances::free_balance(REPORTER), BALANCE);
+        System::assert_last_event(Event::ReportingAgeForgotten { account: REPORTER }.into());
+
+        assert_noop!(
+            Moderation::forget_reporting_age(RuntimeOrigin::signed(REPORTER)),
+            Error::<Test>::ReportingAgeNotFound
+        );
+    });
+}
+
+#[test]
+fn resolve_report_should_return_deposit_when_upheld() {
+    ExtBuilder::default().balances(vec![(REPORTER, BALANCE)]).build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let entity = EntityId::Account(ACCOUNT);
+        let report_id = report(REPORTER, entity.clone(), space_id);
+
+        assert_ok!(Moderation::resolve_report(RuntimeOrigin::signed(SPACE_OWNER), report_id, true));
+
+        assert!(!ReportById::<Test>::contains_key(report_id));
+        assert!(ReportIdsBySpaceId::<Test>::get(space_id).is_empty());
+        assert!(!ReportIdByAccount::<Test>::contains_key((entity, space_id), REPORTER));
+        assert_eq!(Balances::reserved_balance(REPORTER), REPORTING_AGE_DEPOSIT);
+        assert_eq!(Balances::free_balance(REPORTER), BALANCE - REPORTING_AGE_DEPOSIT);
+
+        System::assert_last_event(
+            Event::ReportResolved { account: SPACE_OWNER, report_id, upheld: true }.into(),
+        );
+    });
+}
+
+#[test]
+fn resolve_report_should_slash_deposit_when_dismissed() {
+    ExtBuilder::default().balances(vec![(REPORTER, BALANCE)]).build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let report_id = report(REPORTER, EntityId::Account(ACCOUNT), space_id);
+
+        assert_ok!(Moderation::resolve_report(RuntimeOrigin::signed(SPACE_OWNER), report_id, false));
+
+        assert_eq!(Balances::reserved_balance(REPORTER), REPORTING_AGE_DEPOSIT);
+        assert_eq!(
+            Balances::free_balance(REPORTER),
+            BALANCE - REPORT_DEPOSIT - REPORTING_AGE_DEPOSIT
+        );
+        assert_eq!(Balances::total_issuance(), BALANCE - REPORT_DEPOSIT);
+    });
+}
+
+#[test]
+fn resolve_report_should_fail_when_caller_is_not_moderator() {
+    ExtBuilder::default().balances(vec![(REPORTER, BALANCE)]).build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let report_id = report(REPORTER, EntityId::Account(ACCOUNT), space_id);
+
+        assert_noop!(
+            Moderation::resolve_report(RuntimeOrigin::signed(REPORTER), report_id, true),
+            Error::<Test>::NoPermissionToResolveReports
+        );
+        assert_noop!(
+            Moderation::resolve_report(RuntimeOrigin::signed(SPACE_OWNER), report_id + 1, true),
+            Error::<Test>::ReportNotFound
+        );
+    });
+}
+
+fn block_globally(entity: EntityId<AccountId>) {
+    assert_ok!(Moderation::update_global_entity_status(
+        RuntimeOrigin::root(),
+        entity,
+        Some(EntityStatus::Blocked),
+    ));
+}
+
+#[test]
+fn update_global_entity_status_should_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let entity = EntityId::Content(valid_content_ipfs());
+
+        block_globally(entity.clone());
+        assert_eq!(GlobalStatusByEntity::<Test>::get(&entity), Some(EntityStatus::Blocked));
+        System::assert_last_event(
+            Event::GlobalEntityStatusUpdated {
+                entity: entity.clone(),
+                status: Some(EntityStatus::Blocked),
+            }
+            .into(),
+        );
+
+        assert_ok!(Moderation::update_global_entity_status(RuntimeOrigin::root(), entity.clone(), None));
+        assert!(!GlobalStatusByEntity::<Test>::contains_key(&entity));
+        assert_noop!(
+            Moderation::update_global_entity_status(RuntimeOrigin::root(), entity, None),
+            Error::<Test>::EntityHasNoGlobalStatus
+        );
+    });
+}
+
+#[test]
+fn update_global_entity_status_should_fail_when_origin_is_not_allowed() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_noop!(
+            Moderation::update_global_entity_status(
+                RuntimeOrigin::signed(SPACE_OWNER),
+                EntityId::Account(ACCOUNT),
+                Some(EntityStatus::Blocked),
+            ),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn globally_blocked_entities_should_be_blocked_in_every_space() {
+    ExtBuilder::default().build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+
+        block_globally(EntityId::Account(ACCOUNT));
+        block_globally(EntityId::Content(valid_content_ipfs()));
+
+        // Even an explicit allow in a space doesn't override a global block.
+        assert_ok!(Moderation::update_entity_status(
+            RuntimeOrigin::signed(SPACE_OWNER),
+            EntityId::Account(ACCOUNT),
+            space_id,
+            Some(EntityStatus::Allowed),
+            Content::None,
+        ));
+
+        assert!(Moderation::is_blocked_account(ACCOUNT, space_id));
+        assert!(Moderation::is_blocked_content(valid_content_ipfs(), space_id));
+
+        assert_noop!(
+            create_post(SPACE_OWNER, space_id, valid_content_ipfs()),
+            DispatchError::from(ModerationError::ContentIsBlocked)
+        );
+        assert_noop!(
+            Spaces::create_space(RuntimeOrigin::signed(ACCOUNT), Content::None, None),
+            DispatchError::from(ModerationError::AccountIsBlocked)
+        );
+        assert_noop!(
+            Spaces::create_space(RuntimeOrigin::signed(SPACE_OWNER), valid_content_ipfs(), None),
+            DispatchError::from(ModerationError::ContentIsBlocked)
+        );
+    });
+}
+
+fn create_role(owner: AccountId, space_id: SpaceId) -> u64 {
+    let role_id = pallet_roles::NextRoleId::<Test>::get();
+    assert_ok!(Roles::create_role(
+        RuntimeOrigin::signed(owner),
+        space_id,
+        None,
+        Content::None,
+        vec![SpacePermission::ManageRoles],
+    ));
+    role_id
+}
+
+#[test]
+fn globally_blocked_account_should_not_update_spaces_posts_or_roles() {
+    ExtBuilder::default().build().execute_with(|| {
+        let space_id = create_space(ACCOUNT);
+        let post_id = create_post(ACCOUNT, space_id, valid_content_ipfs()).unwrap();
+        let role_id = create_role(ACCOUNT, space_id);
+
+        block_globally(EntityId::Account(ACCOUNT));
+
+        assert_noop!(
+            Spaces::update_space(
+                RuntimeOrigin::signed(ACCOUNT),
+                space_id,
+                SpaceUpdate { content: None, hidden: Some(true), permissions: None },
+            ),
+            DispatchError::from(ModerationError::AccountIsBlocked)
+        );
+        assert_noop!(
+            Posts::update_post(
+                RuntimeOrigin::signed(ACCOUNT),
+                post_id,
+                PostUpdate { space_id: None, content: None, hidden: Some(true) },
+            ),
+            DispatchError::from(ModerationError::AccountIsBlocked)
+        );
+        assert_noop!(
+            Roles::create_role(
+                RuntimeOrigin::signed(ACCOUNT),
+                space_id,
+                None,
+                Content::None,
+                vec![SpacePermission::ManageRoles],
+            ),
+            DispatchError::from(ModerationError::AccountIsBlocked)
+        );
+        assert_noop!(
+            Roles::update_role(
+                RuntimeOrigin::signed(ACCOUNT),
+                role_id,
+                RoleUpdate { disabled: Some(true), content: None, permissions: None },
+            ),
+            DispatchError::from(ModerationError::AccountIsBlocked)
+        );
+    });
+}
+
+#[test]
+fn globally_blocked_content_should_not_be_set_on_spaces_posts_or_roles() {
+    ExtBuilder::default().build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let post_id = create_post(SPACE_OWNER, space_id, another_valid_content_ipfs()).unwrap();
+        let role_id = create_role(SPACE_OWNER, space_id);
+
+        block_globally(EntityId::Content(valid_content_ipfs()));
+
+        assert_noop!(
+            Spaces::update_space(
+                RuntimeOrigin::signed(SPACE_OWNER),
+                space_id,
+                SpaceUpdate {
+                    content: Some(valid_content_ipfs()),
+                    hidden: None,
+                    permissions: None
+                },
+            ),
+            DispatchError::from(ModerationError::ContentIsBlocked)
+        );
+        assert_noop!(
+            Posts::update_post(
+                RuntimeOrigin::signed(SPACE_OWNER),
+                post_id,
+                PostUpdate { space_id: None, content: Some(valid_content_ipfs()), hidden: None },
+            ),
+            DispatchError::from(ModerationError::ContentIsBlocked)
+        );
+        assert_noop!(
+            Roles::create_role(
+                RuntimeOrigin::signed(SPACE_OWNER),
+                space_id,
+                None,
+                valid_content_ipfs(),
+                vec![SpacePermission::ManageRoles],
+            ),
+            DispatchError::from(ModerationError::ContentIsBlocked)
+        );
+        assert_noop!(
+            Roles::update_role(
+                RuntimeOrigin::signed(SPACE_OWNER),
+                role_id,
+                RoleUpdate {
+                    disabled: None,
+                    content: Some(valid_content_ipfs()),
+                    permissions: None
+                },
+            ),
+            DispatchError::from(ModerationError::ContentIsBlocked)
+        );
+    });
+}
+
+const LIST_OWNER: AccountId = 4;
+
+fn create_list_blocking(entity: EntityId<AccountId>) -> (SpaceId, ModerationListId) {
+    let list_space_id = create_space(LIST_OWNER);
+    let list_id = Moderation::next_moderation_list_id();
+
+    assert_ok!(Moderation::create_moderation_list(
+        RuntimeOrigin::signed(LIST_OWNER),
+        list_space_id,
+        Content::None,
+    ));
+    assert_ok!(Moderation::update_list_entity_status(
+        RuntimeOrigin::signed(LIST_OWNER),
+        list_id,
+        entity,
+        Some(EntityStatus::Blocked),
+    ));
+
+    (list_space_id, list_id)
+}
+
+fn subscribe(space_id: SpaceId, list_id: ModerationListId) {
+    assert_ok!(Moderation::subscribe_to_moderation_list(
+        RuntimeOrigin::signed(SPACE_OWNER),
+        space_id,
+        list_id,
+    ));
+}
+
+#[test]
+fn create_moderation_list_should_work() {
+    ExtBuilder::default().build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let list_id = Moderation::next_moderation_list_id();
+
+        assert_ok!(Moderation::create_moderation_list(
+            RuntimeOrigin::signed(SPACE_OWNER),
+            space_id,
+            valid_content_ipfs(),
+        ));
+
+        let list = ModerationListById::<Test>::get(list_id).expect("list should be created");
+        assert_eq!(list.owner_space, space_id);
+        assert_eq!(list.content, valid_content_ipfs());
+        System::assert_last_event(
+            Event::ModerationListCreated { account: SPACE_OWNER, list_id, owner_space: space_id }.into(),
+        );
+
+        assert_noop!(
+            Moderation::create_moderation_list(RuntimeOrigin::signed(ACCOUNT), space_id, Content::None),
+            Error::<Test>::NoPermissionToUpdateEntityStatus
+        );
+    });
+}
+
+#[test]
+fn update_list_entity_status_should_fail_when_caller_is_not_list_moderator() {
+    ExtBuilder::default().build().execute_with(|| {
+        let (_, list_id) = create_list_blocking(EntityId::Account(ACCOUNT));
+
+        assert_noop!(
+            Moderation::update_list_entity_status(
+                RuntimeOrigin::signed(SPACE_OWNER),
+                list_id,
+                EntityId::Account(REPORTER),
+                Some(EntityStatus::Blocked),
+            ),
+            Error::<Test>::NoPermissionToUpdateEntityStatus
+        );
+        assert_noop!(
+            Moderation::update_list_entity_status(
+                RuntimeOrigin::signed(LIST_OWNER),
+                list_id + 1,
+                EntityId::Account(REPORTER),
+                Some(EntityStatus::Blocked),
+            ),
+            Error::<Test>::ModerationListNotFound
+        );
+    });
+}
+
+#[test]
+fn subscribed_lists_should_be_consulted_by_lookups() {
+    ExtBuilder::default().build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let (_, list_id) = create_list_blocking(EntityId::Account(ACCOUNT));
+
+        assert!(Moderation::is_allowed_account(ACCOUNT, space_id));
+
+        subscribe(space_id, list_id);
+        assert_eq!(SubscribedListIdsBySpaceId::<Test>::get(space_id).into_inner(), vec![list_id]);
+        assert!(Moderation::is_space_subscribed_to_list(list_id, space_id));
+        assert!(Moderation::is_blocked_account(ACCOUNT, space_id));
+        assert_noop!(
+            create_post(ACCOUNT, space_id, Content::None),
+            DispatchError::from(ModerationError::AccountIsBlocked)
+        );
+
+        assert_ok!(Moderation::unsubscribe_from_moderation_list(
+            RuntimeOrigin::signed(SPACE_OWNER),
+            space_id,
+            list_id,
+        ));
+        assert!(!Moderation::is_space_subscribed_to_list(list_id, space_id));
+        assert!(Moderation::is_allowed_account(ACCOUNT, space_id));
+    });
+}
+
+#[test]
+fn own_status_should_take_precedence_over_subscribed_lists() {
+    ExtBuilder::default().build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let (_, list_id) = create_list_blocking(EntityId::Account(ACCOUNT));
+        subscribe(space_id, list_id);
+
+        assert_ok!(Moderation::update_entity_status(
+            RuntimeOrigin::signed(SPACE_OWNER),
+            EntityId::Account(ACCOUNT),
+            space_id,
+            Some(EntityStatus::Allowed),
+            Content::None,
+        ));
+
+        assert!(Moderation::is_allowed_account(ACCOUNT, space_id));
+    });
+}
+
+#[test]
+fn subscribed_lists_should_not_block_space_owner() {
+    ExtBuilder::default().build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let (_, list_id) = create_list_blocking(EntityId::Account(SPACE_OWNER));
+        subscribe(space_id, list_id);
+
+        assert!(Moderation::is_allowed_account(SPACE_OWNER, space_id));
+    });
+}
+
+#[test]
+fn subscribe_to_moderation_list_should_fail_for_invalid_subscriptions() {
+    ExtBuilder::default().build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let (_, list_id) = create_list_blocking(EntityId::Account(ACCOUNT));
+
+        assert_noop!(
+            Moderation::subscribe_to_moderation_list(RuntimeOrigin::signed(ACCOUNT), space_id, list_id),
+            Error::<Test>::NoPermissionToUpdateEntityStatus
+        );
+        assert_noop!(
+            Moderation::subscribe_to_moderation_list(
+                RuntimeOrigin::signed(SPACE_OWNER),
+                space_id,
+                list_id + 100,
+            ),
+            Error::<Test>::ModerationListNotFound
+        );
+        assert_noop!(
+            Moderation::unsubscribe_from_moderation_list(
+                RuntimeOrigin::signed(SPACE_OWNER),
+                space_id,
+                list_id,
+            ),
+            Error::<Test>::NotSubscribedToList
+        );
+
+        subscribe(space_id, list_id);
+        assert_noop!(
+            Moderation::subscribe_to_moderation_list(RuntimeOrigin::signed(SPACE_OWNER), space_id, list_id),
+            Error::<Test>::AlreadySubscribedToList
+        );
+
+        let (_, second_list_id) = create_list_blocking(EntityId::Account(REPORTER));
+        let (_, third_list_id) = create_list_blocking(EntityId::Account(REPORTER));
+        subscribe(space_id, second_list_id);
+        assert_noop!(
+            Moderation::subscribe_to_moderation_list(
+                RuntimeOrigin::signed(SPACE_OWNER),
+                space_id,
+                third_list_id,
+            ),
+            Error::<Test>::TooManySubscribedLists
+        );
+    });
+}
+
+fn set_auto_hide(space_id: SpaceId, settings: AutoHideSettings<u64>) {
+    assert_ok!(Moderation::set_auto_hide_settings(
+        RuntimeOrigin::signed(SPACE_OWNER),
+        space_id,
+        Some(settings),
+    ));
+}
+
+fn threshold_settings(reporters_threshold: u32) -> AutoHideSettings<u64> {
+    AutoHideSettings { reporters_threshold, min_reporting_age: None, followers_only: false }
+}
+
+fn reporters_balances() -> Vec<(AccountId, Balance)> {
+    REPORTERS.iter().map(|reporter| (*reporter, BALANCE)).collect()
+}
+
+fn is_post_hidden(post_id: PostId) -> bool {
+    Posts::post_by_id(post_id).unwrap().hidden
+}
+
+#[test]
+fn post_should_be_hidden_when_reporters_threshold_is_reached() {
+    ExtBuilder::default().balances(reporters_balances()).build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let post_id = create_post(SPACE_OWNER, space_id, valid_content_ipfs()).unwrap();
+        set_auto_hide(space_id, threshold_settings(2));
+
+        report(REPORTERS[0], EntityId::Post(post_id), space_id);
+        assert!(!is_post_hidden(post_id));
+
+        report(REPORTERS[1], EntityId::Post(post_id), space_id);
+        assert!(is_post_hidden(post_id));
+        assert_eq!(Spaces::space_by_id(space_id).unwrap().hidden_posts_count, 1);
+        assert_eq!(AutoHiddenPostScope::<Test>::get(post_id), Some(space_id));
+        System::assert_has_event(
+            Event::PostAutoHidden {
+                post_id,
+                scope: space_id,
+                reason: AutoHideReason::ReportsThresholdReached { reporters_count: 2 },
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn comment_should_be_hidden_when_reporters_threshold_is_reached() {
+    ExtBuilder::default().balances(reporters_balances()).build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let post_id = create_post(SPACE_OWNER, space_id, valid_content_ipfs()).unwrap();
+        let comment_id = pallet_posts::NextPostId::<Test>::get();
+        assert_ok!(Posts::create_post(
+            RuntimeOrigin::signed(ACCOUNT),
+            None,
+            pallet_posts::PostExtension::Comment(pallet_posts::Comment {
+                root_post_id: post_id,
+                parent_id: None,
+            }),
+            valid_content_ipfs(),
+        ));
+        set_auto_hide(space_id, threshold_settings(1));
+
+        report(REPORTERS[0], EntityId::Post(comment_id), space_id);
+
+        assert!(is_post_hidden(comment_id));
+        assert!(!is_post_hidden(post_id));
+    });
+}
+
+#[test]
+fn reports_should_not_hide_post_when_auto_hide_is_disabled() {
+    ExtBuilder::default().balances(reporters_balances()).build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let post_id = create_post(SPACE_OWNER, space_id, valid_content_ipfs()).unwrap();
+
+        for reporter in REPORTERS {
+            report(reporter, EntityId::Post(post_id), space_id);
+        }
+
+        assert!(!is_post_hidden(post_id));
+        assert_eq!(Moderation::auto_hide_reporters_count(post_id), 0);
+    });
+}
+
+#[test]
+fn only_qualified_reporters_should_count_towards_auto_hide() {
+    ExtBuilder::default().balances(reporters_balances()).build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let post_id = create_post(SPACE_OWNER, space_id, valid_content_ipfs()).unwrap();
+        let other_post_id = create_post(SPACE_OWNER, space_id, valid_content_ipfs()).unwrap();
+        set_auto_hide(
+            space_id,
+            AutoHideSettings {
+                reporters_threshold: 1,
+                min_reporting_age: Some(5),
+                followers_only: true,
+            },
+        );
+        assert_ok!(SpaceFollows::follow_space(RuntimeOrigin::signed(REPORTERS[1]), space_id));
+
+        // The first report of a follower doesn't count, its age starts with it.
+        report(REPORTERS[1], EntityId::Post(other_post_id), space_id);
+        assert!(!is_post_hidden(other_post_id));
+        assert_eq!(Moderation::first_report_by_account(REPORTERS[1]).map(|r| r.block), Some(1));
+
+        // Not a follower of the space.
+        System::set_block_number(10);
+        report(REPORTERS[0], EntityId::Post(post_id), space_id);
+        assert!(!is_post_hidden(post_id));
+
+        // A follower, but the reporter is too young.
+        assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(REPORTERS[2]), ACCOUNT, 20));
+        assert_ok!(SpaceFollows::follow_space(RuntimeOrigin::signed(ACCOUNT), space_id));
+        report(ACCOUNT, EntityId::Post(post_id), space_id);
+        assert!(!is_post_hidden(post_id));
+        assert_eq!(Moderation::auto_hide_reporters_count(post_id), 0);
+
+        report(REPORTERS[1], EntityId::Post(post_id), space_id);
+        assert!(is_post_hidden(post_id));
+    });
+}
+
+#[test]
+fn restore_auto_hidden_post_should_work() {
+    ExtBuilder::default().balances(reporters_balances()).build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let post_id = create_post(SPACE_OWNER, space_id, valid_content_ipfs()).unwrap();
+        set_auto_hide(space_id, threshold_settings(1));
+        report(REPORTERS[0], EntityId::Post(post_id), space_id);
+
+        assert_ok!(Moderation::restore_auto_hidden_post(
+            RuntimeOrigin::signed(SPACE_OWNER),
+            post_id,
+            valid_content_ipfs(),
+        ));
+
+        assert!(!is_post_hidden(post_id));
+        assert_eq!(Spaces::space_by_id(space_id).unwrap().hidden_posts_count, 0);
+        assert!(!AutoHiddenPostScope::<Test>::contains_key(post_id));
+        assert_eq!(Moderation::auto_hide_reporters_count(post_id), 0);
+        System::assert_last_event(
+            Event::AutoHiddenPostRestored {
+                account: SPACE_OWNER,
+                post_id,
+                scope: space_id,
+                reason: valid_content_ipfs(),
+            }
+            .into(),
+        );
+
+        // Counting starts over after the post was restored.
+        report(REPORTERS[1], EntityId::Post(post_id), space_id);
+        assert!(is_post_hidden(post_id));
+    });
+}
+
+#[test]
+fn unhiding_auto_hidden_post_should_reset_its_reporters() {
+    ExtBuilder::default().balances(reporters_balances()).build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let post_id = create_post(SPACE_OWNER, space_id, valid_content_ipfs()).unwrap();
+        set_auto_hide(space_id, threshold_settings(2));
+        report(REPORTERS[0], EntityId::Post(post_id), space_id);
+        report(REPORTERS[1], EntityId::Post(post_id), space_id);
+        assert!(is_post_hidden(post_id));
+
+        assert_ok!(Posts::update_post(
+            RuntimeOrigin::signed(SPACE_OWNER),
+            post_id,
+            PostUpdate { space_id: None, content: None, hidden: Some(false) },
+        ));
+
+        assert!(!AutoHiddenPostScope::<Test>::contains_key(post_id));
+        assert_eq!(Moderation::auto_hide_reporters_count(post_id), 0);
+        assert!(!AutoHideReportersByPostId::<Test>::contains_key(post_id, REPORTERS[0]));
+
+        // A single new report doesn't hide the post again.
+        report(REPORTERS[2], EntityId::Post(post_id), space_id);
+        assert!(!is_post_hidden(post_id));
+    });
+}
+
+#[test]
+fn restore_auto_hidden_post_should_fail_for_invalid_calls() {
+    ExtBuilder::default().balances(reporters_balances()).build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let post_id = create_post(SPACE_OWNER, space_id, valid_content_ipfs()).unwrap();
+
+        assert_noop!(
+            Moderation::restore_auto_hidden_post(RuntimeOrigin::signed(SPACE_OWNER), post_id, Content::None),
+            Error::<Test>::PostIsNotAutoHidden
+        );
+
+        set_auto_hide(space_id, threshold_settings(1));
+        report(REPORTERS[0], EntityId::Post(post_id), space_id);
+
+        assert_noop!(
+            Moderation::restore_auto_hidden_post(RuntimeOrigin::signed(ACCOUNT), post_id, Content::None),
+            Error::<Test>::NoPermissionToUpdateEntityStatus
+        );
+    });
+}
+
+#[test]
+fn set_auto_hide_settings_should_fail_for_invalid_settings() {
+    ExtBuilder::default().build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let set = |account, settings| {
+            Moderation::set_auto_hide_settings(RuntimeOrigin::signed(account), space_id, settings)
+        };
+
+        assert_noop!(set(SPACE_OWNER, Some(threshold_settings(0))), Error::<Test>::InvalidReportersThreshold);
+        assert_noop!(
+            set(SPACE_OWNER, Some(threshold_settings(MaxOpenReportsPerSpace::get() + 1))),
+            Error::<Test>::InvalidReportersThreshold
+        );
+        assert_noop!(set(SPACE_OWNER, None), Error::<Test>::NoAutoHideSettings);
+        assert_noop!(
+            set(ACCOUNT, Some(threshold_settings(1))),
+            Error::<Test>::NoPermissionToUpdateEntityStatus
+        );
+    });
+}
+
+#[test]
+fn update_entity_status_should_be_recorded_in_moderation_history() {
+    ExtBuilder::default().build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let entity = EntityId::Account(ACCOUNT);
+
+        assert_ok!(Moderation::update_entity_status(
+            RuntimeOrigin::signed(SPACE_OWNER),
+            entity.clone(),
+            space_id,
+            Some(EntityStatus::Blocked),
+            valid_content_ipfs(),
+        ));
+
+        let history = Moderation::get_moderation_history(entity, space_id);
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].decided.account, SPACE_OWNER);
+        assert_eq!(
+            history[0].decision,
+            ModerationDecision::StatusUpdated(Some(EntityStatus::Blocked))
+        );
+        assert_eq!(history[0].reason, valid_content_ipfs());
+    });
+}
+
+#[test]
+fn moderation_history_should_drop_oldest_records_when_full() {
+    ExtBuilder::default().build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let entity = EntityId::Account(ACCOUNT);
+
+        let statuses = [EntityStatus::Blocked, EntityStatus::Allowed];
+        for status in statuses.into_iter().cycle().take(4) {
+            assert_ok!(Moderation::update_entity_status(
+                RuntimeOrigin::signed(SPACE_OWNER),
+                entity.clone(),
+                space_id,
+                Some(status),
+                Content::None,
+            ));
+        }
+
+        let decisions: Vec<_> = Moderation::get_moderation_history(entity, space_id)
+            .into_iter()
+            .map(|record| record.decision)
+            .collect();
+        assert_eq!(decisions.len() as u32, MaxModerationHistoryLength::get());
+        assert_eq!(decisions[0], ModerationDecision::StatusUpdated(Some(EntityStatus::Allowed)));
+        assert_eq!(decisions[2], ModerationDecision::StatusUpdated(Some(EntityStatus::Allowed)));
+    });
+}
+
+fn appeal(account: AccountId, entity: EntityId<AccountId>, scope: SpaceId) -> AppealId {
+    let appeal_id = Moderation::next_appeal_id();
+    assert_ok!(Moderation::appeal_entity_status(
+        RuntimeOrigin::signed(account),
+        entity,
+        scope,
+        valid_content_ipfs(),
+    ));
+    appeal_id
+}
+
+#[test]
+fn appeal_entity_status_should_work() {
+    ExtBuilder::default().balances(vec![(ACCOUNT, BALANCE)]).build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let entity = EntityId::Account(ACCOUNT);
+        block(entity.clone(), space_id);
+
+        // A blocked account must still be able to appeal.
+        let appeal_id = appeal(ACCOUNT, entity.clone(), space_id);
+
+        let appeal = AppealById::<Test>::get(appeal_id).expect("appeal should be created");
+        assert_eq!(appeal.created.account, ACCOUNT);
+        assert_eq!(appeal.entity, entity);
+        assert_eq!(appeal.scope, space_id);
+        assert_eq!(appeal.reason, valid_content_ipfs());
+        assert_eq!(appeal.deposit, APPEAL_DEPOSIT);
+        assert_eq!(Balances::reserved_balance(ACCOUNT), APPEAL_DEPOSIT);
+
+        assert_eq!(AppealIdsBySpaceId::<Test>::get(space_id).into_inner(), vec![appeal_id]);
+        assert_eq!(
+            AppealIdByAccount::<Test>::get((entity.clone(), space_id), ACCOUNT),
+            Some(appeal_id)
+        );
+        System::assert_last_event(
+            Event::EntityStatusAppealed { account: ACCOUNT, entity, scope: space_id, appeal_id }
+                .into(),
+        );
+    });
+}
+
+#[test]
+fn appeal_entity_status_should_fail_for_invalid_appeals() {
+    ExtBuilder::default().balances(vec![(ACCOUNT, BALANCE)]).build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let post_id = create_post(ACCOUNT, space_id, valid_content_ipfs()).unwrap();
+        let appeal_by = |account, entity| {
+            Moderation::appeal_entity_status(
+                RuntimeOrigin::signed(account),
+                entity,
+                space_id,
+                Content::None,
+            )
+        };
+
+        assert_noop!(
+            appeal_by(ACCOUNT, EntityId::Account(ACCOUNT)),
+            Error::<Test>::EntityIsNotBlocked
+        );
+
+        block(EntityId::Account(REPORTER), space_id);
+        block(EntityId::Post(post_id), space_id);
+        assert_noop!(
+            appeal_by(ACCOUNT, EntityId::Account(REPORTER)),
+            Error::<Test>::NotAllowedToAppeal
+        );
+        assert_noop!(
+            appeal_by(REPORTER, EntityId::Post(post_id)),
+            Error::<Test>::NotAllowedToAppeal
+        );
+
+        assert_noop!(
+            appeal_by(REPORTER, EntityId::Account(REPORTER)),
+            pallet_balances::Error::<Test>::InsufficientBalance
+        );
+
+        appeal(ACCOUNT, EntityId::Post(post_id), space_id);
+        assert_noop!(
+            appeal_by(ACCOUNT, EntityId::Post(post_id)),
+            Error::<Test>::AlreadyAppealedEntity
+        );
+    });
+}
+
+#[test]
+fn resolve_appeal_should_remove_status_when_accepted() {
+    ExtBuilder::default().balances(vec![(ACCOUNT, BALANCE)]).build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let entity = EntityId::Account(ACCOUNT);
+        block(entity.clone(), space_id);
+        let appeal_id = appeal(ACCOUNT, entity.clone(), space_id);
+
+        assert_ok!(Moderation::resolve_appeal(
+            RuntimeOrigin::signed(SPACE_OWNER),
+            appeal_id,
+            true,
+            valid_content_ipfs(),
+        ));
+
+        assert!(Moderation::is_allowed_account(ACCOUNT, space_id));
+        assert!(!AppealById::<Test>::contains_key(appeal_id));
+        assert!(AppealIdsBySpaceId::<Test>::get(space_id).is_empty());
+        assert!(!AppealIdByAccount::<Test>::contains_key((entity.clone(), space_id), ACCOUNT));
+        assert_eq!(Balances::reserved_balance(ACCOUNT), 0);
+        assert_eq!(Balances::free_balance(ACCOUNT), BALANCE);
+
+        let history = Moderation::get_moderation_history(entity, space_id);
+        assert_eq!(history.last().unwrap().decision, ModerationDecision::AppealAccepted(appeal_id));
+        System::assert_last_event(
+            Event::AppealResolved { account: SPACE_OWNER, appeal_id, accepted: true }.into(),
+        );
+    });
+}
+
+#[test]
+fn resolve_appeal_should_keep_status_when_rejected() {
+    ExtBuilder::default().balances(vec![(ACCOUNT, BALANCE)]).build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let entity = EntityId::Account(ACCOUNT);
+        block(entity.clone(), space_id);
+        let appeal_id = appeal(ACCOUNT, entity.clone(), space_id);
+
+        assert_ok!(Moderation::resolve_appeal(
+            RuntimeOrigin::signed(SPACE_OWNER),
+            appeal_id,
+            false,
+            valid_content_ipfs(),
+        ));
+
+        assert!(Moderation::is_blocked_account(ACCOUNT, space_id));
+        assert!(!AppealById::<Test>::contains_key(appeal_id));
+        assert_eq!(Balances::reserved_balance(ACCOUNT), 0);
+        assert_eq!(Balances::free_balance(ACCOUNT), BALANCE - APPEAL_DEPOSIT);
+
+        let history = Moderation::get_moderation_history(entity, space_id);
+        assert_eq!(history.last().unwrap().decision, ModerationDecision::AppealRejected(appeal_id));
+        assert_eq!(history.last().unwrap().reason, valid_content_ipfs());
+    });
+}
+
+#[test]
+fn resolve_appeal_should_fail_when_caller_has_no_permission() {
+    ExtBuilder::default().balances(vec![(ACCOUNT, BALANCE)]).build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        block(EntityId::Account(ACCOUNT), space_id);
+        let appeal_id = appeal(ACCOUNT, EntityId::Account(ACCOUNT), space_id);
+
+        assert_noop!(
+            Moderation::resolve_appeal(
+                RuntimeOrigin::signed(REPORTER),
+                appeal_id,
+                true,
+                Content::None
+            ),
+            Error::<Test>::NoPermissionToUpdateEntityStatus
+        );
+        assert_noop!(
+            Moderation::resolve_appeal(
+                RuntimeOrigin::signed(SPACE_OWNER),
+                999,
+                true,
+                Content::None
+            ),
+            Error::<Test>::AppealNotFound
+        );
+    });
+}
+
+#[test]
+fn resolve_appeal_should_close_other_appeals_when_accepted() {
+    ExtBuilder::default().balances(reporters_balances()).build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let entity = EntityId::Content(valid_content_ipfs());
+        block(entity.clone(), space_id);
+        let appeal_id = appeal(REPORTERS[0], entity.clone(), space_id);
+        let other_appeal_id = appeal(REPORTERS[1], entity.clone(), space_id);
+
+        assert_ok!(Moderation::resolve_appeal(
+            RuntimeOrigin::signed(SPACE_OWNER),
+            appeal_id,
+            true,
+            Content::None,
+        ));
+
+        assert!(!AppealById::<Test>::contains_key(other_appeal_id));
+        assert!(!AppealIdByAccount::<Test>::contains_key((entity, space_id), REPORTERS[1]));
+        assert!(AppealIdsBySpaceId::<Test>::get(space_id).is_empty());
+        assert_eq!(Balances::reserved_balance(REPORTERS[0]), 0);
+        assert_eq!(Balances::reserved_balance(REPORTERS[1]), 0);
+        assert_eq!(Balances::free_balance(REPORTERS[1]), BALANCE);
+    });
+}
+
+#[test]
+fn resolve_appeal_should_fail_when_entity_is_no_longer_blocked() {
+    ExtBuilder::default().balances(vec![(ACCOUNT, BALANCE)]).build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let entity = EntityId::Account(ACCOUNT);
+        block(entity.clone(), space_id);
+        let appeal_id = appeal(ACCOUNT, entity.clone(), space_id);
+
+        StatusByEntityInSpace::<Test>::insert(space_id, &entity, EntityStatus::Allowed);
+
+        assert_noop!(
+            Moderation::resolve_appeal(
+                RuntimeOrigin::signed(SPACE_OWNER),
+                appeal_id,
+                false,
+                Content::None
+            ),
+            Error::<Test>::EntityIsNotBlocked
+        );
+    });
+}
+
+#[test]
+fn update_entity_status_should_close_appeals_when_entity_is_unblocked() {
+    ExtBuilder::default().balances(reporters_balances()).build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let entity = EntityId::Content(valid_content_ipfs());
+        block(entity.clone(), space_id);
+        let appeal_id = appeal(REPORTERS[0], entity.clone(), space_id);
+
+        // Re-blocking the entity keeps its appeals open.
+        block(entity.clone(), space_id);
+        assert!(AppealById::<Test>::contains_key(appeal_id));
+
+        assert_ok!(Moderation::update_entity_status(
+            RuntimeOrigin::signed(SPACE_OWNER),
+            entity.clone(),
+            space_id,
+            None,
+            Content::None,
+        ));
+
+        assert!(!AppealById::<Test>::contains_key(appeal_id));
+        assert!(!AppealIdByAccount::<Test>::contains_key((entity, space_id), REPORTERS[0]));
+        assert!(AppealIdsBySpaceId::<Test>::get(space_id).is_empty());
+        assert_eq!(Balances::reserved_balance(REPORTERS[0]), 0);
+    });
+}
+
+#[test]
+fn space_deletion_should_remove_moderation_data_of_space() {
+    ExtBuilder::default().balances(reporters_balances()).build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let entity = EntityId::Content(valid_content_ipfs());
+        block(entity.clone(), space_id);
+        assert_ok!(Moderation::suggest_entity_status(
+            RuntimeOrigin::signed(SPACE_OWNER),
+            entity.clone(),
+            space_id,
+            Some(EntityStatus::Allowed),
+        ));
+        report(REPORTERS[0], entity.clone(), space_id);
+        appeal(REPORTERS[1], entity.clone(), space_id);
+
+        assert_ok!(Spaces::delete_space(RuntimeOrigin::signed(SPACE_OWNER), space_id));
+        Spaces::on_idle(System::block_number(), Weight::MAX);
+
+        assert!(!StatusByEntityInSpace::<Test>::contains_key(space_id, &entity));
+        assert!(!SuggestedStatusByAccount::<Test>::contains_key(
+            space_id,
+            (entity.clone(), SPACE_OWNER)
+        ));
+        assert!(!ModerationHistory::<Test>::contains_key(space_id, &entity));
+        assert!(ReportIdsBySpaceId::<Test>::get(space_id).is_empty());
+        assert!(AppealIdsBySpaceId::<Test>::get(space_id).is_empty());
+        assert_eq!(Balances::reserved_balance(REPORTERS[0]), REPORTING_AGE_DEPOSIT);
+        assert_eq!(Balances::reserved_balance(REPORTERS[1]), 0);
+    });
+}
+
+#[test]
+fn space_deletion_should_remove_moderation_lists_of_space() {
+    ExtBuilder::default().build().execute_with(|| {
+        let space_id = create_space(SPACE_OWNER);
+        let entity = EntityId::Account(ACCOUNT);
+        let (list_space_id, list_id) = create_list_blocking(entity.clone());
+        subscribe(space_id, list_id);
+        assert!(Moderation::is_blocked_account(ACCOUNT, space_id));
+
+        assert_ok!(Spaces::delete_space(RuntimeOrigin::signed(LIST_OWNER), list_space_id));
+        Spaces::on_idle(System::block_number(), Weight::MAX);
+
+        assert!(!ModerationListById::<Test>::contains_key(list_id));
+        assert!(ModerationListIdsBySpaceId::<Test>::get(list_space_id).is_empty());
+        assert!(!StatusByEntityInList::<Test>::contains_key(list_id, &entity));
+        assert!(!Moderation::is_space_subscribed_to_list(list_id, space_id));
+        assert!(SubscribedListIdsBySpaceId::<Test>::get(space_id).is_empty());
+        assert!(Moderation::is_allowed_account(ACCOUNT, space_id));
+    });
+}
@@ -0,0 +1,179 @@
+// Copyright (C) DAPPFORCE PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
+//
+// Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
+// Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
+
+use super::*;
+
+/// An entity that can be moderated within a space.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum EntityId<AccountId> {
+    Content(Content),
+    Account(AccountId),
+    Space(SpaceId),
+    Post(PostId),
+}
+
+/// Status of an entity within a space. The absence of a status means that
+/// the entity is neither explicitly allowed nor blocked.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum EntityStatus {
+    Allowed,
+    Blocked,
+}
+
+/// A status suggested by a member of a space for a given entity.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct SuggestedStatus<T: Config> {
+    pub suggested: WhoAndWhenOf<T>,
+    /// `None` means that the suggester wants the current status to be removed.
+    pub status: Option<EntityStatus>,
+}
+
+impl<T: Config> SuggestedStatus<T> {
+    pub fn new(who: T::AccountId, status: Option<EntityStatus>) -> Self {
+        Self { suggested: new_who_and_when::<T>(who), status }
+    }
+}
+
+pub type ReportId = u64;
+
+pub const FIRST_REPORT_ID: u64 = 1;
+
+/// A report about an entity that violates the rules of a space.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct Report<AccountId, BlockNumber, Moment, Balance> {
+    pub id: ReportId,
+    /// Who reported the entity and when.
+    pub created: WhoAndWhen<AccountId, BlockNumber, Moment>,
+    pub reported_entity: EntityId<AccountId>,
+    /// A space (scope) in which the entity was reported.
+    pub reported_within: SpaceId,
+    /// Content with the reason of the report, e.g. an IPFS CID.
+    pub reason: Content,
+    /// An amount reserved from the reporter until the report is resolved.
+    pub deposit: Balance,
+}
+
+pub type ModerationListId = u64;
+
+pub const FIRST_MODERATION_LIST_ID: u64 = 1;
+
+/// A curated list of entity statuses owned by a space.
+/// Other spaces can subscribe to a list to reuse its statuses.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ModerationList<T: Config> {
+    pub id: ModerationListId,
+    pub created: WhoAndWhenOf<T>,
+    /// A space whose moderators manage this list.
+    pub owner_space: SpaceId,
+    /// Description of this list, e.g. an IPFS CID.
+    pub content: Content,
+}
+
+impl<T: Config> ModerationList<T> {
+    pub fn new(
+        id: ModerationListId,
+        created_by: T::AccountId,
+        owner_space: SpaceId,
+        content: Content,
+    ) -> Self {
+        Self { id, created: new_who_and_when::<T>(created_by), owner_space, content }
+    }
+}
+
+pub type AppealId = u64;
+
+pub const FIRST_APPEAL_ID: u64 = 1;
+
+/// An appeal against the status of an entity in a space.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct Appeal<T: Config> {
+    pub id: AppealId,
+    /// Who appealed and when.
+    pub created: WhoAndWhenOf<T>,
+    pub entity: EntityIdOf<T>,
+    /// A space (scope) in which the status of the entity is appealed.
+    pub scope: SpaceId,
+    /// Content with the reason of the appeal, e.g. an IPFS CID.
+    pub reason: Content,
+    /// An amount reserved from the appellant until the appeal is resolved.
+    pub deposit: BalanceOf<T>,
+}
+
+/// A moderation decision about an entity in a space.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum ModerationDecision {
+    /// The status of the entity was set, or removed if `None`.
+    StatusUpdated(Option<EntityStatus>),
+    /// An appeal was accepted and the status of the entity was removed.
+    AppealAccepted(AppealId),
+    /// An appeal was rejected and the status of the entity was kept.
+    AppealRejected(AppealId),
+}
+
+/// An entry of the moderation history of an entity in a space.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ModerationRecord<AccountId, BlockNumber, Moment> {
+    /// Who made the decision and when.
+    pub decided: WhoAndWhen<AccountId, BlockNumber, Moment>,
+    pub decision: ModerationDecision,
+    /// Content with the reason of the decision, e.g. an IPFS CID.
+    pub reason: Content,
+}
+
+/// Per-space settings for automatic hiding of reported posts and comments.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct AutoHideSettings<BlockNumber> {
+    /// The number of distinct qualified reporters after which a post is hidden.
+    pub reporters_threshold: u32,
+    /// If set, only reporters whose reporting age is at least this number of blocks count.
+    ///
+    /// The reporting age of an account is counted from its first report in this pallet,
+    /// not from the creation of the account.
+    pub min_reporting_age: Option<BlockNumber>,
+    /// If `true`, only reporters that follow the space count.
+    pub followers_only: bool,
+}
+
+/// The first report of an account, which the reporting age of the account is counted from.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct FirstReport<BlockNumber, Balance> {
+    /// The block at which the account reported an entity for the first time.
+    pub block: BlockNumber,
+    /// An amount reserved from the account while its first report is kept.
+    pub deposit: Balance,
+}
+
+/// Why a post was hidden automatically.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum AutoHideReason {
+    /// The post was reported by this number of distinct qualified reporters.
+    ReportsThresholdReached { reporters_count: u32 },
+}
+
+pub type EntityIdOf<T> = EntityId<<T as frame_system::Config>::AccountId>;
+
+pub type ModerationRecordOf<T> = ModerationRecord<
+    <T as frame_system::Config>::AccountId,
+    BlockNumberFor<T>,
+    <T as pallet_timestamp::Config>::Moment,
+>;
+
+pub type AutoHideSettingsOf<T> = AutoHideSettings<BlockNumberFor<T>>;
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type FirstReportOf<T> = FirstReport<BlockNumberFor<T>, BalanceOf<T>>;
+
+pub type ReportOf<T> = Report<
+    <T as frame_system::Config>::AccountId,
+    BlockNumberFor<T>,
+    <T as pallet_timestamp::Config>::Moment,
+    BalanceOf<T>,
+>;
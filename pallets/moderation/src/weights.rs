@@ -0,0 +1,296 @@
+// Copyright (C) DAPPFORCE PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
+//
+// Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
+// Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
+
+
+//! PLACEHOLDER weights for pallet_moderation
+//!
+//! THESE WEIGHTS ARE HAND-WRITTEN ESTIMATES, NOT BENCHMARK OUTPUT. They only exist so that
+//! the pallet compiles, and must be replaced with the output of its benchmarks before
+//! a runtime that includes the pallet is released:
+//!
+//! ./scripts/run-benchmark-on.sh pallet_moderation ./pallets/moderation/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(non_snake_case)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_moderation.
+pub trait WeightInfo {
+    fn suggest_entity_status() -> Weight;
+    fn update_entity_status(a: u32, ) -> Weight;
+    fn report_entity() -> Weight;
+    fn resolve_report() -> Weight;
+    fn update_global_entity_status() -> Weight;
+    fn create_moderation_list() -> Weight;
+    fn update_list_entity_status() -> Weight;
+    fn subscribe_to_moderation_list() -> Weight;
+    fn unsubscribe_from_moderation_list() -> Weight;
+    fn set_auto_hide_settings() -> Weight;
+    fn restore_auto_hidden_post(r: u32, ) -> Weight;
+    fn appeal_entity_status() -> Weight;
+    fn resolve_appeal(a: u32, ) -> Weight;
+    fn forget_reporting_age() -> Weight;
+}
+
+/// Weights for pallet_moderation using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Storage: Spaces SpaceById (r:1 w:0)
+    /// Storage: Moderation StatusByEntityInSpace (r:1 w:0)
+    /// Storage: Posts PostById (r:1 w:0)
+    /// Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+    /// Storage: Roles RoleIdsByUserInSpace (r:1 w:0)
+    /// Storage: Timestamp Now (r:1 w:0)
+    /// Storage: Moderation SuggestedStatusByAccount (r:0 w:1)
+    fn suggest_entity_status() -> Weight {
+        Weight::from_parts(40_000_000, 20000)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: Spaces SpaceById (r:1 w:0)
+    /// Storage: Moderation StatusByEntityInSpace (r:2 w:1)
+    /// Storage: Posts PostById (r:1 w:0)
+    /// Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+    /// Storage: Roles RoleIdsByUserInSpace (r:1 w:0)
+    /// Storage: Timestamp Now (r:1 w:0)
+    /// Storage: Moderation ModerationHistory (r:1 w:1)
+    /// Storage: Moderation AppealIdByAccount (r:1001 w:1000)
+    /// Storage: Moderation AppealById (r:1000 w:1000)
+    /// Storage: System Account (r:1000 w:1000)
+    /// Storage: Moderation AppealIdsBySpaceId (r:1 w:1)
+    /// The range of component `a` is `[0, 1000]`.
+    fn update_entity_status(a: u32, ) -> Weight {
+        Weight::from_parts(50_000_000, 30000)
+            .saturating_add(Weight::from_parts(15_000_000, 0).saturating_mul(a.into()))
+            .saturating_add(T::DbWeight::get().reads(10_u64))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(a.into())))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(a.into())))
+    }
+    /// Storage: Spaces SpaceById (r:1 w:0)
+    /// Storage: Moderation StatusByEntityInSpace (r:1 w:0)
+    /// Storage: Posts PostById (r:2 w:1)
+    /// Storage: Moderation ReportIdByAccount (r:1 w:1)
+    /// Storage: Moderation NextReportId (r:1 w:1)
+    /// Storage: Moderation ReportIdsBySpaceId (r:1 w:1)
+    /// Storage: System Account (r:1 w:1)
+    /// Storage: Timestamp Now (r:1 w:0)
+    /// Storage: Moderation AutoHideSettingsBySpaceId (r:1 w:0)
+    /// Storage: Moderation AutoHideReportersByPostId (r:1 w:1)
+    /// Storage: Moderation FirstReportByAccount (r:1 w:1)
+    /// Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+    /// Storage: Moderation AutoHideReportersCount (r:1 w:1)
+    /// Storage: Moderation ReportById (r:0 w:1)
+    /// Storage: Moderation AutoHiddenPostScope (r:0 w:1)
+    fn report_entity() -> Weight {
+        Weight::from_parts(85_000_000, 40000)
+            .saturating_add(T::DbWeight::get().reads(14_u64))
+            .saturating_add(T::DbWeight::get().writes(10_u64))
+    }
+    /// Storage: Moderation ReportById (r:1 w:1)
+    /// Storage: Spaces SpaceById (r:1 w:0)
+    /// Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+    /// Storage: Roles RoleIdsByUserInSpace (r:1 w:0)
+    /// Storage: System Account (r:1 w:1)
+    /// Storage: Moderation ReportIdsBySpaceId (r:1 w:1)
+    /// Storage: Moderation ReportIdByAccount (r:0 w:1)
+    fn resolve_report() -> Weight {
+        Weight::from_parts(55_000_000, 30000)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: Posts PostById (r:1 w:0)
+    /// Storage: Moderation GlobalStatusByEntity (r:1 w:1)
+    fn update_global_entity_status() -> Weight {
+        Weight::from_parts(25_000_000, 10000)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: Spaces SpaceById (r:1 w:0)
+    /// Storage: Moderation StatusByEntityInSpace (r:1 w:0)
+    /// Storage: Moderation NextModerationListId (r:1 w:1)
+    /// Storage: Moderation ModerationListById (r:0 w:1)
+    /// Storage: Moderation ModerationListIdsBySpaceId (r:1 w:1)
+    fn create_moderation_list() -> Weight {
+        Weight::from_parts(30_000_000, 10000)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: Moderation ModerationListById (r:1 w:0)
+    /// Storage: Spaces SpaceById (r:1 w:0)
+    /// Storage: Moderation StatusByEntityInSpace (r:1 w:0)
+    /// Storage: Moderation StatusByEntityInList (r:1 w:1)
+    fn update_list_entity_status() -> Weight {
+        Weight::from_parts(30_000_000, 10000)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: Spaces SpaceById (r:1 w:0)
+    /// Storage: Moderation StatusByEntityInSpace (r:1 w:0)
+    /// Storage: Moderation ModerationListById (r:1 w:0)
+    /// Storage: Moderation SubscribedListIdsBySpaceId (r:1 w:1)
+    /// Storage: Moderation SpaceSubscribedToList (r:0 w:1)
+    fn subscribe_to_moderation_list() -> Weight {
+        Weight::from_parts(30_000_000, 10000)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: Spaces SpaceById (r:1 w:0)
+    /// Storage: Moderation StatusByEntityInSpace (r:1 w:0)
+    /// Storage: Moderation SubscribedListIdsBySpaceId (r:1 w:1)
+    /// Storage: Moderation SpaceSubscribedToList (r:0 w:1)
+    fn unsubscribe_from_moderation_list() -> Weight {
+        Weight::from_parts(30_000_000, 10000)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: Spaces SpaceById (r:1 w:0)
+    /// Storage: Moderation StatusByEntityInSpace (r:1 w:0)
+    /// Storage: Moderation AutoHideSettingsBySpaceId (r:1 w:1)
+    fn set_auto_hide_settings() -> Weight {
+        Weight::from_parts(25_000_000, 10000)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: Moderation AutoHiddenPostScope (r:1 w:1)
+    /// Storage: Spaces SpaceById (r:1 w:0)
+    /// Storage: Moderation StatusByEntityInSpace (r:1 w:0)
+    /// Storage: Posts PostById (r:1 w:1)
+    /// Storage: Moderation AutoHideReportersCount (r:1 w:1)
+    /// Storage: Moderation AutoHideReportersByPostId (r:0 w:1000)
+    /// The range of component `r` is `[1, 1000]`.
+    fn restore_auto_hidden_post(r: u32, ) -> Weight {
+        Weight::from_parts(40_000_000, 20000)
+            .saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(r.into()))
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(r.into())))
+    }
+    /// Storage: Spaces SpaceById (r:1 w:0)
+    /// Storage: Moderation StatusByEntityInSpace (r:1 w:0)
+    /// Storage: Posts PostById (r:1 w:0)
+    /// Storage: Moderation AppealIdByAccount (r:1 w:1)
+    /// Storage: Moderation NextAppealId (r:1 w:1)
+    /// Storage: Moderation AppealIdsBySpaceId (r:1 w:1)
+    /// Storage: System Account (r:1 w:1)
+    /// Storage: Timestamp Now (r:1 w:0)
+    /// Storage: Moderation AppealById (r:0 w:1)
+    fn appeal_entity_status() -> Weight {
+        Weight::from_parts(55_000_000, 20000)
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().writes(5_u64))
+    }
+    /// Storage: Moderation AppealById (r:1001 w:1001)
+    /// Storage: Spaces SpaceById (r:1 w:0)
+    /// Storage: Moderation StatusByEntityInSpace (r:1 w:1)
+    /// Storage: Moderation AppealIdsBySpaceId (r:1 w:1)
+    /// Storage: Moderation AppealIdByAccount (r:1000 w:1001)
+    /// Storage: System Account (r:1001 w:1001)
+    /// Storage: Timestamp Now (r:1 w:0)
+    /// Storage: Moderation ModerationHistory (r:1 w:1)
+    /// The range of component `a` is `[0, 1000]`.
+    fn resolve_appeal(a: u32, ) -> Weight {
+        Weight::from_parts(60_000_000, 30000)
+            .saturating_add(Weight::from_parts(15_000_000, 0).saturating_mul(a.into()))
+            .saturating_add(T::DbWeight::get().reads(7_u64))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(a.into())))
+            .saturating_add(T::DbWeight::get().writes(7_u64))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(a.into())))
+    }
+    /// Storage: Moderation FirstReportByAccount (r:1 w:1)
+    /// Storage: System Account (r:1 w:1)
+    fn forget_reporting_age() -> Weight {
+        Weight::from_parts(25_000_000, 10000)
+            .saturating_add(T::DbWeight::get().reads(2_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+    fn suggest_entity_status() -> Weight {
+        Weight::from_parts(40_000_000, 20000)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn update_entity_status(a: u32, ) -> Weight {
+        Weight::from_parts(50_000_000, 30000)
+            .saturating_add(Weight::from_parts(15_000_000, 0).saturating_mul(a.into()))
+            .saturating_add(RocksDbWeight::get().reads(10_u64))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(a.into())))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+            .saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(a.into())))
+    }
+    fn report_entity() -> Weight {
+        Weight::from_parts(85_000_000, 40000)
+            .saturating_add(RocksDbWeight::get().reads(14_u64))
+            .saturating_add(RocksDbWeight::get().writes(10_u64))
+    }
+    fn resolve_report() -> Weight {
+        Weight::from_parts(55_000_000, 30000)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    fn update_global_entity_status() -> Weight {
+        Weight::from_parts(25_000_000, 10000)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn create_moderation_list() -> Weight {
+        Weight::from_parts(30_000_000, 10000)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    fn update_list_entity_status() -> Weight {
+        Weight::from_parts(30_000_000, 10000)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn subscribe_to_moderation_list() -> Weight {
+        Weight::from_parts(30_000_000, 10000)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn unsubscribe_from_moderation_list() -> Weight {
+        Weight::from_parts(30_000_000, 10000)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    fn set_auto_hide_settings() -> Weight {
+        Weight::from_parts(25_000_000, 10000)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    fn restore_auto_hidden_post(r: u32, ) -> Weight {
+        Weight::from_parts(40_000_000, 20000)
+            .saturating_add(Weight::from_parts(2_000_000, 0).saturating_mul(r.into()))
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(r.into())))
+    }
+    fn appeal_entity_status() -> Weight {
+        Weight::from_parts(55_000_000, 20000)
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
+            .saturating_add(RocksDbWeight::get().writes(5_u64))
+    }
+    fn resolve_appeal(a: u32, ) -> Weight {
+        Weight::from_parts(60_000_000, 30000)
+            .saturating_add(Weight::from_parts(15_000_000, 0).saturating_mul(a.into()))
+            .saturating_add(RocksDbWeight::get().reads(7_u64))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(a.into())))
+            .saturating_add(RocksDbWeight::get().writes(7_u64))
+            .saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(a.into())))
+    }
+    fn forget_reporting_age() -> Weight {
+        Weight::from_parts(25_000_000, 10000)
+            .saturating_add(RocksDbWeight::get().reads(2_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+}
@@ -7,6 +7,9 @@
 //! # Ownership Module
 //!
 //! This module allows the transfer of ownership of entities such as spaces, posts, and domains.
+//!
+//! A space can have several owners, so each of them can transfer only their own seat.
+//! A pending transfer of a space is dropped when the space is deleted.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -28,7 +31,7 @@ pub mod pallet {
     use frame_system::pallet_prelude::*;
     use pallet_permissions::SpacePermissions;
 
-    use subsocial_support::{PostId, SpaceId, SpacePermissionsInfo, traits::{CreatorStakingProvider, DomainsProvider, ProfileManager, SpacesProvider, PostsProvider, SpacePermissionsProvider}};
+    use subsocial_support::{PostId, SpaceId, SpacePermissionsInfo, traits::{CreatorStakingProvider, DomainsProvider, ProfileManager, SpacesProvider, PostsProvider, SpaceDeletionHandler, SpacePermissionsProvider}};
 
     pub(crate) type DomainLengthOf<T> = 
         <<T as Config>::DomainsProvider as DomainsProvider<<T as frame_system::Config>::AccountId>>::MaxDomainLength;
@@ -84,6 +87,8 @@ pub mod pallet {
         NotAllowedToAcceptOwnershipTransfer,
         /// Account is not allowed to reject ownership transfer.
         NotAllowedToRejectOwnershipTransfer,
+        /// Another owner of the space has a pending transfer of their seat.
+        AnotherSpaceTransferIsPending,
     }
 
     #[pallet::storage]
@@ -91,6 +96,12 @@ pub mod pallet {
     pub type PendingOwnershipTransfers<T: Config> =
         StorageMap<_, Twox64Concat, OwnableEntity<T>, T::AccountId>;
 
+    /// The owner of a space, whose seat is transferred by a pending ownership transfer.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_space_transfer_from)]
+    pub type PendingSpaceTransferFrom<T: Config> =
+        StorageMap<_, Twox64Concat, SpaceId, T::AccountId>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -132,6 +143,8 @@ pub mod pallet {
                 OwnableEntity::Space(space_id) => {
                     T::SpacePermissionsProvider::ensure_space_owner(space_id, &current_owner)?;
                     Self::ensure_not_active_creator(space_id)?;
+                    Self::ensure_no_space_transfer_of_another_owner(space_id, &current_owner)?;
+                    PendingSpaceTransferFrom::<T>::insert(space_id, current_owner.clone());
                 }
                 OwnableEntity::Post(post_id) =>
                     T::PostsProvider::ensure_post_owner(post_id, &current_owner)?,
@@ -167,11 +180,20 @@ pub mod pallet {
 
             match entity.clone() {
                 OwnableEntity::Space(space_id) => {
-                    let previous_owner = T::SpacesProvider::get_space_owner(space_id)?;
+                    // Transfers created before spaces could have several owners
+                    // are transfers of the owner's seat.
+                    let previous_owner = match PendingSpaceTransferFrom::<T>::take(space_id) {
+                        Some(previous_owner) => previous_owner,
+                        None => T::SpacesProvider::get_space_owner(space_id)?,
+                    };
 
                     Self::ensure_not_active_creator(space_id)?;
 
-                    T::SpacesProvider::do_update_space_owner(space_id, pending_owner.clone())?;
+                    T::SpacesProvider::do_update_space_owner(
+                        space_id,
+                        &previous_owner,
+                        pending_owner.clone(),
+                    )?;
                     T::ProfileManager::unlink_space_from_profile(&previous_owner, space_id);
                 }
                 OwnableEntity::Post(post_id) =>
@@ -202,12 +224,22 @@ pub mod pallet {
                 OwnableEntity::Domain(domain) => T::DomainsProvider::get_domain_owner(&domain),
             }?;
 
+            // Any owner of a space can reject a transfer of its ownership.
+            let is_space_co_owner = match entity.clone() {
+                OwnableEntity::Space(space_id) =>
+                    T::SpacePermissionsProvider::ensure_space_owner(space_id, &who).is_ok(),
+                _ => false,
+            };
+
             ensure!(
-                who == pending_owner || who == current_owner,
+                who == pending_owner || who == current_owner || is_space_co_owner,
                 Error::<T>::NotAllowedToRejectOwnershipTransfer
             );
 
             PendingOwnershipTransfers::<T>::remove(&entity);
+            if let OwnableEntity::Space(space_id) = entity.clone() {
+                PendingSpaceTransferFrom::<T>::remove(space_id);
+            }
 
             Self::deposit_event(Event::OwnershipTransferRejected { account: who, entity });
             Ok(())
@@ -223,5 +255,51 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// A space has a single pending transfer, so an owner cannot replace
+        /// the pending transfer of another owner's seat.
+        fn ensure_no_space_transfer_of_another_owner(
+            space_id: SpaceId,
+            owner: &T::AccountId,
+        ) -> DispatchResult {
+            if Self::pending_ownership_transfer(OwnableEntity::Space(space_id)).is_none() {
+                return Ok(())
+            }
+
+            let pending_from = match Self::pending_space_transfer_from(space_id) {
+                Some(pending_from) => pending_from,
+                None => T::SpacesProvider::get_space_owner(space_id)?,
+            };
+
+            ensure!(&pending_from == owner, Error::<T>::AnotherSpaceTransferIsPending);
+            Ok(())
+        }
+    }
+
+    impl<T: Config> SpaceDeletionHandler for Pallet<T> {
+        /// Drop a pending ownership transfer of a deleted space.
+        fn cleanup_deleted_space(space_id: SpaceId, limit: u32) -> u32 {
+            let mut removed = 0;
+
+            if removed < limit &&
+                PendingOwnershipTransfers::<T>::take(OwnableEntity::Space(space_id)).is_some()
+            {
+                removed += 1;
+            }
+
+            if removed < limit && PendingSpaceTransferFrom::<T>::take(space_id).is_some() {
+                removed += 1;
+            }
+
+            removed
+        }
+
+        /// An item is either the pending transfer or the owner whose seat it transfers.
+        fn cleanup_deleted_space_weight(limit: u32) -> Weight {
+            let item_weight = T::DbWeight::get().writes(1);
+            T::DbWeight::get()
+                .reads(2)
+                .saturating_add(item_weight.saturating_mul(limit.into()))
+        }
     }
 }
@@ -56,16 +56,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     /// Proof Skipped: Spaces SpaceById (max_values: None, max_size: None, mode: Measured)
     /// Storage: CreatorStaking RegisteredCreators (r:1 w:0)
     /// Proof: CreatorStaking RegisteredCreators (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
-    /// Storage: Ownership PendingOwnershipTransfers (r:0 w:1)
+    /// Storage: Ownership PendingOwnershipTransfers (r:1 w:1)
     /// Proof: Ownership PendingOwnershipTransfers (max_values: None, max_size: Some(105), added: 2580, mode: MaxEncodedLen)
+    /// Storage: Ownership PendingSpaceTransferFrom (r:1 w:1)
+    /// Proof Skipped: Ownership PendingSpaceTransferFrom (max_values: None, max_size: None, mode: Measured)
     fn transfer_space_ownership() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `1447`
-        //  Estimated: `8430`
-        // Minimum execution time: 31_653_000 picoseconds.
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
         Weight::from_parts(32_800_000, 8430)
-            .saturating_add(T::DbWeight::get().reads(2_u64))
-            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
     }
     /// Storage: Posts PostById (r:1 w:0)
     /// Proof Skipped: Posts PostById (max_values: None, max_size: None, mode: Measured)
@@ -163,16 +162,15 @@ impl WeightInfo for () {
     /// Proof Skipped: Spaces SpaceById (max_values: None, max_size: None, mode: Measured)
     /// Storage: CreatorStaking RegisteredCreators (r:1 w:0)
     /// Proof: CreatorStaking RegisteredCreators (max_values: None, max_size: Some(53), added: 2528, mode: MaxEncodedLen)
-    /// Storage: Ownership PendingOwnershipTransfers (r:0 w:1)
+    /// Storage: Ownership PendingOwnershipTransfers (r:1 w:1)
     /// Proof: Ownership PendingOwnershipTransfers (max_values: None, max_size: Some(105), added: 2580, mode: MaxEncodedLen)
+    /// Storage: Ownership PendingSpaceTransferFrom (r:1 w:1)
+    /// Proof Skipped: Ownership PendingSpaceTransferFrom (max_values: None, max_size: None, mode: Measured)
     fn transfer_space_ownership() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `1447`
-        //  Estimated: `8430`
-        // Minimum execution time: 31_653_000 picoseconds.
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
         Weight::from_parts(32_800_000, 8430)
-            .saturating_add(RocksDbWeight::get().reads(2_u64))
-            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
     }
     /// Storage: Posts PostById (r:1 w:0)
     /// Proof Skipped: Posts PostById (max_values: None, max_size: None, mode: Measured)
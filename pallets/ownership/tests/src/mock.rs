@@ -127,6 +127,13 @@ impl pallet_balances::Config for Test {
     type RuntimeHoldReason = ();
 }
 
+parameter_types! {
+    pub static SpaceDeposit: Balance = 0;
+    pub static PostDeposit: Balance = 0;
+    pub static RoleDeposit: Balance = 0;
+    pub static FollowDeposit: Balance = 0;
+}
+
 impl pallet_permissions::Config for Test {
     type DefaultSpacePermissions = pallet_permissions::default_permissions::DefaultSpacePermissions;
 }
@@ -138,6 +145,8 @@ parameter_types! {
 impl pallet_roles::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type MaxUsersToProcessPerDeleteRole = MaxUsersToProcessPerDeleteRole;
+    type Currency = Balances;
+    type RoleDeposit = RoleDeposit;
     type SpacePermissionsProvider = Spaces;
     type SpaceFollows = SpaceFollows;
     type IsAccountBlocked = ();
@@ -150,6 +159,8 @@ impl pallet_profiles::Config for Test {
     type SpacePermissionsProvider = Spaces;
     type SpacesProvider = Spaces;
     type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
 }
 
 impl pallet_spaces::Config for Test {
@@ -159,11 +170,24 @@ impl pallet_spaces::Config for Test {
     type IsAccountBlocked = ();
     type IsContentBlocked = ();
     type MaxSpacesPerAccount = ConstU32<100>;
+    type MaxSubspaceDepth = ConstU32<10>;
+    type DomainsProvider = Domains;
+    type MinHandleLen = ConstU32<5>;
+    type MaxHandleLen = ConstU32<50>;
+    type ProfileManager = Profiles;
+    type CreatorStakingProvider = MockCreatorStaking;
+    type SpaceDeletionHandler = (Posts, Ownership);
+    type SpaceSettingsRegistry = Posts;
+    type Currency = Balances;
+    type SpaceDeposit = SpaceDeposit;
+    type MaxSpaceCoOwners = frame_support::traits::ConstU32<3>;
     type WeightInfo = ();
 }
 
 impl pallet_space_follows::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type FollowDeposit = FollowDeposit;
+    type InvitationDeposit = frame_support::traits::ConstU64<0>;
     type WeightInfo = ();
 }
 
@@ -171,6 +195,14 @@ impl pallet_posts::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type MaxCommentDepth = ConstU32<10>;
     type IsPostBlocked = ();
+    type PostDeletionHandler = ();
+    type PostVisibilityHandler = ();
+    type PostDeposit = PostDeposit;
+    type MaxPinnedPostsPerSpace = frame_support::traits::ConstU32<3>;
+    type MaxPostRevisions = frame_support::traits::ConstU32<3>;
+    type MaxScheduledPostsPerBlock = frame_support::traits::ConstU32<3>;
+    type MaxPollOptions = frame_support::traits::ConstU32<3>;
+    type BackerStakesProvider = ();
     type WeightInfo = ();
 }
 
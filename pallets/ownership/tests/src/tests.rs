@@ -4,9 +4,10 @@
 // Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
 // Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
 
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, weights::Weight};
 
 use pallet_ownership::{OwnableEntity, Error as OwnershipError, Event as OwnershipEvent};
+use pallet_spaces::Error as SpacesError;
 
 use crate::{mock::*, tests_utils::*};
 
@@ -59,6 +60,34 @@ fn accept_pending_ownership_works() {
     });
 }
 
+#[test]
+fn accept_pending_ownership_should_move_deposits_to_new_owner() {
+    SpaceDeposit::set(10);
+    PostDeposit::set(5);
+    ExtBuilder::build_with_pending_transfers().execute_with(|| {
+        let _m = use_static_mock();
+        let creator_staking_ctx = MockCreatorStaking::is_creator_active_context();
+        creator_staking_ctx.expect().returning(|_| false).once();
+
+        let account1_reserved = Balances::reserved_balance(ACCOUNT1);
+        let account2_reserved = Balances::reserved_balance(ACCOUNT2);
+
+        assert_ok!(Ownership::accept_pending_ownership(
+            RuntimeOrigin::signed(ACCOUNT2),
+            default_space_entity(),
+        ));
+        assert_ok!(Ownership::accept_pending_ownership(
+            RuntimeOrigin::signed(ACCOUNT2),
+            default_post_entity(),
+        ));
+
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), account1_reserved - 15);
+        assert_eq!(Balances::reserved_balance(ACCOUNT2), account2_reserved + 15);
+        assert_eq!(Spaces::space_by_id(SPACE1).unwrap().deposit.depositor, ACCOUNT2);
+        assert_eq!(Posts::post_by_id(POST1).unwrap().deposit.depositor, ACCOUNT2);
+    });
+}
+
 #[test]
 fn reject_pending_ownership_works() {
     ExtBuilder::build_with_pending_transfers().execute_with(|| {
@@ -185,3 +214,90 @@ fn reject_pending_ownership_should_not_allow_ineligible_account_to_reject() {
         );
     });
 }
+
+#[test]
+fn accept_pending_ownership_should_transfer_only_the_seat_of_a_co_owner() {
+    ExtBuilder::build_with_all_enitities().execute_with(|| {
+        let _m = use_static_mock();
+        let creator_staking_ctx = MockCreatorStaking::is_creator_active_context();
+        creator_staking_ctx.expect().returning(|_| false).times(2);
+
+        assert_ok!(Spaces::propose_space_owner(RuntimeOrigin::signed(ACCOUNT1), SPACE1, ACCOUNT2));
+        assert_ok!(Spaces::accept_space_ownership(RuntimeOrigin::signed(ACCOUNT2), SPACE1));
+
+        // A co-owner transfers their seat to another account.
+        assert_ok!(_transfer_ownership(ACCOUNT2, default_space_entity(), ACCOUNT3));
+        assert_eq!(Ownership::pending_space_transfer_from(SPACE1), Some(ACCOUNT2));
+        assert_ok!(_accept_pending_ownership(ACCOUNT3, default_space_entity()));
+
+        let space = Spaces::space_by_id(SPACE1).unwrap();
+        assert_eq!(space.owner, ACCOUNT1);
+        assert_eq!(space.co_owners.into_inner(), vec![ACCOUNT3]);
+        assert_eq!(space.deposit.depositor, ACCOUNT1);
+        assert_eq!(Ownership::pending_space_transfer_from(SPACE1), None);
+        assert!(Spaces::space_ids_by_owner(ACCOUNT2).is_empty());
+    });
+}
+
+#[test]
+fn transfer_ownership_should_fail_when_another_owner_has_pending_space_transfer() {
+    ExtBuilder::build_with_all_enitities().execute_with(|| {
+        let _m = use_static_mock();
+        let creator_staking_ctx = MockCreatorStaking::is_creator_active_context();
+        creator_staking_ctx.expect().returning(|_| false).times(3);
+
+        assert_ok!(Spaces::propose_space_owner(RuntimeOrigin::signed(ACCOUNT1), SPACE1, ACCOUNT2));
+        assert_ok!(Spaces::accept_space_ownership(RuntimeOrigin::signed(ACCOUNT2), SPACE1));
+        assert_ok!(_transfer_ownership(ACCOUNT2, default_space_entity(), ACCOUNT3));
+
+        // The pending transfer of the co-owner's seat cannot be replaced by another owner.
+        assert_noop!(
+            _transfer_ownership(ACCOUNT1, default_space_entity(), ACCOUNT3),
+            OwnershipError::<Test>::AnotherSpaceTransferIsPending
+        );
+
+        // The co-owner can still replace their own pending transfer.
+        assert_ok!(_transfer_ownership(ACCOUNT2, default_space_entity(), ACCOUNT3));
+        assert_eq!(Ownership::pending_space_transfer_from(SPACE1), Some(ACCOUNT2));
+    });
+}
+
+#[test]
+fn reject_pending_ownership_should_allow_any_space_owner_to_reject() {
+    ExtBuilder::build_with_pending_transfers().execute_with(|| {
+        assert_ok!(Spaces::propose_space_owner(RuntimeOrigin::signed(ACCOUNT1), SPACE1, ACCOUNT3));
+        assert_ok!(Spaces::accept_space_ownership(RuntimeOrigin::signed(ACCOUNT3), SPACE1));
+
+        assert_ok!(_reject_pending_ownership(ACCOUNT3, default_space_entity()));
+        assert_eq!(Ownership::pending_space_transfer_from(SPACE1), None);
+    });
+}
+
+#[test]
+fn delete_space_should_fail_when_space_is_active_creator() {
+    ExtBuilder::build_with_all_enitities().execute_with(|| {
+        let _m = use_static_mock();
+        let creator_staking_ctx = MockCreatorStaking::is_creator_active_context();
+        creator_staking_ctx.expect().returning(|_| true).once();
+
+        assert_noop!(
+            Spaces::delete_space(RuntimeOrigin::signed(ACCOUNT1), SPACE1),
+            SpacesError::<Test>::ActiveCreatorCannotBeDeleted
+        );
+    });
+}
+
+#[test]
+fn delete_space_should_drop_pending_space_transfer() {
+    ExtBuilder::build_with_pending_transfers().execute_with(|| {
+        let _m = use_static_mock();
+        let creator_staking_ctx = MockCreatorStaking::is_creator_active_context();
+        creator_staking_ctx.expect().returning(|_| false).once();
+
+        assert_ok!(Spaces::delete_space(RuntimeOrigin::signed(ACCOUNT1), SPACE1));
+        Spaces::on_idle(System::block_number(), Weight::MAX);
+
+        assert!(Ownership::pending_ownership_transfer(default_space_entity()).is_none());
+        assert!(Ownership::pending_space_transfer_from(SPACE1).is_none());
+    });
+}
@@ -32,6 +32,8 @@ parameter_types! {
       SP::Upvote,
       SP::Downvote,
       SP::Share,
+
+      SP::VoteInPolls,
     ].into_iter().collect()),
 
     // Followers can do everything that everyone else can.
@@ -62,6 +64,10 @@ parameter_types! {
       SP::UpdateEntityStatus,
 
       SP::UpdateSpaceSettings,
+
+      SP::PinPosts,
+
+      SP::InviteMembers,
     ].into_iter().collect()),
   };
 }
@@ -81,6 +81,18 @@ pub enum SpacePermission {
     // Related to space settings:
     /// Allows to update space settings across different pallets.
     UpdateSpaceSettings,
+
+    // Related to pinned posts:
+    /// Pin and unpin root posts of this space.
+    PinPosts,
+
+    // Related to invite-only follows:
+    /// Invite accounts to follow this space, even if it is invite-only.
+    InviteMembers,
+
+    // Related to polls:
+    /// Vote in any poll in this space.
+    VoteInPolls,
 }
 
 pub type SpacePermissionSet = BTreeSet<SpacePermission>;
@@ -146,4 +158,7 @@ pub trait PermissionChecker {
     ) -> DispatchResult {
         Self::ensure_user_has_space_permission(User::Account(account), ctx, permission, error)
     }
+
+    /// Check whether a user holds an enabled and not expired role with a given id in a space.
+    fn has_user_role(user: User<Self::AccountId>, space_id: SpaceId, role_id: u64) -> bool;
 }
@@ -9,11 +9,13 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use frame_benchmarking::{account, benchmarks};
-use frame_support::{dispatch::DispatchError, ensure};
+use frame_support::{
+    dispatch::DispatchError, ensure, sp_runtime::traits::Bounded, traits::Currency,
+};
 use frame_system::RawOrigin;
 
 use pallet_posts::{types::Post, PostExtension, PostById, NextPostId};
-use pallet_spaces::types::Space;
+use pallet_spaces::types::{BalanceOf, Space};
 use subsocial_support::{Content, SpaceId};
 
 use super::*;
@@ -23,6 +25,13 @@ fn create_dummy_space<T: Config>(
 ) -> Result<Space<T>, DispatchError> {
     let space_id = pallet_spaces::NextSpaceId::<T>::get();
 
+    if let RawOrigin::Signed(owner) = &origin {
+        <T as pallet_spaces::Config>::Currency::make_free_balance_be(
+            owner,
+            BalanceOf::<T>::max_value(),
+        );
+    }
+
     pallet_spaces::Pallet::<T>::create_space(origin.into(), Content::None, None)?;
 
     let space = pallet_spaces::SpaceById::<T>::get(space_id)
@@ -28,7 +28,7 @@ pub mod pallet {
     use sp_std::vec::Vec;
     use subsocial_support::{
         remove_from_vec,
-        traits::{IsAccountBlocked, PostFollowsProvider},
+        traits::{IsAccountBlocked, PostDeletionHandler, PostFollowsProvider},
         ModerationError, PostId,
     };
 
@@ -149,4 +149,34 @@ pub mod pallet {
             Pallet::<T>::post_followed_by_account((account, post_id))
         }
     }
+
+    impl<T: Config> PostDeletionHandler for Pallet<T> {
+        /// Unfollow a deleted post on behalf of its followers, one by one.
+        fn cleanup_deleted_post(post_id: PostId, limit: u32) -> u32 {
+            let mut followers = PostFollowers::<T>::get(post_id);
+            let mut removed = 0;
+
+            while removed < limit {
+                let follower = match followers.pop() {
+                    Some(follower) => follower,
+                    None => break,
+                };
+
+                PostsFollowedByAccount::<T>::mutate(&follower, |post_ids| {
+                    remove_from_vec(post_ids, post_id)
+                });
+                PostFollowedByAccount::<T>::remove((follower, post_id));
+
+                removed += 1;
+            }
+
+            if followers.is_empty() {
+                PostFollowers::<T>::remove(post_id);
+            } else {
+                PostFollowers::<T>::insert(post_id, followers);
+            }
+
+            removed
+        }
+    }
 }
@@ -11,13 +11,20 @@
 use codec::Codec;
 use sp_runtime::DispatchResult;
 use sp_runtime::traits::MaybeDisplay;
+use sp_std::vec::Vec;
 
+use pallet_posts::rpc::{FlatPost, FlatPostRevision, PostsFilter};
 use subsocial_support::{Content, PostId, SpaceId};
 
 sp_api::decl_runtime_apis! {
-    pub trait PostsApi<AccountId>
+    /// Version 2 adds the `BlockNumber` and `Balance` parameters, and all methods after
+    /// `can_create_comment`.
+    #[api_version(2)]
+    pub trait PostsApi<AccountId, BlockNumber, Balance>
         where
             AccountId: Codec + MaybeDisplay,
+            BlockNumber: Codec + MaybeDisplay,
+            Balance: Codec + MaybeDisplay,
     {
         fn can_create_post(
             account: AccountId,
@@ -31,5 +38,30 @@ sp_api::decl_runtime_apis! {
             parent_id_opt: Option<PostId>,
             content_opt: Option<Content>
         ) -> DispatchResult;
+
+        fn get_pinned_post_ids(space_id: SpaceId) -> Vec<PostId>;
+
+        fn get_post_revisions(post_id: PostId) -> Vec<FlatPostRevision<AccountId, BlockNumber>>;
+
+        fn get_top_tipped_posts(space_id: SpaceId, limit: u32) -> Vec<(PostId, Balance)>;
+
+        fn can_access_post(account: AccountId, post_id: PostId) -> bool;
+
+        fn get_posts_by_ids(post_ids: Vec<PostId>) -> Vec<FlatPost<AccountId, BlockNumber>>;
+
+        fn get_posts_by_space(
+            space_id: SpaceId,
+            filter: PostsFilter,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<FlatPost<AccountId, BlockNumber>>;
+
+        fn get_reply_tree(post_id: PostId) -> Vec<FlatPost<AccountId, BlockNumber>>;
+
+        fn get_shared_posts(
+            original_post_id: PostId,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<FlatPost<AccountId, BlockNumber>>;
     }
 }
@@ -14,15 +14,17 @@ use jsonrpsee::{
     proc_macros::rpc,
     types::error::{CallError, ErrorObject},
 };
-use sp_api::ProvideRuntimeApi;
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::{ApiError, ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::{DispatchResult, traits::Block as BlockT};
 
+use pallet_posts::rpc::{FlatPost, FlatPostRevision, PostsFilter};
 pub use pallet_posts_rpc_runtime_api::PostsApi as PostsRuntimeApi;
 use subsocial_support::{Content, PostId, SpaceId};
 
 #[rpc(client, server)]
-pub trait PostsApi<AccountId, BlockHash> {
+pub trait PostsApi<AccountId, BlockNumber, Balance, BlockHash> {
     #[method(name = "posts_canCreatePost")]
     fn can_create_post(
         &self,
@@ -41,6 +43,76 @@ pub trait PostsApi<AccountId, BlockHash> {
         content_opt: Option<Content>,
         at: Option<BlockHash>,
     ) -> RpcResult<DispatchResult>;
+
+    #[method(name = "posts_getPinnedPostIds")]
+    fn get_pinned_post_ids(
+        &self,
+        space_id: SpaceId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<PostId>>;
+
+    /// Get the previous revisions of a post's content, from the oldest one.
+    #[method(name = "posts_getPostRevisions")]
+    fn get_post_revisions(
+        &self,
+        post_id: PostId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<FlatPostRevision<AccountId, BlockNumber>>>;
+
+    /// Get up to `limit` most tipped root posts of a space, along with their tips totals.
+    #[method(name = "posts_getTopTippedPosts")]
+    fn get_top_tipped_posts(
+        &self,
+        space_id: SpaceId,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(PostId, Balance)>>;
+
+    /// Check whether an account can comment on and react to a post under its access policy.
+    #[method(name = "posts_canAccessPost")]
+    fn can_access_post(
+        &self,
+        account: AccountId,
+        post_id: PostId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<bool>;
+
+    /// Get the existing posts by their ids.
+    #[method(name = "posts_getPostsByIds")]
+    fn get_posts_by_ids(
+        &self,
+        post_ids: Vec<PostId>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<FlatPost<AccountId, BlockNumber>>>;
+
+    /// Get a page of root posts of a space that match a given filter, from the newest one.
+    #[method(name = "posts_getPostsBySpace")]
+    fn get_posts_by_space(
+        &self,
+        space_id: SpaceId,
+        filter: PostsFilter,
+        offset: u32,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<FlatPost<AccountId, BlockNumber>>>;
+
+    /// Get all replies to a post up to the max comment depth, each right after its parent.
+    #[method(name = "posts_getReplyTree")]
+    fn get_reply_tree(
+        &self,
+        post_id: PostId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<FlatPost<AccountId, BlockNumber>>>;
+
+    /// Get a page of posts that have shared a given post, from the newest one.
+    #[method(name = "posts_getSharedPosts")]
+    fn get_shared_posts(
+        &self,
+        original_post_id: PostId,
+        offset: u32,
+        limit: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<FlatPost<AccountId, BlockNumber>>>;
 }
 
 /// Provides RPC methods for posts pallet.
@@ -61,26 +133,33 @@ impl<C, P> Posts<C, P> {
 pub enum Error {
     /// The call to runtime failed.
     RuntimeError,
+    /// The runtime does not implement the required version of the posts API.
+    UnsupportedApiVersion,
 }
 
 impl From<Error> for i32 {
     fn from(e: Error) -> i32 {
         match e {
             Error::RuntimeError => 1,
+            Error::UnsupportedApiVersion => 2,
         }
     }
 }
 
-impl<C, Block, AccountId>
+impl<C, Block, AccountId, BlockNumber, Balance>
 PostsApiServer<
     AccountId,
+    BlockNumber,
+    Balance,
     <Block as BlockT>::Hash,
 > for Posts<C, Block>
     where
         Block: BlockT,
         C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
-        C::Api: PostsRuntimeApi<Block, AccountId>,
-        AccountId: Clone + Display + Codec + Send + 'static,
+        C::Api: PostsRuntimeApi<Block, AccountId, BlockNumber, Balance>,
+        AccountId: Clone + Display + Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+        BlockNumber: Clone + Display + Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+        Balance: Clone + Display + Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
 {
     fn can_create_post(
         &self,
@@ -116,6 +195,185 @@ PostsApiServer<
 
         Ok(res)
     }
+
+    fn get_pinned_post_ids(
+        &self,
+        space_id: SpaceId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<PostId>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version(
+            api.api_version::<dyn PostsRuntimeApi<Block, AccountId, BlockNumber, Balance>>(at_hash),
+            POSTS_API_V2,
+        )?;
+
+        let res = api
+            .get_pinned_post_ids(at_hash, space_id)
+            .map_err(|e| map_err(e, "Unable to get pinned post ids."))?;
+
+        Ok(res)
+    }
+
+    fn get_post_revisions(
+        &self,
+        post_id: PostId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<FlatPostRevision<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version(
+            api.api_version::<dyn PostsRuntimeApi<Block, AccountId, BlockNumber, Balance>>(at_hash),
+            POSTS_API_V2,
+        )?;
+
+        let res = api
+            .get_post_revisions(at_hash, post_id)
+            .map_err(|e| map_err(e, "Unable to get post revisions."))?;
+
+        Ok(res)
+    }
+
+    fn get_top_tipped_posts(
+        &self,
+        space_id: SpaceId,
+        limit: u32,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<(PostId, Balance)>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version(
+            api.api_version::<dyn PostsRuntimeApi<Block, AccountId, BlockNumber, Balance>>(at_hash),
+            POSTS_API_V2,
+        )?;
+
+        let res = api
+            .get_top_tipped_posts(at_hash, space_id, limit)
+            .map_err(|e| map_err(e, "Unable to get top tipped posts."))?;
+
+        Ok(res)
+    }
+
+    fn can_access_post(
+        &self,
+        account: AccountId,
+        post_id: PostId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<bool> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version(
+            api.api_version::<dyn PostsRuntimeApi<Block, AccountId, BlockNumber, Balance>>(at_hash),
+            POSTS_API_V2,
+        )?;
+
+        let res = api
+            .can_access_post(at_hash, account, post_id)
+            .map_err(|e| map_err(e, "Unable to check access to post."))?;
+
+        Ok(res)
+    }
+
+    fn get_posts_by_ids(
+        &self,
+        post_ids: Vec<PostId>,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<FlatPost<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version(
+            api.api_version::<dyn PostsRuntimeApi<Block, AccountId, BlockNumber, Balance>>(at_hash),
+            POSTS_API_V2,
+        )?;
+
+        let res = api
+            .get_posts_by_ids(at_hash, post_ids)
+            .map_err(|e| map_err(e, "Unable to get posts by ids."))?;
+
+        Ok(res)
+    }
+
+    fn get_posts_by_space(
+        &self,
+        space_id: SpaceId,
+        filter: PostsFilter,
+        offset: u32,
+        limit: u32,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<FlatPost<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version(
+            api.api_version::<dyn PostsRuntimeApi<Block, AccountId, BlockNumber, Balance>>(at_hash),
+            POSTS_API_V2,
+        )?;
+
+        let res = api
+            .get_posts_by_space(at_hash, space_id, filter, offset, limit)
+            .map_err(|e| map_err(e, "Unable to get posts by space."))?;
+
+        Ok(res)
+    }
+
+    fn get_reply_tree(
+        &self,
+        post_id: PostId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<FlatPost<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version(
+            api.api_version::<dyn PostsRuntimeApi<Block, AccountId, BlockNumber, Balance>>(at_hash),
+            POSTS_API_V2,
+        )?;
+
+        let res = api
+            .get_reply_tree(at_hash, post_id)
+            .map_err(|e| map_err(e, "Unable to get reply tree."))?;
+
+        Ok(res)
+    }
+
+    fn get_shared_posts(
+        &self,
+        original_post_id: PostId,
+        offset: u32,
+        limit: u32,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<FlatPost<AccountId, BlockNumber>>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version(
+            api.api_version::<dyn PostsRuntimeApi<Block, AccountId, BlockNumber, Balance>>(at_hash),
+            POSTS_API_V2,
+        )?;
+
+        let res = api
+            .get_shared_posts(at_hash, original_post_id, offset, limit)
+            .map_err(|e| map_err(e, "Unable to get shared posts."))?;
+
+        Ok(res)
+    }
+}
+
+/// The first version of `PostsApi` with the post listing, revision, tip and access methods.
+const POSTS_API_V2: u32 = 2;
+
+fn ensure_api_version(
+    version: Result<Option<u32>, ApiError>,
+    required: u32,
+) -> Result<(), CallError> {
+    let version = version.map_err(|e| map_err(e, "Unable to get posts API version."))?;
+
+    if version.map_or(true, |v| v < required) {
+        return Err(CallError::Custom(ErrorObject::owned(
+            Error::UnsupportedApiVersion.into(),
+            "The runtime does not support this method of the posts API.",
+            version.map(|v| format!("Required version {}, runtime has {}", required, v)),
+        )))
+    }
+
+    Ok(())
 }
 
 fn map_err(error: impl ToString, desc: &'static str) -> CallError {
@@ -7,15 +7,30 @@
 
 
 use super::*;
-use frame_benchmarking::{benchmarks, whitelisted_caller};
-use frame_support::dispatch::DispatchError;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{dispatch::DispatchError, traits::Currency};
 use frame_system::RawOrigin;
 use pallet_spaces::types::Space;
-use subsocial_support::Content;
+use sp_runtime::traits::Bounded;
+use sp_std::vec::Vec;
+use subsocial_support::{traits::SpaceDeletionHandler, Content};
+
+/// The number of other posts in a space and shares of a post in the worst-case benchmarks,
+/// whose id lists are decoded and rewritten with each removed post.
+/// Removing a comment rewrites the replies of its parent the same way
+/// as removing a shared post rewrites the shares of the original post.
+const LARGE_POST_IDS_LIST_LEN: u64 = 10_000;
 
 fn create_dummy_space<T: Config>(
     origin: RawOrigin<T::AccountId>,
 ) -> Result<Space<T>, DispatchError> {
+    if let RawOrigin::Signed(owner) = &origin {
+        <T as pallet_spaces::Config>::Currency::make_free_balance_be(
+            owner,
+            BalanceOf::<T>::max_value(),
+        );
+    }
+
     let space_id = pallet_spaces::NextSpaceId::<T>::get();
 
     pallet_spaces::Pallet::<T>::create_space(origin.into(), Content::None, None)?;
@@ -113,6 +128,21 @@ benchmarks! {
         ensure!(post.extension == ext, "Post wasn't created with the right extension");
     }
 
+    create_post__poll {
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let space = create_dummy_space::<T>(origin.clone())?;
+        let post_id = NextPostId::<T>::get();
+
+        let ext = PostExtension::Poll(PollExtension {
+            options_count: T::MaxPollOptions::get() as u8,
+            voting_period: 10,
+        });
+    }: create_post(origin, Some(space.id), ext, Content::None)
+    verify {
+        ensure!(PostById::<T>::get(post_id).is_some(), "Poll wasn't created");
+        ensure!(PollByPostId::<T>::get(post_id).is_some(), "Poll wasn't created");
+    }
+
 
     update_post {
         let origin = RawOrigin::Signed(whitelisted_caller());
@@ -150,4 +180,187 @@ benchmarks! {
 
         ensure!(moved_post.space_id == Some(new_space.id), "Post wasn't moved");
     }
+
+    pin_post {
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let space = create_dummy_space::<T>(origin.clone())?;
+        let post = create_dummy_post::<T>(origin.clone(), space.clone())?;
+    }: pin_post(origin, space.id, post.id)
+    verify {
+        ensure!(
+            PinnedPostIdsBySpaceId::<T>::get(space.id).contains(&post.id),
+            "Post wasn't pinned"
+        );
+    }
+
+    unpin_post {
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let space = create_dummy_space::<T>(origin.clone())?;
+        let post = create_dummy_post::<T>(origin.clone(), space.clone())?;
+        Pallet::<T>::pin_post(origin.clone().into(), space.id, post.id)?;
+    }: unpin_post(origin, space.id, post.id)
+    verify {
+        ensure!(
+            !PinnedPostIdsBySpaceId::<T>::get(space.id).contains(&post.id),
+            "Post wasn't unpinned"
+        );
+    }
+
+    delete_post {
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let space = create_dummy_space::<T>(origin.clone())?;
+        let post = create_dummy_post::<T>(origin.clone(), space.clone())?;
+        Pallet::<T>::pin_post(origin.clone().into(), space.id, post.id)?;
+    }: delete_post(origin, post.id)
+    verify {
+        ensure!(PostById::<T>::get(post.id).is_none(), "Post wasn't deleted");
+        ensure!(PostTombstoneById::<T>::get(post.id).is_some(), "Post tombstone wasn't created");
+    }
+
+    schedule_post {
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let space = create_dummy_space::<T>(origin.clone())?;
+        let post_id = NextPostId::<T>::get();
+        let publish_at = frame_system::Pallet::<T>::block_number() + 1u32.into();
+    }: schedule_post(origin, space.id, Content::None, publish_at)
+    verify {
+        ensure!(ScheduledPostById::<T>::get(post_id).is_some(), "Post wasn't scheduled");
+    }
+
+    cancel_scheduled_post {
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let space = create_dummy_space::<T>(origin.clone())?;
+        let post_id = NextPostId::<T>::get();
+        let publish_at = frame_system::Pallet::<T>::block_number() + 1u32.into();
+        Pallet::<T>::schedule_post(origin.clone().into(), space.id, Content::None, publish_at)?;
+    }: cancel_scheduled_post(origin, post_id)
+    verify {
+        ensure!(ScheduledPostById::<T>::get(post_id).is_none(), "Scheduled post wasn't canceled");
+    }
+
+    publish_scheduled_posts {
+        let n in 0 .. T::MaxScheduledPostsPerBlock::get();
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let space = create_dummy_space::<T>(origin.clone())?;
+        let publish_at = frame_system::Pallet::<T>::block_number() + 1u32.into();
+
+        for _ in 0 .. n {
+            Pallet::<T>::schedule_post(origin.clone().into(), space.id, Content::None, publish_at)?;
+        }
+    }: {
+        Pallet::<T>::publish_scheduled_posts(publish_at);
+    }
+    verify {
+        ensure!(ScheduledPostIdsByBlock::<T>::get(publish_at).is_empty(), "Posts weren't published");
+        ensure!(
+            PostIdsBySpaceId::<T>::get(space.id).len() == n as usize,
+            "Not all scheduled posts were published"
+        );
+    }
+
+    vote_in_poll {
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let space = create_dummy_space::<T>(origin.clone())?;
+        let post_id = NextPostId::<T>::get();
+
+        let ext = PostExtension::Poll(PollExtension { options_count: 2, voting_period: 10 });
+        Pallet::<T>::create_post(origin.clone().into(), Some(space.id), ext, Content::None)?;
+    }: vote_in_poll(origin, post_id, 1)
+    verify {
+        let poll = PollByPostId::<T>::get(post_id).ok_or(DispatchError::Other("Poll not found"))?;
+        ensure!(poll.tallies[1] == 1, "Vote wasn't counted");
+    }
+
+    tip_post {
+        let owner = account::<T::AccountId>("owner", 1, 0);
+        let space = create_dummy_space::<T>(RawOrigin::Signed(owner.clone()))?;
+        let post = create_dummy_post::<T>(RawOrigin::Signed(owner.clone()), space)?;
+
+        let amount = <T as pallet_spaces::Config>::Currency::minimum_balance();
+        // Leave room for the tip in the balance of the post owner.
+        <T as pallet_spaces::Config>::Currency::make_free_balance_be(&owner, amount);
+
+        let tipper: T::AccountId = whitelisted_caller();
+        <T as pallet_spaces::Config>::Currency::make_free_balance_be(
+            &tipper,
+            BalanceOf::<T>::max_value(),
+        );
+    }: tip_post(RawOrigin::Signed(tipper), post.id, amount)
+    verify {
+        ensure!(TipsTotalByPostId::<T>::get(post.id) == amount, "Tip wasn't counted");
+    }
+
+    set_post_access_policy {
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let space = create_dummy_space::<T>(origin.clone())?;
+        let post = create_dummy_post::<T>(origin.clone(), space)?;
+
+        let policy = PostAccessPolicy::CreatorBackers {
+            min_stake: <T as pallet_spaces::Config>::Currency::minimum_balance(),
+        };
+    }: set_post_access_policy(origin, post.id, Some(policy.clone()))
+    verify {
+        ensure!(
+            PostAccessPolicyByPostId::<T>::get(post.id) == Some(policy),
+            "Access policy wasn't set"
+        );
+    }
+
+    cleanup_deleted_post {
+        let n in 0 .. 100;
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let space = create_dummy_space::<T>(origin.clone())?;
+        let post_id = NextPostId::<T>::get();
+
+        let ext = PostExtension::Poll(PollExtension { options_count: 2, voting_period: 10 });
+        Pallet::<T>::create_post(origin.clone().into(), Some(space.id), ext, Content::None)?;
+
+        for i in 0 .. n {
+            let voter = account::<T::AccountId>("voter", i, 0);
+            PollVoteByAccount::<T>::insert(post_id, voter, 0);
+        }
+
+        Pallet::<T>::delete_post(origin.into(), post_id)?;
+    }: {
+        Pallet::<T>::cleanup_deleted_post(post_id, n + 1);
+    }
+    verify {
+        ensure!(
+            PollVoteByAccount::<T>::iter_prefix(post_id).next().is_none(),
+            "Poll votes weren't removed"
+        );
+    }
+
+    cleanup_deleted_space {
+        let n in 1 .. 100;
+        let origin = RawOrigin::Signed(whitelisted_caller());
+        let original_space = create_dummy_space::<T>(origin.clone())?;
+        let original_post = create_dummy_post::<T>(origin.clone(), original_space)?;
+        let space = create_dummy_space::<T>(origin.clone())?;
+
+        for _ in 0 .. n {
+            Pallet::<T>::create_post(
+                origin.clone().into(),
+                Some(space.id),
+                PostExtension::SharedPost(original_post.id),
+                Content::None,
+            )?;
+        }
+
+        let padding = (u64::MAX - LARGE_POST_IDS_LIST_LEN .. u64::MAX).collect::<Vec<PostId>>();
+        PostIdsBySpaceId::<T>::mutate(space.id, |ids| {
+            *ids = padding.iter().copied().chain(ids.iter().copied()).collect()
+        });
+        SharedPostIdsByOriginalPostId::<T>::mutate(original_post.id, |ids| {
+            *ids = padding.iter().copied().chain(ids.iter().copied()).collect()
+        });
+    }: {
+        <Pallet<T> as SpaceDeletionHandler>::cleanup_deleted_space(space.id, n);
+    }
+    verify {
+        ensure!(
+            PostIdsBySpaceId::<T>::get(space.id).len() == padding.len(),
+            "Posts of the space weren't removed"
+        );
+    }
 }
@@ -4,11 +4,18 @@
 // Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
 // Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
 
-use frame_support::dispatch::DispatchResult;
+use frame_support::{dispatch::DispatchResult, weights::Weight};
 use sp_runtime::traits::Saturating;
+use sp_std::vec;
 
+use pallet_permissions::PermissionChecker;
 use subsocial_support::{remove_from_vec, SpaceId};
-use subsocial_support::traits::PostsProvider;
+use subsocial_support::traits::{
+    BackerStakesProvider, PostsProvider, SpaceDeletionHandler, SpaceSettingInfo,
+    SpaceSettingsRegistry,
+};
+
+use crate::weights::WeightInfo;
 
 use super::*;
 
@@ -24,13 +31,18 @@ impl<T: Config> Post<T> {
             id,
             created: new_who_and_when::<T>(created_by.clone()),
             edited: false,
-            owner: created_by,
+            owner: created_by.clone(),
+            author: User::Account(created_by.clone()),
             extension,
             space_id: space_id_opt,
             content,
             hidden: false,
             upvotes_count: 0,
             downvotes_count: 0,
+            replies_count: 0,
+            shares_count: 0,
+            // A deposit is reserved only when a post is actually stored.
+            deposit: StorageDeposit::free(created_by),
         }
     }
 
@@ -43,6 +55,12 @@ impl<T: Config> Post<T> {
         self.owner == *account
     }
 
+    /// Get the id of a space that authored this post, if the post was created on behalf of a
+    /// space.
+    pub fn author_space_id(&self) -> Option<SpaceId> {
+        self.author.clone().maybe_space()
+    }
+
     pub fn is_root_post(&self) -> bool {
         !self.is_comment()
     }
@@ -59,6 +77,10 @@ impl<T: Config> Post<T> {
         matches!(self.extension, PostExtension::SharedPost(_))
     }
 
+    pub fn is_poll(&self) -> bool {
+        matches!(self.extension, PostExtension::Poll(_))
+    }
+
     pub fn get_comment_ext(&self) -> Result<Comment, DispatchError> {
         match self.extension {
             PostExtension::Comment(comment_ext) => Ok(comment_ext),
@@ -75,35 +97,45 @@ impl<T: Config> Post<T> {
 
     pub fn get_root_post(&self) -> Result<Post<T>, DispatchError> {
         match self.extension {
-            PostExtension::RegularPost | PostExtension::SharedPost(_) => Ok(self.clone()),
+            PostExtension::RegularPost | PostExtension::SharedPost(_) | PostExtension::Poll(_) =>
+                Ok(self.clone()),
             PostExtension::Comment(comment) => Pallet::<T>::require_post(comment.root_post_id),
         }
     }
 
+    /// Get the space id of the root post of this post. The space of a deleted root post
+    /// is taken from its tombstone, so that its replies stay in the same space.
+    fn get_root_post_space_id(&self) -> Result<Option<SpaceId>, DispatchError> {
+        let root_post_id = match self.extension {
+            PostExtension::Comment(comment) => comment.root_post_id,
+            _ => return Ok(self.space_id),
+        };
+
+        if let Some(root_post) = Pallet::<T>::post_by_id(root_post_id) {
+            return Ok(root_post.space_id)
+        }
+
+        Pallet::<T>::post_tombstone_by_id(root_post_id)
+            .map(|tombstone| tombstone.space_id)
+            .ok_or_else(|| Error::<T>::PostNotFound.into())
+    }
+
     pub fn get_space_id(&self) -> Result<SpaceId, DispatchError> {
         Self::try_get_space_id(self).ok_or_else(|| Error::<T>::PostHasNoSpaceId.into())
     }
 
     pub fn try_get_space_id(&self) -> Option<SpaceId> {
-        if let Ok(root_post) = self.get_root_post() {
-            return root_post.space_id
-        }
-
-        None
+        self.get_root_post_space_id().ok().flatten()
     }
 
     pub fn get_space(&self) -> Result<Space<T>, DispatchError> {
-        let root_post = self.get_root_post()?;
-        let space_id = root_post.space_id.ok_or(Error::<T>::PostHasNoSpaceId)?;
+        let space_id = self.get_root_post_space_id()?.ok_or(Error::<T>::PostHasNoSpaceId)?;
         Spaces::require_space(space_id)
     }
 
     pub fn try_get_space(&self) -> Option<Space<T>> {
-        if let Ok(root_post) = self.get_root_post() {
-            return root_post.space_id.and_then(|space_id| Spaces::require_space(space_id).ok())
-        }
-
-        None
+        self.try_get_space_id()
+            .and_then(|space_id| Spaces::require_space(space_id).ok())
     }
 
     pub fn try_get_parent_id(&self) -> Option<PostId> {
@@ -129,6 +161,22 @@ impl<T: Config> Post<T> {
         self.downvotes_count.saturating_dec();
     }
 
+    pub fn inc_replies(&mut self) {
+        self.replies_count.saturating_inc();
+    }
+
+    pub fn dec_replies(&mut self) {
+        self.replies_count.saturating_dec();
+    }
+
+    pub fn inc_shares(&mut self) {
+        self.shares_count.saturating_inc();
+    }
+
+    pub fn dec_shares(&mut self) {
+        self.shares_count.saturating_dec();
+    }
+
     pub fn is_public(&self) -> bool {
         !self.hidden && self.content.is_some()
     }
@@ -138,6 +186,18 @@ impl<T: Config> Post<T> {
     }
 }
 
+impl<T: Config> PostTombstone<T> {
+    pub fn new(post: &Post<T>, deleted_by: T::AccountId) -> Self {
+        PostTombstone {
+            id: post.id,
+            extension: post.extension,
+            space_id: post.space_id,
+            owner: post.owner.clone(),
+            deleted: new_who_and_when::<T>(deleted_by),
+        }
+    }
+}
+
 impl<T: Config> Pallet<T> {
     pub fn ensure_account_can_update_post(
         editor: &T::AccountId,
@@ -182,6 +242,32 @@ impl<T: Config> Pallet<T> {
         )
     }
 
+    pub fn ensure_account_can_delete_post(
+        account: &T::AccountId,
+        post: &Post<T>,
+        space: &Space<T>,
+    ) -> DispatchResult {
+        ensure!(
+            T::IsAccountBlocked::is_allowed_account(account.clone(), space.id),
+            ModerationError::AccountIsBlocked
+        );
+
+        let (permission_to_check, permission_error) = if !post.is_owner(account) {
+            (SpacePermission::DeleteAnyPost, Error::<T>::NoPermissionToDeleteAnyPost)
+        } else if post.is_comment() {
+            (SpacePermission::DeleteOwnComments, Error::<T>::NoPermissionToDeleteOwnComments)
+        } else {
+            (SpacePermission::DeleteOwnPosts, Error::<T>::NoPermissionToDeleteOwnPosts)
+        };
+
+        Spaces::ensure_account_has_space_permission(
+            account.clone(),
+            space,
+            permission_to_check,
+            permission_error.into(),
+        )
+    }
+
     /// Check that there is a `Post` with such `post_id` in the storage
     /// or return`PostNotFound` error.
     pub fn ensure_post_exists(post_id: PostId) -> DispatchResult {
@@ -220,19 +306,103 @@ impl<T: Config> Pallet<T> {
         })
     }
 
-    // TODO refactor to a tail recursion
-    /// Get all post ancestors (parent_id) including this post
-    pub fn get_post_ancestors(post_id: PostId) -> Vec<Post<T>> {
-        let mut ancestors: Vec<Post<T>> = Vec::new();
+    /// Get the depth of a comment: the number of comments from this one up to the root post,
+    /// including this comment and any deleted comments in between.
+    /// Counting stops at `MaxCommentDepth`, as deeper comments cannot be created.
+    pub fn get_comment_depth(comment_id: PostId) -> Result<u32, DispatchError> {
+        let mut depth = 0;
+        let mut comment_id_opt = Some(comment_id);
 
-        if let Some(post) = Self::post_by_id(post_id) {
-            ancestors.push(post.clone());
-            if let Some(parent_id) = post.get_comment_ext().ok().unwrap().parent_id {
-                ancestors.extend(Self::get_post_ancestors(parent_id).iter().cloned());
+        while let Some(comment_id) = comment_id_opt {
+            if depth >= T::MaxCommentDepth::get() {
+                break;
             }
+
+            let extension = match Self::post_by_id(comment_id) {
+                Some(comment) => comment.extension,
+                None =>
+                    Self::post_tombstone_by_id(comment_id)
+                        .ok_or(Error::<T>::PostNotFound)?
+                        .extension,
+            };
+
+            let comment_ext = match extension {
+                PostExtension::Comment(comment_ext) => comment_ext,
+                _ => return Err(Error::<T>::NotComment.into()),
+            };
+
+            depth += 1;
+            comment_id_opt = comment_ext.parent_id;
+        }
+
+        Ok(depth)
+    }
+
+    /// Create a new post or comment on behalf of `creator`, who becomes its owner.
+    /// If `author_space_opt` is provided, `creator` acts on behalf of that space,
+    /// which is recorded as the author of the post.
+    pub(crate) fn do_create_post(
+        creator: T::AccountId,
+        space_id_opt: Option<SpaceId>,
+        extension: PostExtension,
+        content: Content,
+        author_space_opt: Option<SpaceId>,
+    ) -> Result<PostId, DispatchError> {
+        let new_post_id = Self::next_post_id();
+        let mut new_post: Post<T> =
+            Post::new(new_post_id, creator.clone(), space_id_opt, extension, content.clone());
+
+        // Get space from either space_id_opt or Comment if a comment provided
+        let space = &new_post.get_space()?;
+        let root_post = &mut new_post.get_root_post()?;
+
+        if let Some(author_space_id) = author_space_opt {
+            Spaces::<T>::ensure_can_represent_space(&creator, author_space_id, space.id)?;
+            new_post.author = User::Space(author_space_id);
+        }
+
+        // Check whether account has permission to create Post (by extension)
+        let mut permission_to_check = SpacePermission::CreatePosts;
+        let mut error_on_permission_failed = Error::<T>::NoPermissionToCreatePosts;
+
+        if let PostExtension::Comment(_) = extension {
+            permission_to_check = SpacePermission::CreateComments;
+            error_on_permission_failed = Error::<T>::NoPermissionToCreateComments;
+        }
+
+        Self::ensure_can_create_post(
+            creator.clone(),
+            &new_post,
+            Some(content.clone()),
+            permission_to_check,
+            error_on_permission_failed.into(),
+        )?;
+
+        match extension {
+            PostExtension::SharedPost(original_post_id) =>
+                Self::create_shared_post(&creator, new_post_id, original_post_id)?,
+            PostExtension::Comment(comment_ext) =>
+                Self::create_comment(new_post_id, comment_ext, root_post.id)?,
+            PostExtension::Poll(poll_ext) => Self::create_poll(new_post_id, poll_ext)?,
+            _ => (),
+        }
+
+        new_post.deposit = StorageDeposit::reserve::<<T as pallet_spaces::Config>::Currency>(
+            creator.clone(),
+            T::PostDeposit::get(),
+        )?;
+
+        if new_post.is_root_post() {
+            PostIdsBySpaceId::<T>::mutate(space.id, |ids| ids.push(new_post_id));
+            Self::add_post_to_space_counters(&new_post, space.id);
         }
 
-        ancestors
+        PostById::insert(new_post_id, new_post);
+        NextPostId::<T>::mutate(|n| {
+            *n += 1;
+        });
+
+        Ok(new_post_id)
     }
 
     pub(crate) fn create_comment(
@@ -248,6 +418,7 @@ impl<T: Config> Pallet<T> {
         }
 
         ReplyIdsByPostId::<T>::mutate(commented_post_id, |reply_ids| reply_ids.push(new_post_id));
+        Self::mutate_post_by_id(commented_post_id, |post| post.inc_replies())?;
 
         Ok(())
     }
@@ -271,6 +442,28 @@ impl<T: Config> Pallet<T> {
         )?;
 
         SharedPostIdsByOriginalPostId::<T>::mutate(original_post_id, |ids| ids.push(new_post_id));
+
+        original_post.inc_shares();
+        PostById::<T>::insert(original_post_id, original_post);
+        Ok(())
+    }
+
+    pub(crate) fn create_poll(new_post_id: PostId, poll_ext: PollExtension) -> DispatchResult {
+        let PollExtension { options_count, voting_period } = poll_ext;
+
+        ensure!(
+            options_count >= 2 && u32::from(options_count) <= T::MaxPollOptions::get(),
+            Error::<T>::InvalidPollOptionsCount
+        );
+        ensure!(voting_period > 0, Error::<T>::PollVotingPeriodIsZero);
+
+        let closes_at =
+            frame_system::Pallet::<T>::block_number().saturating_add(voting_period.into());
+
+        PollByPostId::<T>::insert(
+            new_post_id,
+            Poll { closes_at, tallies: vec![0; options_count as usize] },
+        );
         Ok(())
     }
 
@@ -281,6 +474,7 @@ impl<T: Config> Pallet<T> {
     ) -> DispatchResult {
         let old_space_id_opt = post.try_get_space_id();
         let new_space = Spaces::<T>::require_space(new_space_id)?;
+        new_space.ensure_not_archived()?;
 
         ensure!(
             T::IsAccountBlocked::is_allowed_account(editor.clone(), new_space_id),
@@ -302,14 +496,17 @@ impl<T: Config> Pallet<T> {
         );
 
         match post.extension {
-            PostExtension::RegularPost | PostExtension::SharedPost(_) => {
+            PostExtension::RegularPost | PostExtension::SharedPost(_) | PostExtension::Poll(_) => {
                 if let Some(old_space_id) = old_space_id_opt {
                     PostIdsBySpaceId::<T>::mutate(old_space_id, |post_ids| {
                         remove_from_vec(post_ids, post.id)
                     });
+                    Self::unpin_post_in_space(old_space_id, post.id);
+                    Self::remove_post_from_space_counters(post, old_space_id);
                 }
 
                 PostIdsBySpaceId::<T>::mutate(new_space_id, |post_ids| post_ids.push(post.id));
+                Self::add_post_to_space_counters(post, new_space_id);
 
                 post.space_id = Some(new_space_id);
                 PostById::<T>::insert(post.id, post);
@@ -330,6 +527,8 @@ impl<T: Config> Pallet<T> {
 
             post.space_id = None;
             PostIdsBySpaceId::<T>::mutate(space_id, |post_ids| remove_from_vec(post_ids, post_id));
+            Self::unpin_post_in_space(space_id, post_id);
+            Self::remove_post_from_space_counters(&post, space_id);
         }
 
         PostById::insert(post.id, post);
@@ -337,6 +536,292 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// Remove a post from the storage along with its indexes.
+    /// The replies of this post are not removed.
+    pub(crate) fn remove_post(post: &Post<T>) {
+        if let PostExtension::Comment(ext) = post.extension {
+            let commented_post_id = ext.parent_id.unwrap_or(ext.root_post_id);
+            ReplyIdsByPostId::<T>::mutate(commented_post_id, |reply_ids| {
+                remove_from_vec(reply_ids, post.id)
+            });
+            let _ = Self::mutate_post_by_id(commented_post_id, |post| post.dec_replies());
+        }
+
+        if let (true, Some(space_id)) = (post.is_root_post(), post.space_id) {
+            PostIdsBySpaceId::<T>::mutate(space_id, |ids| remove_from_vec(ids, post.id));
+        }
+
+        ReplyIdsByPostId::<T>::remove(post.id);
+        Self::remove_post_keeping_replies(post);
+    }
+
+    /// Remove a post from the storage along with its indexes, except for the reply tree:
+    /// a comment stays among the replies of its parent, a root post stays among the posts
+    /// of its space, and the replies of this post stay linked to it.
+    /// This way the replies can still be reached when the space is deleted.
+    pub(crate) fn remove_post_keeping_replies(post: &Post<T>) {
+        if let PostExtension::SharedPost(original_post_id) = post.extension {
+            SharedPostIdsByOriginalPostId::<T>::mutate(original_post_id, |ids| {
+                remove_from_vec(ids, post.id)
+            });
+            let _ = Self::mutate_post_by_id(original_post_id, |post| post.dec_shares());
+        }
+
+        // Comments may have a space id too, but they are not counted in their space.
+        if let (true, Some(space_id)) = (post.is_root_post(), post.space_id) {
+            Self::unpin_post_in_space(space_id, post.id);
+            Self::remove_post_from_space_counters(post, space_id);
+        }
+
+        post.deposit.release::<<T as pallet_spaces::Config>::Currency>();
+
+        SharedPostIdsByOriginalPostId::<T>::remove(post.id);
+        PostRevisionsByPostId::<T>::remove(post.id);
+        LastEditByPostId::<T>::remove(post.id);
+        TipsTotalByPostId::<T>::remove(post.id);
+        PostAccessPolicyByPostId::<T>::remove(post.id);
+        PollByPostId::<T>::remove(post.id);
+        PostById::<T>::remove(post.id);
+    }
+
+    /// Remove at most `limit` poll votes, reactions and followers of a deleted post
+    /// and return the number of removed items. Returning less than `limit` means that
+    /// there is nothing left to remove.
+    pub(crate) fn cleanup_deleted_post(post_id: PostId, limit: u32) -> u32 {
+        if limit == 0 {
+            return 0
+        }
+
+        let cursor = PollVotesCleanupCursor::<T>::take(post_id);
+        let result = PollVoteByAccount::<T>::clear_prefix(post_id, limit, cursor.as_deref());
+        if let Some(cursor) = result.maybe_cursor {
+            PollVotesCleanupCursor::<T>::insert(post_id, cursor);
+            return limit
+        }
+
+        let removed = result.unique;
+        removed.saturating_add(T::PostDeletionHandler::cleanup_deleted_post(
+            post_id,
+            limit.saturating_sub(removed),
+        ))
+    }
+
+    /// Add a deleted post to the end of the queue of deleted posts.
+    pub(crate) fn enqueue_post_cleanup(post_id: PostId) {
+        let tail = PostCleanupQueueTail::<T>::get();
+        PostIdsPendingCleanup::<T>::insert(tail, post_id);
+        PostCleanupQueueTail::<T>::put(tail.saturating_add(1));
+    }
+
+    /// Clean up deleted posts in the order of deletion, as long as `max_weight` allows.
+    pub(crate) fn cleanup_deleted_posts(max_weight: Weight) -> Weight {
+        let mut used_weight = T::DbWeight::get().reads(2);
+
+        let base_weight = <T as Config>::WeightInfo::cleanup_deleted_post(0);
+        let item_weight =
+            <T as Config>::WeightInfo::cleanup_deleted_post(1).saturating_sub(base_weight);
+
+        let first = PostCleanupQueueHead::<T>::get();
+        let tail = PostCleanupQueueTail::<T>::get();
+        let mut head = first;
+
+        while head < tail {
+            used_weight.saturating_accrue(T::DbWeight::get().reads(1));
+            let post_id = match PostIdsPendingCleanup::<T>::get(head) {
+                Some(post_id) => post_id,
+                None => {
+                    head += 1;
+                    continue
+                },
+            };
+
+            let remaining_weight =
+                max_weight.saturating_sub(used_weight).saturating_sub(base_weight);
+            let limit = remaining_weight
+                .checked_div_per_component(&item_weight)
+                .unwrap_or_default()
+                .min(u32::MAX as u64) as u32;
+
+            if limit == 0 {
+                break;
+            }
+
+            let removed = Self::cleanup_deleted_post(post_id, limit);
+            used_weight.saturating_accrue(<T as Config>::WeightInfo::cleanup_deleted_post(removed));
+
+            if removed >= limit {
+                break;
+            }
+
+            PostIdsPendingCleanup::<T>::remove(head);
+            used_weight.saturating_accrue(T::DbWeight::get().writes(1));
+            head += 1;
+        }
+
+        if head != first {
+            PostCleanupQueueHead::<T>::put(head);
+            used_weight.saturating_accrue(T::DbWeight::get().writes(1));
+        }
+
+        used_weight
+    }
+
+    /// Count a root post in the posts counters of a given space.
+    pub(crate) fn add_post_to_space_counters(post: &Post<T>, space_id: SpaceId) {
+        let _ = Spaces::<T>::mutate_space_by_id(space_id, |space| {
+            space.inc_posts();
+            if post.hidden {
+                space.inc_hidden_posts();
+            }
+        });
+    }
+
+    /// Stop counting a root post in the posts counters of a given space.
+    /// Does nothing if the space has already been deleted.
+    pub(crate) fn remove_post_from_space_counters(post: &Post<T>, space_id: SpaceId) {
+        let _ = Spaces::<T>::mutate_space_by_id(space_id, |space| {
+            space.dec_posts();
+            if post.hidden {
+                space.dec_hidden_posts();
+            }
+        });
+    }
+
+    /// Hide or unhide a post, keeping the hidden posts counter of its space in sync.
+    /// The updated post is not saved to the storage.
+    pub fn set_post_hidden(post: &mut Post<T>, hidden: bool) {
+        if post.hidden == hidden {
+            return
+        }
+
+        post.hidden = hidden;
+
+        if let (true, Some(space_id)) = (post.is_root_post(), post.space_id) {
+            let _ = Spaces::<T>::mutate_space_by_id(space_id, |space| {
+                if hidden {
+                    space.inc_hidden_posts();
+                } else {
+                    space.dec_hidden_posts();
+                }
+            });
+
+            if hidden {
+                Self::unpin_post_in_space(space_id, post.id);
+            }
+        }
+
+        if !hidden {
+            T::PostVisibilityHandler::on_post_unhidden(post.id);
+        }
+    }
+
+    /// Append the current content of a post to its revisions, before the content is replaced.
+    /// When the revisions are full, the oldest one is dropped to make room, so that the stored
+    /// revisions never shrink, even if the retention of the post's space was lowered.
+    /// Nothing is recorded when the retention is disabled.
+    pub(crate) fn add_post_revision(post: &Post<T>, space_opt: Option<&Space<T>>) {
+        let retention = space_opt
+            .map(|space| Spaces::<T>::space_setting::<PostRevisionsRetentionSetting>(space.id))
+            .unwrap_or_default();
+
+        let max_revisions = match retention {
+            PostRevisionsRetention::Disabled => 0,
+            PostRevisionsRetention::KeepLatest(count) => count.min(T::MaxPostRevisions::get()),
+        } as usize;
+
+        if max_revisions == 0 {
+            return
+        }
+
+        PostRevisionsByPostId::<T>::mutate(post.id, |revisions| {
+            if revisions.len() >= max_revisions || revisions.is_full() {
+                revisions.remove(0);
+            }
+
+            let revision = PostRevision {
+                content: post.content.clone(),
+                edited: Self::last_edit_by_post_id(post.id)
+                    .unwrap_or_else(|| post.created.clone()),
+            };
+            // Cannot fail, as one revision was dropped if the revisions were full.
+            let _ = revisions.try_push(revision);
+        });
+    }
+
+    /// Publish the posts scheduled at a given block. A scheduled post that cannot be created
+    /// anymore is dropped, and its deposit is returned to the author.
+    pub(crate) fn publish_scheduled_posts(now: BlockNumberFor<T>) -> Weight {
+        let post_ids = ScheduledPostIdsByBlock::<T>::take(now);
+
+        for post_id in post_ids.iter().copied() {
+            if let Some(scheduled_post) = ScheduledPostById::<T>::take(post_id) {
+                if let Err(error) = Self::publish_scheduled_post(&scheduled_post) {
+                    scheduled_post.deposit.release::<<T as pallet_spaces::Config>::Currency>();
+                    Self::deposit_event(Event::ScheduledPostDropped { post_id, error });
+                }
+            }
+        }
+
+        <T as Config>::WeightInfo::publish_scheduled_posts(post_ids.len() as u32)
+    }
+
+    /// Create a scheduled post in its space, checking again that its author can create it.
+    fn publish_scheduled_post(scheduled_post: &ScheduledPost<T>) -> DispatchResult {
+        let ScheduledPost { id: post_id, created, space_id, content, deposit, .. } =
+            scheduled_post.clone();
+        let author = created.account;
+
+        let mut new_post: Post<T> = Post::new(
+            post_id,
+            author.clone(),
+            Some(space_id),
+            PostExtension::RegularPost,
+            content.clone(),
+        );
+
+        Self::ensure_can_create_post(
+            author.clone(),
+            &new_post,
+            Some(content),
+            SpacePermission::CreatePosts,
+            Error::<T>::NoPermissionToCreatePosts.into(),
+        )?;
+
+        new_post.deposit = deposit;
+
+        PostIdsBySpaceId::<T>::mutate(space_id, |ids| ids.push(post_id));
+        Self::add_post_to_space_counters(&new_post, space_id);
+        PostById::<T>::insert(post_id, new_post);
+
+        Self::deposit_event(Event::PostCreated { account: author, post_id });
+        Ok(())
+    }
+
+    /// Remove a post from the pinned posts of a given space, keeping the order of the others.
+    pub(crate) fn unpin_post_in_space(space_id: SpaceId, post_id: PostId) {
+        if Self::pinned_post_ids_by_space_id(space_id).contains(&post_id) {
+            PinnedPostIdsBySpaceId::<T>::mutate(space_id, |pinned_ids| {
+                pinned_ids.retain(|id| *id != post_id)
+            });
+        }
+    }
+
+    fn ensure_can_pin_posts(account: &T::AccountId, space_id: SpaceId) -> DispatchResult {
+        let space = Spaces::<T>::require_space(space_id)?;
+
+        ensure!(
+            T::IsAccountBlocked::is_allowed_account(account.clone(), space_id),
+            ModerationError::AccountIsBlocked
+        );
+
+        Spaces::ensure_account_has_space_permission(
+            account.clone(),
+            &space,
+            SpacePermission::PinPosts,
+            Error::<T>::NoPermissionToPinPosts.into(),
+        )
+    }
+
     /// This function performs validation checks to determine if an account can create or reply
     /// to a post in a specific space.
     pub(super) fn ensure_can_create_post(
@@ -348,6 +833,7 @@ impl<T: Config> Pallet<T> {
     ) -> DispatchResult {
         let space = &new_post.get_space()?;
         ensure!(!space.hidden, Error::<T>::CannotCreateInHiddenScope);
+        space.ensure_not_archived()?;
 
         ensure!(
             T::IsAccountBlocked::is_allowed_account(account.clone(), space.id),
@@ -365,6 +851,11 @@ impl<T: Config> Pallet<T> {
         let root_post = &mut new_post.get_root_post()?;
         ensure!(!root_post.hidden, Error::<T>::CannotCreateInHiddenScope);
 
+        if new_post.is_comment() {
+            Self::ensure_comments_policy_allows(&account, space)?;
+            Self::ensure_can_access_post(&account, root_post)?;
+        }
+
         Spaces::ensure_account_has_space_permission(
             account.clone(),
             space,
@@ -373,15 +864,77 @@ impl<T: Config> Pallet<T> {
         )
     }
 
+    fn ensure_comments_policy_allows(account: &T::AccountId, space: &Space<T>) -> DispatchResult {
+        match Spaces::<T>::space_setting::<CommentsPolicySetting>(space.id) {
+            CommentsPolicy::Open => (),
+            CommentsPolicy::FollowersOnly => ensure!(
+                space.is_owner(account) || space.is_follower(account),
+                Error::<T>::CommentsAreForFollowersOnly
+            ),
+            CommentsPolicy::Closed => fail!(Error::<T>::CommentsAreClosed),
+        }
+        Ok(())
+    }
+
+    /// Get the account that receives tips for a given post: the owner of the space that authored
+    /// the post, if any, or the owner of the post otherwise.
+    pub(crate) fn tip_recipient(post: &Post<T>) -> T::AccountId {
+        post.author_space_id()
+            .and_then(Spaces::<T>::space_by_id)
+            .map_or_else(|| post.owner.clone(), |space| space.owner)
+    }
+
+    /// Check whether an account meets the access policy of the root post of a given post.
+    /// The owner of the root post and the owners of its space always have access.
+    pub fn can_account_access_post(account: &T::AccountId, post: &Post<T>) -> bool {
+        let root_post = match post.get_root_post() {
+            Ok(root_post) => root_post,
+            Err(_) => return false,
+        };
+
+        let policy = match Self::post_access_policy_by_post_id(root_post.id) {
+            Some(policy) => policy,
+            None => return true,
+        };
+
+        if root_post.is_owner(account) {
+            return true
+        }
+
+        let space = match root_post.try_get_space() {
+            Some(space) => space,
+            None => return false,
+        };
+
+        if space.is_owner(account) {
+            return true
+        }
+
+        match policy {
+            PostAccessPolicy::CreatorBackers { min_stake } =>
+                T::BackerStakesProvider::get_backer_stake(account, space.id) >= min_stake,
+            PostAccessPolicy::RoleHolders { role_id } =>
+                <T as pallet_spaces::Config>::Roles::has_user_role(
+                    User::Account(account.clone()),
+                    space.id,
+                    role_id,
+                ),
+        }
+    }
+
+    pub fn ensure_can_access_post(account: &T::AccountId, post: &Post<T>) -> DispatchResult {
+        ensure!(Self::can_account_access_post(account, post), Error::<T>::NoAccessToPost);
+        Ok(())
+    }
+
     pub fn ensure_can_reply_to_parent(parent_id: PostId) -> DispatchResult {
         let parent_comment =
             Self::post_by_id(parent_id).ok_or(Error::<T>::UnknownParentComment)?;
 
         ensure!(parent_comment.is_comment(), Error::<T>::NotACommentByParentId);
 
-        let ancestors = Self::get_post_ancestors(parent_id);
         ensure!(
-            ancestors.len() < T::MaxCommentDepth::get() as usize,
+            Self::get_comment_depth(parent_id)? < T::MaxCommentDepth::get(),
             Error::<T>::MaxCommentDepthReached,
         );
 
@@ -458,6 +1011,95 @@ impl<T: Config> Pallet<T> {
 
         Ok(())
     }
+
+    /// Get the ids of posts pinned in a given space, in the order they were pinned.
+    pub fn get_pinned_post_ids(space_id: SpaceId) -> Vec<PostId> {
+        Self::pinned_post_ids_by_space_id(space_id).into_inner()
+    }
+}
+
+impl<T: Config> SpaceDeletionHandler for Pallet<T> {
+    /// Remove the posts of a deleted space one by one,
+    /// removing the deepest replies of a post before the post itself.
+    /// The poll votes, reactions and followers of a post are removed before the post.
+    fn cleanup_deleted_space(space_id: SpaceId, limit: u32) -> u32 {
+        // Read and write the posts of the space once, however many of them are removed.
+        let mut post_ids = Self::post_ids_by_space_id(space_id);
+        let mut removed = 0;
+
+        while removed < limit {
+            let root_post_id = match post_ids.last() {
+                Some(post_id) => *post_id,
+                None => break,
+            };
+
+            let mut parent_id_opt = None;
+            let mut post_id = root_post_id;
+            while let Some(reply_id) = Self::reply_ids_by_post_id(post_id).last() {
+                parent_id_opt = Some(post_id);
+                post_id = *reply_id;
+            }
+
+            if let Some(post) = Self::post_by_id(post_id) {
+                let remaining = limit - removed;
+                let cleaned = Self::cleanup_deleted_post(post_id, remaining);
+                removed = removed.saturating_add(cleaned);
+                if cleaned >= remaining {
+                    break;
+                }
+
+                if parent_id_opt.is_none() {
+                    post_ids.pop();
+                    ReplyIdsByPostId::<T>::remove(post_id);
+                    Self::remove_post_keeping_replies(&post);
+                } else {
+                    Self::remove_post(&post);
+                }
+            } else if let Some(parent_id) = parent_id_opt {
+                // A deleted comment, whose replies have been removed already.
+                ReplyIdsByPostId::<T>::mutate(parent_id, |ids| remove_from_vec(ids, post_id));
+                ReplyIdsByPostId::<T>::remove(post_id);
+                PostTombstoneById::<T>::remove(post_id);
+            } else {
+                // A deleted root post, whose replies have been removed already.
+                post_ids.pop();
+                ReplyIdsByPostId::<T>::remove(post_id);
+                PostTombstoneById::<T>::remove(post_id);
+            }
+
+            removed += 1;
+        }
+
+        if post_ids.is_empty() {
+            PostIdsBySpaceId::<T>::remove(space_id);
+            PinnedPostIdsBySpaceId::<T>::remove(space_id);
+        } else {
+            PostIdsBySpaceId::<T>::insert(space_id, post_ids);
+        }
+
+        removed
+    }
+
+    /// An item is either a post or a poll vote, reaction or follower of a post.
+    fn cleanup_deleted_space_weight(limit: u32) -> Weight {
+        let posts_weight = <T as Config>::WeightInfo::cleanup_deleted_space(limit);
+        let post_data_weight = <T as Config>::WeightInfo::cleanup_deleted_post(limit);
+        posts_weight.max(post_data_weight)
+    }
+}
+
+impl<T: Config> SpaceSettingsRegistry for Pallet<T> {
+    fn registered_settings() -> Vec<SpaceSettingInfo> {
+        vec![
+            SpaceSettingInfo::of::<CommentsPolicySetting>(),
+            SpaceSettingInfo::of::<PostRevisionsRetentionSetting>(),
+        ]
+    }
+
+    fn check_setting_value(key: &[u8], value: &[u8]) -> Option<bool> {
+        CommentsPolicySetting::check_encoded_value(key, value)
+            .or_else(|| PostRevisionsRetentionSetting::check_encoded_value(key, value))
+    }
 }
 
 impl<T: Config> PostsProvider<T::AccountId> for Pallet<T> {
@@ -477,10 +1119,14 @@ impl<T: Config> PostsProvider<T::AccountId> for Pallet<T> {
         if post.is_owner(new_owner) {
             return Ok(())
         }
-        
+
+        let mut deposit = post.deposit.clone();
+        deposit.transfer::<<T as pallet_spaces::Config>::Currency>(new_owner.clone())?;
+
         PostById::<T>::mutate(post_id, |stored_post_opt| {
             if let Some(stored_post) = stored_post_opt {
                 stored_post.owner = new_owner.clone();
+                stored_post.deposit = deposit;
             }
         });
         
@@ -493,8 +1139,9 @@ impl<T: Config> PostsProvider<T::AccountId> for Pallet<T> {
         let new_post: Post<T> =
             Post::new(new_post_id, owner.clone(), Some(space_id), PostExtension::RegularPost, content.clone());
 
-        PostById::insert(new_post_id, new_post);
         PostIdsBySpaceId::<T>::mutate(space_id, |ids| ids.push(new_post_id));
+        Self::add_post_to_space_counters(&new_post, space_id);
+        PostById::insert(new_post_id, new_post);
         NextPostId::<T>::mutate(|n| n.saturating_inc());
         
         Ok(new_post_id)
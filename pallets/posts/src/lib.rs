@@ -16,6 +16,40 @@
 //! - Articles on Medium,
 //! - Shared links on Reddit,
 //! - Questions and answers on Stack Overflow.
+//!
+//! A space can restrict who can comment on its posts with the comments policy space setting,
+//! see `CommentsPolicySetting`.
+//!
+//! Accounts with the `PinPosts` permission can pin root posts of a space, up to
+//! `MaxPinnedPostsPerSpace`. A post is unpinned automatically when it is hidden,
+//! moved to another space or removed.
+//!
+//! Creating a post reserves `PostDeposit` from its author. The deposit moves to a new owner
+//! together with the post and is returned when the post is removed.
+//!
+//! When the content of a post is updated, its previous content is appended to the revisions
+//! of the post, up to `MaxPostRevisions`. How many revisions are kept is defined by the
+//! post revisions retention space setting, see `PostRevisionsRetentionSetting`. A space
+//! without this setting keeps the `DEFAULT_POST_REVISIONS_TO_KEEP` latest revisions.
+//!
+//! A post with the `Poll` extension is a poll: its content describes a question and up to
+//! `MaxPollOptions` options, and accounts with the `VoteInPolls` permission can vote for one
+//! of the options with `vote_in_poll` until the voting period of the poll ends. Each account
+//! can vote in a poll only once, and the votes for each option are counted on-chain.
+//!
+//! Any post or comment can be tipped with `tip_post`, which transfers native tokens from
+//! the tipper to the owner of the post. The total amount of tips received by each post is
+//! kept on-chain.
+//!
+//! A regular post can be scheduled with `schedule_post` to be published in a space at a given
+//! block. Its id and deposit are reserved at once, and the post is created at the target block
+//! if its author is still allowed to create it. The author can cancel a scheduled post before
+//! it is published with `cancel_scheduled_post`.
+//!
+//! Posts and comments can be deleted with `delete_post` by accounts that have one of the
+//! `DeleteOwnPosts`, `DeleteOwnComments` or `DeleteAnyPost` permissions. A deleted post leaves
+//! a `PostTombstone` behind, and a deleted comment that has replies stays among the replies of
+//! its parent, so that reply trees stay navigable.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -25,7 +59,7 @@ use frame_support::{
     ensure, fail,
     traits::Get,
 };
-use frame_system::ensure_signed;
+use frame_system::{ensure_signed, pallet_prelude::BlockNumberFor};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
@@ -33,15 +67,22 @@ use sp_runtime::RuntimeDebug;
 use sp_std::prelude::*;
 
 use pallet_permissions::SpacePermission;
-use pallet_spaces::{types::Space, Pallet as Spaces};
+use pallet_spaces::{
+    types::{BalanceOf, Space, StorageDepositOf},
+    Pallet as Spaces,
+};
 use subsocial_support::{
     ensure_content_is_valid, new_who_and_when, remove_from_vec,
-    traits::{IsAccountBlocked, IsContentBlocked, IsPostBlocked},
-    Content, ModerationError, PostId, SpaceId, WhoAndWhen, WhoAndWhenOf,
+    traits::{
+        BackerStakesProvider, IsAccountBlocked, IsContentBlocked, IsPostBlocked,
+        PostDeletionHandler, PostVisibilityHandler, SpaceSetting,
+    },
+    Content, ModerationError, PostId, SpaceId, StorageDeposit, User, WhoAndWhen, WhoAndWhenOf,
 };
 
 pub use pallet::*;
 pub mod functions;
+pub mod migration;
 
 pub mod types;
 pub use types::*;
@@ -50,15 +91,21 @@ pub use types::*;
 mod benchmarking;
 pub mod weights;
 
-// pub mod rpc;
+pub mod rpc;
+
+pub const LOG_TARGET: &str = "runtime::posts";
 
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
 
     use crate::weights::WeightInfo;
-    use frame_support::{pallet_prelude::*, traits::IsType};
+    use frame_support::{
+        pallet_prelude::*,
+        traits::{Currency, ExistenceRequirement, IsType},
+    };
     use frame_system::pallet_prelude::*;
+    use sp_runtime::traits::{Saturating, Zero};
 
     #[pallet::config]
     pub trait Config:
@@ -76,11 +123,44 @@ pub mod pallet {
 
         type IsPostBlocked: IsPostBlocked<PostId>;
 
+        /// Cleans up the data of other pallets related to a deleted post.
+        type PostDeletionHandler: PostDeletionHandler;
+
+        /// Updates the data of other pallets related to a post that was made visible again.
+        type PostVisibilityHandler: PostVisibilityHandler;
+
+        /// The amount reserved from an owner for storing a post.
+        #[pallet::constant]
+        type PostDeposit: Get<BalanceOf<Self>>;
+
+        /// The maximum number of posts that can be pinned in a space.
+        #[pallet::constant]
+        type MaxPinnedPostsPerSpace: Get<u32>;
+
+        /// The maximum number of previous revisions that can be kept for a post.
+        #[pallet::constant]
+        type MaxPostRevisions: Get<u32>;
+
+        /// The maximum number of posts that can be scheduled to be published at the same block.
+        #[pallet::constant]
+        type MaxScheduledPostsPerBlock: Get<u32>;
+
+        /// The maximum number of options in a poll.
+        #[pallet::constant]
+        type MaxPollOptions: Get<u32>;
+
+        /// Provides the stakes of backers on creators, to check access to token-gated posts.
+        type BackerStakesProvider: BackerStakesProvider<Self::AccountId, BalanceOf<Self>>;
+
         type WeightInfo: WeightInfo;
     }
 
+    /// The current storage version
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+
     #[pallet::pallet]
     #[pallet::without_storage_info]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::type_value]
@@ -116,6 +196,96 @@ pub mod pallet {
     pub type SharedPostIdsByOriginalPostId<T: Config> =
         StorageMap<_, Twox64Concat, PostId, Vec<PostId>, ValueQuery>;
 
+    /// Get the ids of posts pinned in a given space, in the order they were pinned.
+    #[pallet::storage]
+    #[pallet::getter(fn pinned_post_ids_by_space_id)]
+    pub type PinnedPostIdsBySpaceId<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        SpaceId,
+        BoundedVec<PostId, T::MaxPinnedPostsPerSpace>,
+        ValueQuery,
+    >;
+
+    /// Get the previous revisions of a post's content by the post's id, from the oldest one.
+    #[pallet::storage]
+    #[pallet::getter(fn post_revisions_by_post_id)]
+    pub type PostRevisionsByPostId<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        PostId,
+        BoundedVec<PostRevision<T>, T::MaxPostRevisions>,
+        ValueQuery,
+    >;
+
+    /// Get who replaced the content of an edited post with its current content and when,
+    /// by the post's id.
+    #[pallet::storage]
+    #[pallet::getter(fn last_edit_by_post_id)]
+    pub type LastEditByPostId<T: Config> = StorageMap<_, Twox64Concat, PostId, WhoAndWhenOf<T>>;
+
+    /// Get what is left of a post deleted with `delete_post`, by the post's id.
+    #[pallet::storage]
+    #[pallet::getter(fn post_tombstone_by_id)]
+    pub type PostTombstoneById<T: Config> = StorageMap<_, Twox64Concat, PostId, PostTombstone<T>>;
+
+    /// Get the closing block and the votes of a poll by the poll post's id.
+    #[pallet::storage]
+    #[pallet::getter(fn poll_by_post_id)]
+    pub type PollByPostId<T: Config> = StorageMap<_, Twox64Concat, PostId, Poll<T>>;
+
+    /// Get the option an account voted for in a given poll.
+    #[pallet::storage]
+    #[pallet::getter(fn poll_vote_by_account)]
+    pub type PollVoteByAccount<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, PostId, Blake2_128Concat, T::AccountId, u8>;
+
+    /// Get the total amount of tips received by a post, by the post's id.
+    #[pallet::storage]
+    #[pallet::getter(fn tips_total_by_post_id)]
+    pub type TipsTotalByPostId<T: Config> =
+        StorageMap<_, Twox64Concat, PostId, BalanceOf<T>, ValueQuery>;
+
+    /// Get the access policy of a token-gated root post by the post's id.
+    #[pallet::storage]
+    #[pallet::getter(fn post_access_policy_by_post_id)]
+    pub type PostAccessPolicyByPostId<T: Config> =
+        StorageMap<_, Twox64Concat, PostId, PostAccessPolicyOf<T>>;
+
+    /// Get the details of a post scheduled to be published, by the post's id.
+    #[pallet::storage]
+    #[pallet::getter(fn scheduled_post_by_id)]
+    pub type ScheduledPostById<T: Config> = StorageMap<_, Twox64Concat, PostId, ScheduledPost<T>>;
+
+    /// Get the ids of posts scheduled to be published at a given block.
+    #[pallet::storage]
+    #[pallet::getter(fn scheduled_post_ids_by_block)]
+    pub type ScheduledPostIdsByBlock<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<PostId, T::MaxScheduledPostsPerBlock>,
+        ValueQuery,
+    >;
+
+    /// The ids of deleted posts whose votes, reactions and followers are not removed yet,
+    /// by their position in the queue of deleted posts.
+    #[pallet::storage]
+    pub type PostIdsPendingCleanup<T: Config> = StorageMap<_, Twox64Concat, u64, PostId>;
+
+    /// The position of the first post in the queue of deleted posts.
+    #[pallet::storage]
+    pub type PostCleanupQueueHead<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// The position of the next post added to the queue of deleted posts.
+    #[pallet::storage]
+    pub type PostCleanupQueueTail<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Where to continue removing the poll votes of a deleted post, if they were not all
+    /// removed at once.
+    #[pallet::storage]
+    pub type PollVotesCleanupCursor<T: Config> = StorageMap<_, Twox64Concat, PostId, Vec<u8>>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -133,6 +303,49 @@ pub mod pallet {
             from_space: Option<SpaceId>,
             to_space: Option<SpaceId>,
         },
+        PostPinned {
+            account: T::AccountId,
+            space_id: SpaceId,
+            post_id: PostId,
+        },
+        PostUnpinned {
+            account: T::AccountId,
+            space_id: SpaceId,
+            post_id: PostId,
+        },
+        PostDeleted {
+            account: T::AccountId,
+            post_id: PostId,
+        },
+        PostScheduled {
+            account: T::AccountId,
+            post_id: PostId,
+            publish_at: BlockNumberFor<T>,
+        },
+        ScheduledPostCanceled {
+            account: T::AccountId,
+            post_id: PostId,
+        },
+        /// A scheduled post was not published, because it could not be created at its block.
+        ScheduledPostDropped {
+            post_id: PostId,
+            error: DispatchError,
+        },
+        PollVoted {
+            account: T::AccountId,
+            post_id: PostId,
+            option_index: u8,
+        },
+        PostTipped {
+            account: T::AccountId,
+            post_id: PostId,
+            recipient: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        PostAccessPolicyUpdated {
+            account: T::AccountId,
+            post_id: PostId,
+        },
     }
 
     #[pallet::error]
@@ -188,10 +401,86 @@ pub mod pallet {
         NoPermissionToUpdateOwnPosts,
         /// A comment owner is not allowed to update their own comments in this space.
         NoPermissionToUpdateOwnComments,
+        /// User has no permission to delete any posts in this space.
+        NoPermissionToDeleteAnyPost,
+        /// A post owner is not allowed to delete their own posts in this space.
+        NoPermissionToDeleteOwnPosts,
+        /// A comment owner is not allowed to delete their own comments in this space.
+        NoPermissionToDeleteOwnComments,
 
         /// `force_create_post` failed, because this post already exists.
         /// Consider removing the post with `force_remove_post` first.
         PostAlreadyExists,
+
+        // Comments policy related errors:
+        /// Comments are closed in this space.
+        CommentsAreClosed,
+        /// Only followers of this space can comment.
+        CommentsAreForFollowersOnly,
+
+        // Pinned posts related errors:
+        /// User has no permission to pin or unpin posts in this space.
+        NoPermissionToPinPosts,
+        /// Only a visible root post of a given space can be pinned in it.
+        CannotPinPost,
+        /// This post is already pinned in this space.
+        PostIsAlreadyPinned,
+        /// This post is not pinned in this space.
+        PostIsNotPinned,
+        /// Cannot pin more posts in this space.
+        TooManyPinnedPosts,
+
+        // Scheduled posts related errors:
+        /// Scheduled post was not found by id.
+        ScheduledPostNotFound,
+        /// A post can be scheduled only to a future block.
+        PublishBlockIsNotInFuture,
+        /// Cannot schedule more posts to be published at this block.
+        TooManyPostsScheduledAtBlock,
+        /// Only the author of a scheduled post can cancel it.
+        NotAScheduledPostAuthor,
+
+        // Poll related errors:
+        /// This post's extension is not a `Poll`.
+        NotAPoll,
+        /// A poll should have from 2 up to `MaxPollOptions` options.
+        InvalidPollOptionsCount,
+        /// A poll should accept votes for at least one block.
+        PollVotingPeriodIsZero,
+        /// The voting period of this poll has ended.
+        PollIsClosed,
+        /// This poll has no option with such index.
+        InvalidPollOption,
+        /// An account has already voted in this poll.
+        AlreadyVotedInPoll,
+        /// User has no permission to vote in polls in this space.
+        NoPermissionToVoteInPolls,
+
+        // Tips related errors:
+        /// Tip amount should be greater than zero.
+        TipAmountIsZero,
+        /// An account cannot tip its own post.
+        CannotTipOwnPost,
+
+        // Access policy related errors:
+        /// Only a root post in a space can have an access policy.
+        CannotRestrictAccessToPost,
+        /// Minimum stake of a creator backers policy should be greater than zero.
+        AccessPolicyMinStakeIsZero,
+        /// An account does not meet the access policy of this post.
+        NoAccessToPost,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            migration::v2::migrate_next_posts::<T>()
+                .saturating_add(Self::publish_scheduled_posts(now))
+        }
+
+        fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::cleanup_deleted_posts(remaining_weight)
+        }
     }
 
     #[pallet::call]
@@ -202,6 +491,7 @@ pub mod pallet {
                 PostExtension::RegularPost => <T as Config>::WeightInfo::create_post__regular(),
                 PostExtension::Comment(..) => <T as Config>::WeightInfo::create_post__comment(),
                 PostExtension::SharedPost(..) => <T as Config>::WeightInfo::create_post__shared(),
+                PostExtension::Poll(..) => <T as Config>::WeightInfo::create_post__poll(),
             }
         )]
         pub fn create_post(
@@ -212,47 +502,8 @@ pub mod pallet {
         ) -> DispatchResult {
             let creator = ensure_signed(origin)?;
 
-            let new_post_id = Self::next_post_id();
-            let new_post: Post<T> =
-                Post::new(new_post_id, creator.clone(), space_id_opt, extension, content.clone());
-
-            // Get space from either space_id_opt or Comment if a comment provided
-            let space = &new_post.get_space()?;
-            let root_post = &mut new_post.get_root_post()?;
-
-            // Check whether account has permission to create Post (by extension)
-            let mut permission_to_check = SpacePermission::CreatePosts;
-            let mut error_on_permission_failed = Error::<T>::NoPermissionToCreatePosts;
-
-            if let PostExtension::Comment(_) = extension {
-                permission_to_check = SpacePermission::CreateComments;
-                error_on_permission_failed = Error::<T>::NoPermissionToCreateComments;
-            }
-
-            Self::ensure_can_create_post(
-                creator.clone(),
-                &new_post,
-                Some(content.clone()),
-                permission_to_check,
-                error_on_permission_failed.into(),
-            )?;
-
-            match extension {
-                PostExtension::SharedPost(original_post_id) =>
-                    Self::create_shared_post(&creator, new_post_id, original_post_id)?,
-                PostExtension::Comment(comment_ext) =>
-                    Self::create_comment(new_post_id, comment_ext, root_post.id)?,
-                _ => (),
-            }
-
-            if new_post.is_root_post() {
-                PostIdsBySpaceId::<T>::mutate(space.id, |ids| ids.push(new_post_id));
-            }
-
-            PostById::insert(new_post_id, new_post);
-            NextPostId::<T>::mutate(|n| {
-                *n += 1;
-            });
+            let new_post_id =
+                Self::do_create_post(creator.clone(), space_id_opt, extension, content, None)?;
 
             Self::deposit_event(Event::PostCreated { account: creator, post_id: new_post_id });
             Ok(())
@@ -275,7 +526,10 @@ pub mod pallet {
             let space_opt = &post.try_get_space();
 
             if let Some(space) = space_opt {
+                space.ensure_not_archived()?;
                 Self::ensure_account_can_update_post(&editor, &post, space)?;
+            } else {
+                post.ensure_owner(&editor)?;
             }
 
             let mut is_update_applied = false;
@@ -291,15 +545,17 @@ pub mod pallet {
                         );
                     }
 
+                    Self::add_post_revision(&post, space_opt.as_ref());
                     post.content = content;
                     post.edited = true;
+                    LastEditByPostId::<T>::insert(post.id, new_who_and_when::<T>(editor.clone()));
                     is_update_applied = true;
                 }
             }
 
             if let Some(hidden) = update.hidden {
                 if hidden != post.hidden {
-                    post.hidden = hidden;
+                    Self::set_post_hidden(&mut post, hidden);
                     is_update_applied = true;
                 }
             }
@@ -326,6 +582,7 @@ pub mod pallet {
             ensure!(new_space_id != post.space_id, Error::<T>::CannotMoveToSameSpace);
 
             if let Some(space) = post.try_get_space() {
+                space.ensure_not_archived()?;
                 Self::ensure_account_can_update_post(&who, post, &space)?;
             } else {
                 post.ensure_owner(&who)?;
@@ -371,6 +628,7 @@ pub mod pallet {
             ensure!(Self::require_post(post_id).is_err(), Error::<T>::PostAlreadyExists);
 
             let WhoAndWhen { account, time, .. } = created;
+            let author = User::Account(account.clone());
             let new_who_and_when =
                 WhoAndWhen { account, block: frame_system::Pallet::<T>::block_number(), time };
 
@@ -379,17 +637,22 @@ pub mod pallet {
                 created: new_who_and_when,
                 edited: false,
                 owner: owner.clone(),
+                author,
                 extension,
                 space_id: space_id_opt,
                 content,
                 hidden,
                 upvotes_count,
                 downvotes_count,
+                replies_count: Self::reply_ids_by_post_id(post_id).len() as u32,
+                shares_count: Self::shared_post_ids_by_original_post_id(post_id).len() as u32,
+                deposit: StorageDeposit::free(owner.clone()),
             };
 
             if new_post.is_root_post() {
                 if let Some(space_id) = new_post.space_id {
                     PostIdsBySpaceId::<T>::mutate(space_id, |ids| ids.push(post_id));
+                    Self::add_post_to_space_counters(&new_post, space_id);
                 }
             }
 
@@ -399,11 +662,13 @@ pub mod pallet {
                     ReplyIdsByPostId::<T>::mutate(commented_post_id, |reply_ids| {
                         reply_ids.push(post_id)
                     });
+                    let _ = Self::mutate_post_by_id(commented_post_id, |post| post.inc_replies());
                 },
                 PostExtension::SharedPost(original_post_id) => {
                     SharedPostIdsByOriginalPostId::<T>::mutate(original_post_id, |ids| {
                         ids.push(post_id)
                     });
+                    let _ = Self::mutate_post_by_id(original_post_id, |post| post.inc_shares());
                 },
                 _ => (),
             }
@@ -416,7 +681,7 @@ pub mod pallet {
 
         #[pallet::call_index(4)]
         #[pallet::weight((
-            Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(2, 3),
+            Weight::from_parts(10_000, 0) + T::DbWeight::get().reads_writes(3, 4),
             DispatchClass::Operational,
             Pays::Yes,
         ))]
@@ -432,6 +697,8 @@ pub mod pallet {
                         PostIdsBySpaceId::<T>::mutate(space_id, |ids| {
                             remove_from_vec(ids, post_id)
                         });
+                        Self::unpin_post_in_space(space_id, post_id);
+                        Self::remove_post_from_space_counters(&old_post, space_id);
                     }
                 }
 
@@ -441,15 +708,20 @@ pub mod pallet {
                         ReplyIdsByPostId::<T>::mutate(commented_post_id, |reply_ids| {
                             remove_from_vec(reply_ids, post_id)
                         });
+                        let _ =
+                            Self::mutate_post_by_id(commented_post_id, |post| post.dec_replies());
                     },
                     PostExtension::SharedPost(original_post_id) => {
                         SharedPostIdsByOriginalPostId::<T>::mutate(original_post_id, |ids| {
                             remove_from_vec(ids, post_id)
                         });
+                        let _ = Self::mutate_post_by_id(original_post_id, |post| post.dec_shares());
                     },
                     _ => (),
                 }
+                old_post.deposit.release::<<T as pallet_spaces::Config>::Currency>();
                 PostById::<T>::remove(post_id);
+                Self::enqueue_post_cleanup(post_id);
             }
 
             Ok(Pays::No.into())
@@ -469,5 +741,314 @@ pub mod pallet {
             NextPostId::<T>::put(post_id);
             Ok(Pays::No.into())
         }
+
+        /// Pin a visible root post of a given space at the end of its pinned posts.
+        /// Requires the `PinPosts` permission in the space.
+        #[pallet::call_index(6)]
+        #[pallet::weight(<T as Config>::WeightInfo::pin_post())]
+        pub fn pin_post(origin: OriginFor<T>, space_id: SpaceId, post_id: PostId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let post = Self::require_post(post_id)?;
+            ensure!(
+                post.is_root_post() && post.space_id == Some(space_id) && !post.hidden,
+                Error::<T>::CannotPinPost
+            );
+
+            Self::ensure_can_pin_posts(&who, space_id)?;
+
+            PinnedPostIdsBySpaceId::<T>::try_mutate(space_id, |pinned_ids| {
+                ensure!(!pinned_ids.contains(&post_id), Error::<T>::PostIsAlreadyPinned);
+                pinned_ids.try_push(post_id).map_err(|_| Error::<T>::TooManyPinnedPosts)
+            })?;
+
+            Self::deposit_event(Event::PostPinned { account: who, space_id, post_id });
+            Ok(())
+        }
+
+        /// Unpin a post in a given space. Requires the `PinPosts` permission in the space.
+        #[pallet::call_index(7)]
+        #[pallet::weight(<T as Config>::WeightInfo::unpin_post())]
+        pub fn unpin_post(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            post_id: PostId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                Self::pinned_post_ids_by_space_id(space_id).contains(&post_id),
+                Error::<T>::PostIsNotPinned
+            );
+
+            Self::ensure_can_pin_posts(&who, space_id)?;
+
+            Self::unpin_post_in_space(space_id, post_id);
+
+            Self::deposit_event(Event::PostUnpinned { account: who, space_id, post_id });
+            Ok(())
+        }
+
+        /// Delete a post or a comment, leaving a tombstone in its place.
+        /// The replies of a deleted post are not deleted. Poll votes, reactions and followers
+        /// of the post are removed later, in blocks with spare weight.
+        /// Posts of an archived space cannot be deleted.
+        #[pallet::call_index(8)]
+        #[pallet::weight(<T as Config>::WeightInfo::delete_post())]
+        pub fn delete_post(origin: OriginFor<T>, post_id: PostId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let post = Self::require_post(post_id)?;
+
+            if let Some(space) = post.try_get_space() {
+                space.ensure_not_archived()?;
+                Self::ensure_account_can_delete_post(&who, &post, &space)?;
+            } else {
+                post.ensure_owner(&who)?;
+            }
+
+            if Self::reply_ids_by_post_id(post_id).is_empty() {
+                Self::remove_post(&post);
+            } else {
+                Self::remove_post_keeping_replies(&post);
+            }
+            PostTombstoneById::<T>::insert(post_id, PostTombstone::new(&post, who.clone()));
+            Self::enqueue_post_cleanup(post_id);
+
+            Self::deposit_event(Event::PostDeleted { account: who, post_id });
+            Ok(())
+        }
+
+        /// Schedule a regular post to be published in a given space at a given future block.
+        /// The post id and the post deposit are reserved at once.
+        #[pallet::call_index(9)]
+        #[pallet::weight(<T as Config>::WeightInfo::schedule_post())]
+        pub fn schedule_post(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            content: Content,
+            publish_at: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let creator = ensure_signed(origin)?;
+
+            ensure!(
+                publish_at > frame_system::Pallet::<T>::block_number(),
+                Error::<T>::PublishBlockIsNotInFuture
+            );
+
+            Self::can_create_regular_post(creator.clone(), space_id, Some(content.clone()))?;
+
+            let post_id = Self::next_post_id();
+            ScheduledPostIdsByBlock::<T>::try_mutate(publish_at, |post_ids| {
+                post_ids.try_push(post_id).map_err(|_| Error::<T>::TooManyPostsScheduledAtBlock)
+            })?;
+
+            let deposit = StorageDeposit::reserve::<<T as pallet_spaces::Config>::Currency>(
+                creator.clone(),
+                T::PostDeposit::get(),
+            )?;
+
+            let scheduled_post = ScheduledPost::<T> {
+                id: post_id,
+                created: new_who_and_when::<T>(creator.clone()),
+                space_id,
+                content,
+                publish_at,
+                deposit,
+            };
+
+            ScheduledPostById::<T>::insert(post_id, scheduled_post);
+            NextPostId::<T>::mutate(|n| {
+                *n += 1;
+            });
+
+            Self::deposit_event(Event::PostScheduled { account: creator, post_id, publish_at });
+            Ok(())
+        }
+
+        /// Cancel a post that has not been published yet and return its deposit.
+        /// Only the author of the scheduled post can cancel it.
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as Config>::WeightInfo::cancel_scheduled_post())]
+        pub fn cancel_scheduled_post(origin: OriginFor<T>, post_id: PostId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let scheduled_post =
+                Self::scheduled_post_by_id(post_id).ok_or(Error::<T>::ScheduledPostNotFound)?;
+            ensure!(scheduled_post.created.account == who, Error::<T>::NotAScheduledPostAuthor);
+
+            ScheduledPostIdsByBlock::<T>::mutate(scheduled_post.publish_at, |post_ids| {
+                post_ids.retain(|id| *id != post_id)
+            });
+            scheduled_post.deposit.release::<<T as pallet_spaces::Config>::Currency>();
+            ScheduledPostById::<T>::remove(post_id);
+
+            Self::deposit_event(Event::ScheduledPostCanceled { account: who, post_id });
+            Ok(())
+        }
+
+        /// Vote for an option of a poll, by the index of the option.
+        /// An account can vote in a poll only once, before the poll is closed.
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as Config>::WeightInfo::vote_in_poll())]
+        pub fn vote_in_poll(
+            origin: OriginFor<T>,
+            post_id: PostId,
+            option_index: u8,
+        ) -> DispatchResult {
+            let voter = ensure_signed(origin)?;
+
+            let post = Self::require_post(post_id)?;
+            let mut poll = Self::poll_by_post_id(post_id).ok_or(Error::<T>::NotAPoll)?;
+
+            ensure!(
+                frame_system::Pallet::<T>::block_number() < poll.closes_at,
+                Error::<T>::PollIsClosed
+            );
+            ensure!(
+                !PollVoteByAccount::<T>::contains_key(post_id, &voter),
+                Error::<T>::AlreadyVotedInPoll
+            );
+
+            let space = post.get_space()?;
+            space.ensure_not_archived()?;
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(voter.clone(), space.id),
+                ModerationError::AccountIsBlocked
+            );
+            Spaces::ensure_account_has_space_permission(
+                voter.clone(),
+                &space,
+                SpacePermission::VoteInPolls,
+                Error::<T>::NoPermissionToVoteInPolls.into(),
+            )?;
+
+            let tally = poll
+                .tallies
+                .get_mut(option_index as usize)
+                .ok_or(Error::<T>::InvalidPollOption)?;
+            *tally = tally.saturating_add(1);
+
+            PollByPostId::<T>::insert(post_id, poll);
+            PollVoteByAccount::<T>::insert(post_id, &voter, option_index);
+
+            Self::deposit_event(Event::PollVoted { account: voter, post_id, option_index });
+            Ok(())
+        }
+
+        /// Tip the author of a post or a comment with a given amount of native tokens.
+        /// Tips for a post authored by a space go to the owner of that space.
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as Config>::WeightInfo::tip_post())]
+        pub fn tip_post(
+            origin: OriginFor<T>,
+            post_id: PostId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let tipper = ensure_signed(origin)?;
+
+            ensure!(!amount.is_zero(), Error::<T>::TipAmountIsZero);
+
+            let post = Self::require_post(post_id)?;
+            let recipient = Self::tip_recipient(&post);
+            ensure!(tipper != recipient, Error::<T>::CannotTipOwnPost);
+
+            if let Some(space_id) = post.try_get_space_id() {
+                ensure!(
+                    T::IsAccountBlocked::is_allowed_account(tipper.clone(), space_id),
+                    ModerationError::AccountIsBlocked
+                );
+                ensure!(
+                    T::IsAccountBlocked::is_allowed_account(recipient.clone(), space_id),
+                    ModerationError::AccountIsBlocked
+                );
+                ensure!(
+                    T::IsPostBlocked::is_allowed_post(post_id, space_id),
+                    ModerationError::PostIsBlocked
+                );
+            }
+
+            <T as pallet_spaces::Config>::Currency::transfer(
+                &tipper,
+                &recipient,
+                amount,
+                ExistenceRequirement::KeepAlive,
+            )?;
+
+            TipsTotalByPostId::<T>::mutate(post_id, |total| *total = total.saturating_add(amount));
+
+            Self::deposit_event(Event::PostTipped { account: tipper, post_id, recipient, amount });
+            Ok(())
+        }
+
+        /// Set or remove (with `None`) the access policy of a root post, which restricts who can
+        /// comment on the post and react to it. Requires the same permissions as updating the post.
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_post_access_policy())]
+        pub fn set_post_access_policy(
+            origin: OriginFor<T>,
+            post_id: PostId,
+            policy_opt: Option<PostAccessPolicyOf<T>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let post = Self::require_post(post_id)?;
+            ensure!(
+                post.is_root_post() && post.space_id.is_some(),
+                Error::<T>::CannotRestrictAccessToPost
+            );
+
+            let space = post.get_space()?;
+            space.ensure_not_archived()?;
+            Self::ensure_account_can_update_post(&who, &post, &space)?;
+
+            match policy_opt {
+                Some(policy) => {
+                    if let PostAccessPolicy::CreatorBackers { min_stake } = &policy {
+                        ensure!(!min_stake.is_zero(), Error::<T>::AccessPolicyMinStakeIsZero);
+                    }
+                    PostAccessPolicyByPostId::<T>::insert(post_id, policy);
+                },
+                None => PostAccessPolicyByPostId::<T>::remove(post_id),
+            }
+
+            Self::deposit_event(Event::PostAccessPolicyUpdated { account: who, post_id });
+            Ok(())
+        }
+
+        /// Create a post or a comment on behalf of a space, which is recorded as its author.
+        /// The signer becomes the owner of the post and needs the `RepresentSpaceInternally`
+        /// permission in the author space to post in it, or `RepresentSpaceExternally` to post
+        /// in another space.
+        #[pallet::call_index(14)]
+        #[pallet::weight(
+            match extension {
+                PostExtension::RegularPost => <T as Config>::WeightInfo::create_post__regular(),
+                PostExtension::Comment(..) => <T as Config>::WeightInfo::create_post__comment(),
+                PostExtension::SharedPost(..) => <T as Config>::WeightInfo::create_post__shared(),
+                PostExtension::Poll(..) => <T as Config>::WeightInfo::create_post__poll(),
+            }
+            .saturating_add(T::DbWeight::get().reads(2))
+        )]
+        pub fn create_post_as_space(
+            origin: OriginFor<T>,
+            author_space_id: SpaceId,
+            space_id_opt: Option<SpaceId>,
+            extension: PostExtension,
+            content: Content,
+        ) -> DispatchResult {
+            let creator = ensure_signed(origin)?;
+
+            let new_post_id = Self::do_create_post(
+                creator.clone(),
+                space_id_opt,
+                extension,
+                content,
+                Some(author_space_id),
+            )?;
+
+            Self::deposit_event(Event::PostCreated { account: creator, post_id: new_post_id });
+            Ok(())
+        }
     }
 }
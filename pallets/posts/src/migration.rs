@@ -0,0 +1,186 @@
+// Copyright (C) DAPPFORCE PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
+//
+// Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
+// Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
+
+use frame_support::{log, traits::OnRuntimeUpgrade};
+use sp_runtime::Saturating;
+use sp_std::vec::Vec;
+
+use super::*;
+
+pub mod v2 {
+    use frame_support::{pallet_prelude::*, storage::StoragePrefixedMap, weights::Weight};
+    use subsocial_support::StorageDeposit;
+
+    use super::*;
+
+    /// The maximum number of posts migrated in a single block.
+    pub const POSTS_PER_BLOCK: u64 = 200;
+
+    // Old post, before the replies and shares counters, authors and storage deposits
+    // were introduced
+    #[derive(Encode, Decode)]
+    pub struct OldPost<T: Config> {
+        pub id: PostId,
+        pub created: WhoAndWhenOf<T>,
+        pub edited: bool,
+        pub owner: T::AccountId,
+        pub extension: PostExtension,
+        pub space_id: Option<SpaceId>,
+        pub content: Content,
+        pub hidden: bool,
+        pub upvotes_count: u32,
+        pub downvotes_count: u32,
+    }
+
+    #[frame_support::storage_alias]
+    pub type OldPostById<T: Config> = StorageMap<Pallet<T>, Twox64Concat, PostId, OldPost<T>>;
+
+    /// The raw key of the last migrated post, or the prefix of the posts map if no post
+    /// has been migrated yet. It exists only while the migration is in progress.
+    #[frame_support::storage_alias]
+    type MigrationCursor<T: Config> = StorageValue<Pallet<T>, Vec<u8>>;
+
+    impl<T: Config> OldPost<T> {
+        fn migrate_to_v2(self) -> Post<T> {
+            // Posts created before space authors were introduced are authored by their creators.
+            let author = User::Account(self.created.account.clone());
+            Post {
+                id: self.id,
+                created: self.created,
+                edited: self.edited,
+                owner: self.owner.clone(),
+                author,
+                extension: self.extension,
+                space_id: self.space_id,
+                content: self.content,
+                hidden: self.hidden,
+                upvotes_count: self.upvotes_count,
+                downvotes_count: self.downvotes_count,
+                replies_count: Pallet::<T>::reply_ids_by_post_id(self.id).len() as u32,
+                shares_count: Pallet::<T>::shared_post_ids_by_original_post_id(self.id).len()
+                    as u32,
+                // Existing posts are grandfathered and keep being stored for free.
+                deposit: StorageDeposit::free(self.owner),
+            }
+        }
+    }
+
+    /// Whether posts are being migrated. Calls that use posts should not be dispatched
+    /// until the migration is over.
+    pub fn is_migration_in_progress<T: Config>() -> bool {
+        MigrationCursor::<T>::exists()
+    }
+
+    /// Migrate up to `POSTS_PER_BLOCK` posts, if the migration is in progress, counting
+    /// root posts in their spaces, and put the new storage version once every post is migrated.
+    ///
+    /// Posts are not migrated until spaces are, since the counters of spaces are updated.
+    pub(crate) fn migrate_next_posts<T: Config>() -> Weight {
+        let cursor = match MigrationCursor::<T>::get() {
+            Some(cursor) => cursor,
+            None => return T::DbWeight::get().reads(1),
+        };
+        if pallet_spaces::migration::v5::is_migration_in_progress::<T>() {
+            return T::DbWeight::get().reads(2)
+        }
+
+        let mut old_posts = OldPostById::<T>::iter_from(cursor);
+        let mut migrated = 0u64;
+        let mut root_posts_counted = 0u64;
+        let mut is_finished = true;
+
+        for (post_id, old_post) in old_posts.by_ref() {
+            let post = old_post.migrate_to_v2();
+            if let (true, Some(space_id)) = (post.is_root_post(), post.space_id) {
+                root_posts_counted.saturating_inc();
+                let _ = Spaces::<T>::mutate_space_by_id(space_id, |space| {
+                    space.posts_count.saturating_inc();
+                    if post.hidden {
+                        space.hidden_posts_count.saturating_inc();
+                    }
+                });
+            }
+            PostById::<T>::insert(post_id, post);
+            migrated.saturating_inc();
+
+            if migrated >= POSTS_PER_BLOCK {
+                is_finished = false;
+                break;
+            }
+        }
+
+        if is_finished {
+            MigrationCursor::<T>::kill();
+            let current_version = Pallet::<T>::current_storage_version();
+            current_version.put::<Pallet<T>>();
+            log::info!(
+                target: LOG_TARGET,
+                "Upgraded the last {} posts, storage to version {:?}",
+                migrated,
+                current_version
+            );
+        } else {
+            MigrationCursor::<T>::put(old_posts.last_raw_key().to_vec());
+        }
+
+        // Each post also reads its replies and shares, and a root post updates its space.
+        T::DbWeight::get()
+            .reads_writes(migrated * 3 + root_posts_counted + 2, migrated + root_posts_counted + 1)
+    }
+
+    /// Starts a migration of posts from the storage version 0 to 2, which translates
+    /// up to `POSTS_PER_BLOCK` posts in `on_initialize` of every block, adding the replies
+    /// and shares counters, authors and storage deposits to posts, and counts the posts
+    /// of spaces from the translated posts.
+    ///
+    /// Posts are migrated only after `pallet_spaces::migration::v5::MigrateToV5` is over.
+    pub struct MigrateToV2<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let current_version = Pallet::<T>::current_storage_version();
+            let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+            log::info!(
+                target: LOG_TARGET,
+                "Running migration with current storage version {:?} / onchain {:?}",
+                current_version,
+                onchain_version
+            );
+
+            if onchain_version == 0 && current_version == 2 && !is_migration_in_progress::<T>() {
+                MigrationCursor::<T>::put(PostById::<T>::final_prefix().to_vec());
+
+                log::info!(
+                    target: LOG_TARGET,
+                    "Started migrating posts to storage version {:?}",
+                    current_version
+                );
+                T::DbWeight::get().reads_writes(2, 1)
+            } else {
+                log::info!(
+                    target: LOG_TARGET,
+                    "Migration did not execute. This probably should be removed"
+                );
+                T::DbWeight::get().reads(2)
+            }
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+            let current_version = Pallet::<T>::current_storage_version();
+            let onchain_version = Pallet::<T>::on_chain_storage_version();
+            ensure!(onchain_version == 0 && current_version == 2, "migration from version 0 to 2.");
+            Ok(Vec::new())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), DispatchError> {
+            ensure!(is_migration_in_progress::<T>(), "posts should be migrated in next blocks");
+            Ok(())
+        }
+    }
+}
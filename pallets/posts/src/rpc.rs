@@ -0,0 +1,246 @@
+// Copyright (C) DAPPFORCE PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
+//
+// Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
+// Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
+
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::{traits::Zero, SaturatedConversion};
+
+use super::*;
+
+/// A previous revision of a post's content as it is returned by the posts RPC.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FlatPostRevision<AccountId, BlockNumber> {
+    pub content: Content,
+
+    pub edited_by_account: AccountId,
+    pub edited_at_block: BlockNumber,
+    pub edited_at_time: u64,
+}
+
+pub type FlatPostRevisionOf<T> =
+    FlatPostRevision<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+impl<T: Config> From<PostRevision<T>> for FlatPostRevisionOf<T> {
+    fn from(from: PostRevision<T>) -> Self {
+        let PostRevision { content, edited } = from;
+
+        Self {
+            content,
+            edited_by_account: edited.account,
+            edited_at_block: edited.block,
+            edited_at_time: edited.time.saturated_into::<u64>(),
+        }
+    }
+}
+
+/// A post as it is returned by the posts RPC.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FlatPost<AccountId, BlockNumber> {
+    pub id: PostId,
+
+    pub created_by_account: AccountId,
+    pub created_at_block: BlockNumber,
+    pub created_at_time: u64,
+    pub edited: bool,
+
+    pub owner: AccountId,
+    /// A space on behalf of which the post was created, if any.
+    pub author_space_id: Option<SpaceId>,
+
+    pub extension: PostExtension,
+    pub space_id: Option<SpaceId>,
+    pub content: Content,
+    pub hidden: bool,
+
+    pub upvotes_count: u32,
+    pub downvotes_count: u32,
+    pub replies_count: u32,
+    pub shares_count: u32,
+}
+
+pub type FlatPostOf<T> = FlatPost<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+impl<T: Config> From<Post<T>> for FlatPostOf<T> {
+    fn from(from: Post<T>) -> Self {
+        let Post {
+            id,
+            created,
+            edited,
+            owner,
+            author,
+            extension,
+            space_id,
+            content,
+            hidden,
+            upvotes_count,
+            downvotes_count,
+            replies_count,
+            shares_count,
+            ..
+        } = from;
+
+        Self {
+            id,
+            created_by_account: created.account,
+            created_at_block: created.block,
+            created_at_time: created.time.saturated_into::<u64>(),
+            edited,
+            owner,
+            author_space_id: author.maybe_space(),
+            extension,
+            space_id,
+            content,
+            hidden,
+            upvotes_count,
+            downvotes_count,
+            replies_count,
+            shares_count,
+        }
+    }
+}
+
+/// Which posts of a space are returned by the posts RPC.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub enum PostsFilter {
+    All,
+    /// Only posts that are not hidden.
+    Public,
+    /// Only hidden posts.
+    Hidden,
+}
+
+impl PostsFilter {
+    fn matches<T: Config>(&self, post: &Post<T>) -> bool {
+        match self {
+            Self::All => true,
+            Self::Public => !post.hidden,
+            Self::Hidden => post.hidden,
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Get the existing posts by their ids, skipping the ids of missing posts.
+    pub fn get_posts_by_ids(post_ids: Vec<PostId>) -> Vec<FlatPostOf<T>> {
+        post_ids
+            .into_iter()
+            .filter_map(Self::post_by_id)
+            .map(|post| post.into())
+            .collect()
+    }
+
+    /// Get a page of root posts of a space that match a given filter, from the newest one.
+    pub fn get_posts_by_space(
+        space_id: SpaceId,
+        filter: PostsFilter,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<FlatPostOf<T>> {
+        Self::get_page_of_posts(Self::post_ids_by_space_id(space_id), filter, offset, limit)
+    }
+
+    /// Get a page of posts that have shared a given post, from the newest one.
+    pub fn get_shared_posts(
+        original_post_id: PostId,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<FlatPostOf<T>> {
+        Self::get_page_of_posts(
+            Self::shared_post_ids_by_original_post_id(original_post_id),
+            PostsFilter::All,
+            offset,
+            limit,
+        )
+    }
+
+    /// Get all replies to a post up to `MaxCommentDepth`, in the order of a depth-first walk
+    /// of its reply tree: each reply goes right after its parent and older sibling replies.
+    ///
+    /// Replies of deleted comments are included as well, while deleted comments are not.
+    pub fn get_reply_tree(post_id: PostId) -> Vec<FlatPostOf<T>> {
+        let max_depth = T::MaxCommentDepth::get();
+        let mut replies = Vec::new();
+
+        // Reply ids along with their depth, with the next reply to visit at the end.
+        let mut to_visit: Vec<(PostId, u32)> =
+            Self::newest_first(Self::reply_ids_by_post_id(post_id))
+                .map(|id| (id, 1))
+                .collect();
+
+        while let Some((reply_id, depth)) = to_visit.pop() {
+            if depth < max_depth {
+                to_visit.extend(
+                    Self::newest_first(Self::reply_ids_by_post_id(reply_id))
+                        .map(|id| (id, depth.saturating_add(1))),
+                );
+            }
+
+            if let Some(reply) = Self::post_by_id(reply_id) {
+                replies.push(reply.into());
+            }
+        }
+
+        replies
+    }
+
+    fn get_page_of_posts(
+        post_ids: Vec<PostId>,
+        filter: PostsFilter,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<FlatPostOf<T>> {
+        Self::newest_first(post_ids)
+            .filter_map(Self::post_by_id)
+            .filter(|post| filter.matches(post))
+            .skip(offset as usize)
+            .take(limit as usize)
+            .map(|post| post.into())
+            .collect()
+    }
+
+    /// Post ids are sequential, while the lists of post ids in storage lose their order
+    /// when an id is removed from them.
+    fn newest_first(mut post_ids: Vec<PostId>) -> impl Iterator<Item = PostId> {
+        post_ids.sort_unstable();
+        post_ids.into_iter().rev()
+    }
+
+    /// Get the previous revisions of a post's content, from the oldest one.
+    pub fn get_post_revisions(post_id: PostId) -> Vec<FlatPostRevisionOf<T>> {
+        Self::post_revisions_by_post_id(post_id)
+            .into_iter()
+            .map(|revision| revision.into())
+            .collect()
+    }
+
+    /// Get up to `limit` root posts of a space that received the most tips, along with
+    /// the total amount of tips of each post, starting from the most tipped one.
+    pub fn get_top_tipped_posts(space_id: SpaceId, limit: u32) -> Vec<(PostId, BalanceOf<T>)> {
+        let mut tipped_posts: Vec<_> = Self::post_ids_by_space_id(space_id)
+            .into_iter()
+            .map(|post_id| (post_id, Self::tips_total_by_post_id(post_id)))
+            .filter(|(_, total)| !total.is_zero())
+            .collect();
+
+        // The most tipped posts go first, and the older ones among posts with equal tips.
+        tipped_posts.sort_by(|(a_id, a_total), (b_id, b_total)| {
+            b_total.cmp(a_total).then_with(|| a_id.cmp(b_id))
+        });
+        tipped_posts.truncate(limit as usize);
+
+        tipped_posts
+    }
+
+    /// Check whether an account can comment on and react to a given post under its access policy.
+    pub fn can_access_post(account: T::AccountId, post_id: PostId) -> bool {
+        Self::require_post(post_id)
+            .map_or(false, |post| Self::can_account_access_post(&account, &post))
+    }
+}
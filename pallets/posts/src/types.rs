@@ -8,6 +8,9 @@ use super::*;
 
 pub const FIRST_POST_ID: u64 = 1;
 
+/// How many latest revisions of its posts a space keeps, if it has no retention setting.
+pub const DEFAULT_POST_REVISIONS_TO_KEEP: u32 = 5;
+
 /// Information about a post's owner, its' related space, content, and visibility.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 #[scale_info(skip_type_params(T))]
@@ -22,6 +25,10 @@ pub struct Post<T: Config> {
     /// The current owner of a given post.
     pub owner: T::AccountId,
 
+    /// The author of a given post: either the account that created it or a space on behalf of
+    /// which it was created.
+    pub author: User<T::AccountId>,
+
     /// Through post extension you can provide specific information necessary for different kinds
     /// of posts such as regular posts, comments, and shared posts.
     pub extension: PostExtension,
@@ -40,6 +47,67 @@ pub struct Post<T: Config> {
 
     /// The number of times a given post has been downvoted.
     pub downvotes_count: u32,
+
+    /// The number of direct replies to a given post.
+    pub replies_count: u32,
+
+    /// The number of times a given post has been shared.
+    pub shares_count: u32,
+
+    /// The amount reserved for storing this post, returned when the post is removed.
+    pub deposit: StorageDepositOf<T>,
+}
+
+/// What is left of a post deleted by an account, so that its replies and shares
+/// can still refer to it.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct PostTombstone<T: Config> {
+    pub id: PostId,
+
+    /// The extension of the deleted post, which links a deleted comment to its parent.
+    pub extension: PostExtension,
+
+    /// An id of a space which contained the deleted post.
+    pub space_id: Option<SpaceId>,
+
+    /// The owner of the post at the moment it was deleted.
+    pub owner: T::AccountId,
+
+    /// Who deleted the post and when.
+    pub deleted: WhoAndWhenOf<T>,
+}
+
+/// A previous content of a post, replaced by an update of the post.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct PostRevision<T: Config> {
+    pub content: Content,
+
+    /// Who set this content and when: the author of the post for its original content,
+    /// or the editor who put this content in place.
+    pub edited: WhoAndWhenOf<T>,
+}
+
+/// A regular post that is created in a space once a given block is reached.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ScheduledPost<T: Config> {
+    /// An id reserved for the post, under which it will be published.
+    pub id: PostId,
+
+    /// Who scheduled the post and when. The scheduler becomes the owner of the post.
+    pub created: WhoAndWhenOf<T>,
+
+    pub space_id: SpaceId,
+
+    pub content: Content,
+
+    /// The block at which the post is published.
+    pub publish_at: BlockNumberFor<T>,
+
+    /// The amount reserved for storing the post, which moves to the post once it is published.
+    pub deposit: StorageDepositOf<T>,
 }
 
 #[derive(Encode, Decode, Default, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
@@ -61,6 +129,7 @@ pub enum PostExtension {
     RegularPost,
     Comment(Comment),
     SharedPost(PostId),
+    Poll(PollExtension),
 }
 
 #[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo)]
@@ -70,8 +139,89 @@ pub struct Comment {
     pub parent_id: Option<PostId>,
 }
 
+/// A poll, whose question and options are described in the content of a post,
+/// while the votes for its options are counted on-chain.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct PollExtension {
+    /// The number of options to vote for, from 2 up to `MaxPollOptions`.
+    pub options_count: u8,
+    /// The number of blocks after the creation of a poll, during which votes are accepted.
+    pub voting_period: u32,
+}
+
+/// The on-chain state of a poll post.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct Poll<T: Config> {
+    /// The first block at which votes are not accepted anymore.
+    pub closes_at: BlockNumberFor<T>,
+
+    /// The number of votes for each option, in the order of the options.
+    pub tallies: Vec<u32>,
+}
+
+/// Who, besides the owner of a post and the owners of its space, can comment on the post
+/// and react to it.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum PostAccessPolicy<Balance> {
+    /// Backers who have staked at least `min_stake` on the post's space as a creator.
+    CreatorBackers { min_stake: Balance },
+    /// Holders of a given role in the post's space.
+    RoleHolders { role_id: u64 },
+}
+
+pub type PostAccessPolicyOf<T> = PostAccessPolicy<BalanceOf<T>>;
+
 impl Default for PostExtension {
     fn default() -> Self {
         PostExtension::RegularPost
     }
 }
+
+/// Who can comment on posts in a space, on top of the `CreateComments` permission.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
+pub enum CommentsPolicy {
+    /// Everyone who has the `CreateComments` permission can comment.
+    #[default]
+    Open,
+    /// Only the space owner and followers of the space can comment.
+    FollowersOnly,
+    /// Nobody can comment.
+    Closed,
+}
+
+/// A space setting that defines the comments policy of a space.
+pub struct CommentsPolicySetting;
+
+impl SpaceSetting for CommentsPolicySetting {
+    const KEY: &'static [u8] = b"posts:comments_policy";
+    const VERSION: u16 = 1;
+
+    type Value = CommentsPolicy;
+}
+
+/// How many previous revisions of its posts a space keeps.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum PostRevisionsRetention {
+    /// New revisions are not recorded, while the already stored ones are kept.
+    Disabled,
+    /// Keep up to a given number of the latest revisions, but no more than `MaxPostRevisions`.
+    KeepLatest(u32),
+}
+
+impl Default for PostRevisionsRetention {
+    fn default() -> Self {
+        PostRevisionsRetention::KeepLatest(DEFAULT_POST_REVISIONS_TO_KEEP)
+    }
+}
+
+/// A space setting that defines how many previous revisions of its posts a space keeps.
+pub struct PostRevisionsRetentionSetting;
+
+impl SpaceSetting for PostRevisionsRetentionSetting {
+    const KEY: &'static [u8] = b"posts:revisions_retention";
+    const VERSION: u16 = 1;
+
+    type Value = PostRevisionsRetention;
+}
@@ -43,8 +43,20 @@ pub trait WeightInfo {
     fn create_post__regular() -> Weight;
     fn create_post__shared() -> Weight;
     fn create_post__comment() -> Weight;
+    fn create_post__poll() -> Weight;
     fn update_post() -> Weight;
     fn move_post() -> Weight;
+    fn pin_post() -> Weight;
+    fn unpin_post() -> Weight;
+    fn delete_post() -> Weight;
+    fn schedule_post() -> Weight;
+    fn cancel_scheduled_post() -> Weight;
+    fn publish_scheduled_posts(n: u32, ) -> Weight;
+    fn vote_in_poll() -> Weight;
+    fn tip_post() -> Weight;
+    fn set_post_access_policy() -> Weight;
+    fn cleanup_deleted_post(n: u32, ) -> Weight;
+    fn cleanup_deleted_space(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_posts using the Substrate node and recommended hardware.
@@ -115,20 +127,45 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(7_u64))
             .saturating_add(T::DbWeight::get().writes(3_u64))
     }
+    /// Storage: Posts NextPostId (r:1 w:1)
+    /// Proof Skipped: Posts NextPostId (max_values: Some(1), max_size: None, mode: Measured)
+    /// Storage: Timestamp Now (r:1 w:0)
+    /// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    /// Storage: Spaces SpaceById (r:1 w:0)
+    /// Proof Skipped: Spaces SpaceById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+    /// Proof Skipped: SpaceFollows SpaceFollowedByAccount (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PostIdsBySpaceId (r:1 w:1)
+    /// Proof Skipped: Posts PostIdsBySpaceId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PollByPostId (r:0 w:1)
+    /// Proof Skipped: Posts PollByPostId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PostById (r:0 w:1)
+    /// Proof Skipped: Posts PostById (max_values: None, max_size: None, mode: Measured)
+    fn create_post__poll() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(54_236_000, 21203)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
     /// Storage: Posts PostById (r:2 w:1)
     /// Proof Skipped: Posts PostById (max_values: None, max_size: None, mode: Measured)
     /// Storage: Spaces SpaceById (r:1 w:0)
     /// Proof Skipped: Spaces SpaceById (max_values: None, max_size: None, mode: Measured)
     /// Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
     /// Proof Skipped: SpaceFollows SpaceFollowedByAccount (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Spaces SpaceSettingByKey (r:1 w:0)
+    /// Proof Skipped: Spaces SpaceSettingByKey (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PostRevisionsByPostId (r:1 w:1)
+    /// Proof Skipped: Posts PostRevisionsByPostId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts LastEditByPostId (r:1 w:1)
+    /// Proof Skipped: Posts LastEditByPostId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Timestamp Now (r:1 w:0)
+    /// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
     fn update_post() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `1874`
-        //  Estimated: `18492`
-        // Minimum execution time: 50_055_000 picoseconds.
-        Weight::from_parts(52_079_000, 18492)
-            .saturating_add(T::DbWeight::get().reads(4_u64))
-            .saturating_add(T::DbWeight::get().writes(1_u64))
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(60_442_000, 18492)
+            .saturating_add(T::DbWeight::get().reads(8_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
     }
     /// Storage: Posts PostById (r:1 w:1)
     /// Proof Skipped: Posts PostById (max_values: None, max_size: None, mode: Measured)
@@ -147,6 +184,217 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
             .saturating_add(T::DbWeight::get().reads(7_u64))
             .saturating_add(T::DbWeight::get().writes(3_u64))
     }
+    /// Storage: Posts PostById (r:1 w:0)
+    /// Proof Skipped: Posts PostById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Spaces SpaceById (r:1 w:0)
+    /// Proof Skipped: Spaces SpaceById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+    /// Proof Skipped: SpaceFollows SpaceFollowedByAccount (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PinnedPostIdsBySpaceId (r:1 w:1)
+    /// Proof Skipped: Posts PinnedPostIdsBySpaceId (max_values: None, max_size: None, mode: Measured)
+    fn pin_post() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(39_304_000, 18492)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: Posts PinnedPostIdsBySpaceId (r:1 w:1)
+    /// Proof Skipped: Posts PinnedPostIdsBySpaceId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Spaces SpaceById (r:1 w:0)
+    /// Proof Skipped: Spaces SpaceById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+    /// Proof Skipped: SpaceFollows SpaceFollowedByAccount (max_values: None, max_size: None, mode: Measured)
+    fn unpin_post() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(34_712_000, 14736)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: Posts PostById (r:1 w:1)
+    /// Proof Skipped: Posts PostById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Spaces SpaceById (r:1 w:1)
+    /// Proof Skipped: Spaces SpaceById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+    /// Proof Skipped: SpaceFollows SpaceFollowedByAccount (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts ReplyIdsByPostId (r:1 w:1)
+    /// Proof Skipped: Posts ReplyIdsByPostId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PostIdsBySpaceId (r:1 w:1)
+    /// Proof Skipped: Posts PostIdsBySpaceId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PinnedPostIdsBySpaceId (r:1 w:0)
+    /// Proof Skipped: Posts PinnedPostIdsBySpaceId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: System Account (r:1 w:1)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: Timestamp Now (r:1 w:0)
+    /// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    /// Storage: Posts SharedPostIdsByOriginalPostId (r:0 w:1)
+    /// Proof Skipped: Posts SharedPostIdsByOriginalPostId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PostTombstoneById (r:0 w:1)
+    /// Proof Skipped: Posts PostTombstoneById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PostCleanupQueueTail (r:1 w:1)
+    /// Proof Skipped: Posts PostCleanupQueueTail (max_values: Some(1), max_size: None, mode: Measured)
+    /// Storage: Posts PostIdsPendingCleanup (r:0 w:1)
+    /// Proof Skipped: Posts PostIdsPendingCleanup (max_values: None, max_size: None, mode: Measured)
+    fn delete_post() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(63_015_000, 27364)
+            .saturating_add(T::DbWeight::get().reads(9_u64))
+            .saturating_add(T::DbWeight::get().writes(10_u64))
+    }
+    /// Storage: Spaces SpaceById (r:1 w:0)
+    /// Proof Skipped: Spaces SpaceById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+    /// Proof Skipped: SpaceFollows SpaceFollowedByAccount (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts NextPostId (r:1 w:1)
+    /// Proof Skipped: Posts NextPostId (max_values: Some(1), max_size: None, mode: Measured)
+    /// Storage: Timestamp Now (r:1 w:0)
+    /// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    /// Storage: Posts ScheduledPostIdsByBlock (r:1 w:1)
+    /// Proof Skipped: Posts ScheduledPostIdsByBlock (max_values: None, max_size: None, mode: Measured)
+    /// Storage: System Account (r:1 w:1)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: Posts ScheduledPostById (r:0 w:1)
+    /// Proof Skipped: Posts ScheduledPostById (max_values: None, max_size: None, mode: Measured)
+    fn schedule_post() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(53_904_000, 22518)
+            .saturating_add(T::DbWeight::get().reads(6_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+    /// Storage: Posts ScheduledPostById (r:1 w:1)
+    /// Proof Skipped: Posts ScheduledPostById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts ScheduledPostIdsByBlock (r:1 w:1)
+    /// Proof Skipped: Posts ScheduledPostIdsByBlock (max_values: None, max_size: None, mode: Measured)
+    /// Storage: System Account (r:1 w:1)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    fn cancel_scheduled_post() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(35_517_000, 11466)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: Posts ScheduledPostIdsByBlock (r:1 w:1)
+    /// Proof Skipped: Posts ScheduledPostIdsByBlock (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts ScheduledPostById (r:20 w:20)
+    /// Proof Skipped: Posts ScheduledPostById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Spaces SpaceById (r:1 w:1)
+    /// Proof Skipped: Spaces SpaceById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+    /// Proof Skipped: SpaceFollows SpaceFollowedByAccount (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Timestamp Now (r:1 w:0)
+    /// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    /// Storage: Posts PostIdsBySpaceId (r:1 w:1)
+    /// Proof Skipped: Posts PostIdsBySpaceId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PostById (r:0 w:20)
+    /// Proof Skipped: Posts PostById (max_values: None, max_size: None, mode: Measured)
+    /// The range of component `n` is `[0, 20]`.
+    fn publish_scheduled_posts(n: u32, ) -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(6_084_000, 4508)
+            .saturating_add(Weight::from_parts(38_716_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+            .saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 14912).saturating_mul(n.into()))
+    }
+    /// Storage: Posts PostById (r:1 w:0)
+    /// Proof Skipped: Posts PostById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PollByPostId (r:1 w:1)
+    /// Proof Skipped: Posts PollByPostId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PollVoteByAccount (r:1 w:1)
+    /// Proof Skipped: Posts PollVoteByAccount (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Spaces SpaceById (r:1 w:0)
+    /// Proof Skipped: Spaces SpaceById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+    /// Proof Skipped: SpaceFollows SpaceFollowedByAccount (max_values: None, max_size: None, mode: Measured)
+    fn vote_in_poll() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(37_682_000, 18297)
+            .saturating_add(T::DbWeight::get().reads(5_u64))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+    }
+    /// Storage: Posts PostById (r:1 w:0)
+    /// Proof Skipped: Posts PostById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: System Account (r:2 w:2)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: Posts TipsTotalByPostId (r:1 w:1)
+    /// Proof Skipped: Posts TipsTotalByPostId (max_values: None, max_size: None, mode: Measured)
+    fn tip_post() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(49_105_000, 15484)
+            .saturating_add(T::DbWeight::get().reads(4_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: Posts PostById (r:1 w:0)
+    /// Proof Skipped: Posts PostById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Spaces SpaceById (r:1 w:0)
+    /// Proof Skipped: Spaces SpaceById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+    /// Proof Skipped: SpaceFollows SpaceFollowedByAccount (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PostAccessPolicyByPostId (r:0 w:1)
+    /// Proof Skipped: Posts PostAccessPolicyByPostId (max_values: None, max_size: None, mode: Measured)
+    fn set_post_access_policy() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(28_204_000, 11916)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: Posts PollVotesCleanupCursor (r:1 w:1)
+    /// Proof Skipped: Posts PollVotesCleanupCursor (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PollVoteByAccount (r:0 w:1)
+    /// Proof Skipped: Posts PollVoteByAccount (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Reactions ReactionIdsByPostId (r:1 w:1)
+    /// Proof Skipped: Reactions ReactionIdsByPostId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Reactions ReactionById (r:1 w:1)
+    /// Proof Skipped: Reactions ReactionById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: System Account (r:1 w:1)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: PostFollows PostFollowers (r:1 w:1)
+    /// Proof Skipped: PostFollows PostFollowers (max_values: None, max_size: None, mode: Measured)
+    /// Storage: PostFollows PostsFollowedByAccount (r:1 w:1)
+    /// Proof Skipped: PostFollows PostsFollowedByAccount (max_values: None, max_size: None, mode: Measured)
+    /// The range of component `n` is `[0, 100]`.
+    fn cleanup_deleted_post(n: u32, ) -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(9_684_000, 8980)
+            .saturating_add(Weight::from_parts(24_518_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+            .saturating_add(T::DbWeight::get().writes((4_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 5190).saturating_mul(n.into()))
+    }
+    /// Storage: Posts PostIdsBySpaceId (r:1 w:1)
+    /// Proof Skipped: Posts PostIdsBySpaceId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts ReplyIdsByPostId (r:100 w:100)
+    /// Proof Skipped: Posts ReplyIdsByPostId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PostById (r:200 w:200)
+    /// Proof Skipped: Posts PostById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PollVotesCleanupCursor (r:100 w:0)
+    /// Proof Skipped: Posts PollVotesCleanupCursor (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Reactions ReactionIdsByPostId (r:100 w:0)
+    /// Proof Skipped: Reactions ReactionIdsByPostId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: PostFollows PostFollowers (r:100 w:0)
+    /// Proof Skipped: PostFollows PostFollowers (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts SharedPostIdsByOriginalPostId (r:100 w:200)
+    /// Proof Skipped: Posts SharedPostIdsByOriginalPostId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PinnedPostIdsBySpaceId (r:100 w:101)
+    /// Proof Skipped: Posts PinnedPostIdsBySpaceId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Spaces SpaceById (r:100 w:100)
+    /// Proof Skipped: Spaces SpaceById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: System Account (r:100 w:100)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// The range of component `n` is `[1, 100]`.
+    fn cleanup_deleted_space(n: u32, ) -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(35_000_000, 20000)
+            .saturating_add(Weight::from_parts(95_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().reads((10_u64).saturating_mul(n.into())))
+            .saturating_add(T::DbWeight::get().writes(2_u64))
+            .saturating_add(T::DbWeight::get().writes((16_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 40000).saturating_mul(n.into()))
+    }
 }
 
 // For backwards compatibility and tests
@@ -216,20 +464,45 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(7_u64))
             .saturating_add(RocksDbWeight::get().writes(3_u64))
     }
+    /// Storage: Posts NextPostId (r:1 w:1)
+    /// Proof Skipped: Posts NextPostId (max_values: Some(1), max_size: None, mode: Measured)
+    /// Storage: Timestamp Now (r:1 w:0)
+    /// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    /// Storage: Spaces SpaceById (r:1 w:0)
+    /// Proof Skipped: Spaces SpaceById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+    /// Proof Skipped: SpaceFollows SpaceFollowedByAccount (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PostIdsBySpaceId (r:1 w:1)
+    /// Proof Skipped: Posts PostIdsBySpaceId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PollByPostId (r:0 w:1)
+    /// Proof Skipped: Posts PollByPostId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PostById (r:0 w:1)
+    /// Proof Skipped: Posts PostById (max_values: None, max_size: None, mode: Measured)
+    fn create_post__poll() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(54_236_000, 21203)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
     /// Storage: Posts PostById (r:2 w:1)
     /// Proof Skipped: Posts PostById (max_values: None, max_size: None, mode: Measured)
     /// Storage: Spaces SpaceById (r:1 w:0)
     /// Proof Skipped: Spaces SpaceById (max_values: None, max_size: None, mode: Measured)
     /// Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
     /// Proof Skipped: SpaceFollows SpaceFollowedByAccount (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Spaces SpaceSettingByKey (r:1 w:0)
+    /// Proof Skipped: Spaces SpaceSettingByKey (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PostRevisionsByPostId (r:1 w:1)
+    /// Proof Skipped: Posts PostRevisionsByPostId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts LastEditByPostId (r:1 w:1)
+    /// Proof Skipped: Posts LastEditByPostId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Timestamp Now (r:1 w:0)
+    /// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
     fn update_post() -> Weight {
-        // Proof Size summary in bytes:
-        //  Measured:  `1874`
-        //  Estimated: `18492`
-        // Minimum execution time: 50_055_000 picoseconds.
-        Weight::from_parts(52_079_000, 18492)
-            .saturating_add(RocksDbWeight::get().reads(4_u64))
-            .saturating_add(RocksDbWeight::get().writes(1_u64))
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(60_442_000, 18492)
+            .saturating_add(RocksDbWeight::get().reads(8_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
     }
     /// Storage: Posts PostById (r:1 w:1)
     /// Proof Skipped: Posts PostById (max_values: None, max_size: None, mode: Measured)
@@ -248,4 +521,215 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(7_u64))
             .saturating_add(RocksDbWeight::get().writes(3_u64))
     }
+    /// Storage: Posts PostById (r:1 w:0)
+    /// Proof Skipped: Posts PostById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Spaces SpaceById (r:1 w:0)
+    /// Proof Skipped: Spaces SpaceById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+    /// Proof Skipped: SpaceFollows SpaceFollowedByAccount (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PinnedPostIdsBySpaceId (r:1 w:1)
+    /// Proof Skipped: Posts PinnedPostIdsBySpaceId (max_values: None, max_size: None, mode: Measured)
+    fn pin_post() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(39_304_000, 18492)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: Posts PinnedPostIdsBySpaceId (r:1 w:1)
+    /// Proof Skipped: Posts PinnedPostIdsBySpaceId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Spaces SpaceById (r:1 w:0)
+    /// Proof Skipped: Spaces SpaceById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+    /// Proof Skipped: SpaceFollows SpaceFollowedByAccount (max_values: None, max_size: None, mode: Measured)
+    fn unpin_post() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(34_712_000, 14736)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: Posts PostById (r:1 w:1)
+    /// Proof Skipped: Posts PostById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Spaces SpaceById (r:1 w:1)
+    /// Proof Skipped: Spaces SpaceById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+    /// Proof Skipped: SpaceFollows SpaceFollowedByAccount (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts ReplyIdsByPostId (r:1 w:1)
+    /// Proof Skipped: Posts ReplyIdsByPostId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PostIdsBySpaceId (r:1 w:1)
+    /// Proof Skipped: Posts PostIdsBySpaceId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PinnedPostIdsBySpaceId (r:1 w:0)
+    /// Proof Skipped: Posts PinnedPostIdsBySpaceId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: System Account (r:1 w:1)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: Timestamp Now (r:1 w:0)
+    /// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    /// Storage: Posts SharedPostIdsByOriginalPostId (r:0 w:1)
+    /// Proof Skipped: Posts SharedPostIdsByOriginalPostId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PostTombstoneById (r:0 w:1)
+    /// Proof Skipped: Posts PostTombstoneById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PostCleanupQueueTail (r:1 w:1)
+    /// Proof Skipped: Posts PostCleanupQueueTail (max_values: Some(1), max_size: None, mode: Measured)
+    /// Storage: Posts PostIdsPendingCleanup (r:0 w:1)
+    /// Proof Skipped: Posts PostIdsPendingCleanup (max_values: None, max_size: None, mode: Measured)
+    fn delete_post() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(63_015_000, 27364)
+            .saturating_add(RocksDbWeight::get().reads(9_u64))
+            .saturating_add(RocksDbWeight::get().writes(10_u64))
+    }
+    /// Storage: Spaces SpaceById (r:1 w:0)
+    /// Proof Skipped: Spaces SpaceById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+    /// Proof Skipped: SpaceFollows SpaceFollowedByAccount (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts NextPostId (r:1 w:1)
+    /// Proof Skipped: Posts NextPostId (max_values: Some(1), max_size: None, mode: Measured)
+    /// Storage: Timestamp Now (r:1 w:0)
+    /// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    /// Storage: Posts ScheduledPostIdsByBlock (r:1 w:1)
+    /// Proof Skipped: Posts ScheduledPostIdsByBlock (max_values: None, max_size: None, mode: Measured)
+    /// Storage: System Account (r:1 w:1)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: Posts ScheduledPostById (r:0 w:1)
+    /// Proof Skipped: Posts ScheduledPostById (max_values: None, max_size: None, mode: Measured)
+    fn schedule_post() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(53_904_000, 22518)
+            .saturating_add(RocksDbWeight::get().reads(6_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+    /// Storage: Posts ScheduledPostById (r:1 w:1)
+    /// Proof Skipped: Posts ScheduledPostById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts ScheduledPostIdsByBlock (r:1 w:1)
+    /// Proof Skipped: Posts ScheduledPostIdsByBlock (max_values: None, max_size: None, mode: Measured)
+    /// Storage: System Account (r:1 w:1)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    fn cancel_scheduled_post() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(35_517_000, 11466)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: Posts ScheduledPostIdsByBlock (r:1 w:1)
+    /// Proof Skipped: Posts ScheduledPostIdsByBlock (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts ScheduledPostById (r:20 w:20)
+    /// Proof Skipped: Posts ScheduledPostById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Spaces SpaceById (r:1 w:1)
+    /// Proof Skipped: Spaces SpaceById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+    /// Proof Skipped: SpaceFollows SpaceFollowedByAccount (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Timestamp Now (r:1 w:0)
+    /// Proof: Timestamp Now (max_values: Some(1), max_size: Some(8), added: 503, mode: MaxEncodedLen)
+    /// Storage: Posts PostIdsBySpaceId (r:1 w:1)
+    /// Proof Skipped: Posts PostIdsBySpaceId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PostById (r:0 w:20)
+    /// Proof Skipped: Posts PostById (max_values: None, max_size: None, mode: Measured)
+    /// The range of component `n` is `[0, 20]`.
+    fn publish_scheduled_posts(n: u32, ) -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(6_084_000, 4508)
+            .saturating_add(Weight::from_parts(38_716_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+            .saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 14912).saturating_mul(n.into()))
+    }
+    /// Storage: Posts PostById (r:1 w:0)
+    /// Proof Skipped: Posts PostById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PollByPostId (r:1 w:1)
+    /// Proof Skipped: Posts PollByPostId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PollVoteByAccount (r:1 w:1)
+    /// Proof Skipped: Posts PollVoteByAccount (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Spaces SpaceById (r:1 w:0)
+    /// Proof Skipped: Spaces SpaceById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+    /// Proof Skipped: SpaceFollows SpaceFollowedByAccount (max_values: None, max_size: None, mode: Measured)
+    fn vote_in_poll() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(37_682_000, 18297)
+            .saturating_add(RocksDbWeight::get().reads(5_u64))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+    }
+    /// Storage: Posts PostById (r:1 w:0)
+    /// Proof Skipped: Posts PostById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: System Account (r:2 w:2)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: Posts TipsTotalByPostId (r:1 w:1)
+    /// Proof Skipped: Posts TipsTotalByPostId (max_values: None, max_size: None, mode: Measured)
+    fn tip_post() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(49_105_000, 15484)
+            .saturating_add(RocksDbWeight::get().reads(4_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: Posts PostById (r:1 w:0)
+    /// Proof Skipped: Posts PostById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Spaces SpaceById (r:1 w:0)
+    /// Proof Skipped: Spaces SpaceById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+    /// Proof Skipped: SpaceFollows SpaceFollowedByAccount (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PostAccessPolicyByPostId (r:0 w:1)
+    /// Proof Skipped: Posts PostAccessPolicyByPostId (max_values: None, max_size: None, mode: Measured)
+    fn set_post_access_policy() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(28_204_000, 11916)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: Posts PollVotesCleanupCursor (r:1 w:1)
+    /// Proof Skipped: Posts PollVotesCleanupCursor (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PollVoteByAccount (r:0 w:1)
+    /// Proof Skipped: Posts PollVoteByAccount (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Reactions ReactionIdsByPostId (r:1 w:1)
+    /// Proof Skipped: Reactions ReactionIdsByPostId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Reactions ReactionById (r:1 w:1)
+    /// Proof Skipped: Reactions ReactionById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: System Account (r:1 w:1)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// Storage: PostFollows PostFollowers (r:1 w:1)
+    /// Proof Skipped: PostFollows PostFollowers (max_values: None, max_size: None, mode: Measured)
+    /// Storage: PostFollows PostsFollowedByAccount (r:1 w:1)
+    /// Proof Skipped: PostFollows PostsFollowedByAccount (max_values: None, max_size: None, mode: Measured)
+    /// The range of component `n` is `[0, 100]`.
+    fn cleanup_deleted_post(n: u32, ) -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(9_684_000, 8980)
+            .saturating_add(Weight::from_parts(24_518_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+            .saturating_add(RocksDbWeight::get().writes((4_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 5190).saturating_mul(n.into()))
+    }
+    /// Storage: Posts PostIdsBySpaceId (r:1 w:1)
+    /// Proof Skipped: Posts PostIdsBySpaceId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts ReplyIdsByPostId (r:100 w:100)
+    /// Proof Skipped: Posts ReplyIdsByPostId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PostById (r:200 w:200)
+    /// Proof Skipped: Posts PostById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PollVotesCleanupCursor (r:100 w:0)
+    /// Proof Skipped: Posts PollVotesCleanupCursor (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Reactions ReactionIdsByPostId (r:100 w:0)
+    /// Proof Skipped: Reactions ReactionIdsByPostId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: PostFollows PostFollowers (r:100 w:0)
+    /// Proof Skipped: PostFollows PostFollowers (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts SharedPostIdsByOriginalPostId (r:100 w:200)
+    /// Proof Skipped: Posts SharedPostIdsByOriginalPostId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Posts PinnedPostIdsBySpaceId (r:100 w:101)
+    /// Proof Skipped: Posts PinnedPostIdsBySpaceId (max_values: None, max_size: None, mode: Measured)
+    /// Storage: Spaces SpaceById (r:100 w:100)
+    /// Proof Skipped: Spaces SpaceById (max_values: None, max_size: None, mode: Measured)
+    /// Storage: System Account (r:100 w:100)
+    /// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+    /// The range of component `n` is `[1, 100]`.
+    fn cleanup_deleted_space(n: u32, ) -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(35_000_000, 20000)
+            .saturating_add(Weight::from_parts(95_000_000, 0).saturating_mul(n.into()))
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().reads((10_u64).saturating_mul(n.into())))
+            .saturating_add(RocksDbWeight::get().writes(2_u64))
+            .saturating_add(RocksDbWeight::get().writes((16_u64).saturating_mul(n.into())))
+            .saturating_add(Weight::from_parts(0, 40000).saturating_mul(n.into()))
+    }
 }
\ No newline at end of file
@@ -0,0 +1,120 @@
+// Copyright (C) DAPPFORCE PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
+//
+// Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
+// Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
+
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResult};
+
+use pallet_permissions::SpacePermission as SP;
+use pallet_posts::{Error as PostsError, Event as PostsEvent, PostAccessPolicy};
+use subsocial_support::PostId;
+
+use crate::{mock::*, tests_utils::*};
+
+fn _set_post_access_policy(
+    origin: Option<RuntimeOrigin>,
+    post_id: Option<PostId>,
+    policy_opt: Option<PostAccessPolicy<Balance>>,
+) -> DispatchResult {
+    Posts::set_post_access_policy(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        post_id.unwrap_or(POST1),
+        policy_opt,
+    )
+}
+
+fn _create_comment_by_account2() -> DispatchResult {
+    _create_comment(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None, None)
+}
+
+fn backers_policy(min_stake: Balance) -> Option<PostAccessPolicy<Balance>> {
+    Some(PostAccessPolicy::CreatorBackers { min_stake })
+}
+
+#[test]
+fn set_post_access_policy_should_work() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_set_post_access_policy(None, None, backers_policy(10)));
+
+        assert_eq!(Posts::post_access_policy_by_post_id(POST1), backers_policy(10));
+        System::assert_last_event(
+            PostsEvent::PostAccessPolicyUpdated { account: ACCOUNT1, post_id: POST1 }.into(),
+        );
+
+        assert_ok!(_set_post_access_policy(None, None, None));
+        assert!(Posts::post_access_policy_by_post_id(POST1).is_none());
+        assert!(Posts::can_access_post(ACCOUNT2, POST1));
+    });
+}
+
+#[test]
+fn set_post_access_policy_should_fail_for_invalid_post_or_policy() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_noop!(
+            _set_post_access_policy(None, Some(POST2), backers_policy(10)),
+            PostsError::<Test>::CannotRestrictAccessToPost
+        );
+        assert_noop!(
+            _set_post_access_policy(None, None, backers_policy(0)),
+            PostsError::<Test>::AccessPolicyMinStakeIsZero
+        );
+        assert_noop!(
+            _set_post_access_policy(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                None,
+                backers_policy(10)
+            ),
+            PostsError::<Test>::NoPermissionToUpdateAnyPost
+        );
+    });
+}
+
+#[test]
+fn create_comment_should_require_min_stake_on_creator() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_set_post_access_policy(None, None, backers_policy(10)));
+
+        set_backer_stake(ACCOUNT2, SPACE1, 9);
+        assert!(!Posts::can_access_post(ACCOUNT2, POST1));
+        assert_noop!(_create_comment_by_account2(), PostsError::<Test>::NoAccessToPost);
+
+        set_backer_stake(ACCOUNT2, SPACE1, 10);
+        assert!(Posts::can_access_post(ACCOUNT2, POST1));
+        assert_ok!(_create_comment_by_account2());
+    });
+}
+
+#[test]
+fn create_comment_should_require_role_in_space() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_role(None, None, None, None, Some(vec![SP::UpdateOwnComments])));
+        assert_ok!(_set_post_access_policy(
+            None,
+            None,
+            Some(PostAccessPolicy::RoleHolders { role_id: ROLE1 })
+        ));
+
+        assert_noop!(_create_comment_by_account2(), PostsError::<Test>::NoAccessToPost);
+
+        assert_ok!(_grant_role(None, None, None));
+        assert_ok!(_create_comment_by_account2());
+    });
+}
+
+#[test]
+fn post_owner_should_have_access_to_restricted_post() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_ok!(_set_post_access_policy(None, None, backers_policy(10)));
+
+        assert!(Posts::can_access_post(ACCOUNT1, POST1));
+        assert!(Posts::can_access_post(ACCOUNT1, POST2));
+        assert_ok!(_create_default_comment());
+
+        // Replies to comments follow the access policy of their root post.
+        assert_noop!(
+            _create_comment(Some(RuntimeOrigin::signed(ACCOUNT2)), None, Some(Some(POST2)), None),
+            PostsError::<Test>::NoAccessToPost
+        );
+    });
+}
@@ -4,11 +4,12 @@
 // Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
 // Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
 
+use codec::Encode;
 use frame_support::{assert_noop, assert_ok};
 use sp_runtime::DispatchError;
 
-use pallet_posts::Error as PostsError;
-use subsocial_support::{mock_functions::*, ContentError, PostId};
+use pallet_posts::{CommentsPolicy, CommentsPolicySetting, Error as PostsError};
+use subsocial_support::{mock_functions::*, traits::SpaceSetting, ContentError, PostId};
 
 use crate::{mock::*, tests_utils::*};
 
@@ -32,6 +33,10 @@ fn create_comment_should_work() {
 
         assert_eq!(comment.upvotes_count, 0);
         assert_eq!(comment.downvotes_count, 0);
+
+        assert_eq!(Posts::post_by_id(POST1).unwrap().replies_count, 1);
+        // Comments are not counted as posts of a space
+        assert_eq!(Spaces::space_by_id(SPACE1).unwrap().posts_count, 1);
     });
 }
 
@@ -128,6 +133,48 @@ fn create_comment_should_fail_when_max_comment_depth_reached() {
     });
 }
 
+#[test]
+fn create_comment_should_count_deleted_ancestors_in_max_depth() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_comment(None, None, Some(None), None)); // PostId 2
+
+        for parent_id in 2..11_u64 {
+            assert_ok!(_create_comment(None, None, Some(Some(parent_id)), None));
+        }
+
+        // A deleted comment in the middle of the chain still counts towards its depth.
+        assert_ok!(Posts::delete_post(RuntimeOrigin::signed(ACCOUNT1), 5));
+        assert_eq!(Posts::get_comment_depth(11), Ok(MaxCommentDepth::get()));
+
+        assert_noop!(
+            _create_comment(None, None, Some(Some(11)), None),
+            PostsError::<Test>::MaxCommentDepthReached
+        );
+    });
+}
+
+#[test]
+fn create_comment_should_respect_comments_policy_of_space() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(Spaces::update_space_setting(
+            RuntimeOrigin::signed(ACCOUNT1),
+            SPACE1,
+            CommentsPolicySetting::KEY.to_vec(),
+            Some(CommentsPolicy::FollowersOnly.encode()),
+        ));
+
+        // The space owner can comment without following the space.
+        assert_ok!(_create_default_comment());
+        assert_noop!(
+            _create_comment(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None, None),
+            PostsError::<Test>::CommentsAreForFollowersOnly
+        );
+
+        assert_ok!(SpaceFollows::follow_space(RuntimeOrigin::signed(ACCOUNT2), SPACE1));
+        assert_ok!(_create_comment(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None, None));
+    });
+}
+
 #[test]
 fn update_comment_should_work() {
     ExtBuilder::build_with_comment().execute_with(|| {
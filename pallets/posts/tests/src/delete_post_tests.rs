@@ -0,0 +1,116 @@
+// Copyright (C) DAPPFORCE PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
+//
+// Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
+// Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
+
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResult};
+
+use pallet_posts::{Error as PostsError, Event as PostsEvent};
+use pallet_spaces::Error as SpacesError;
+use subsocial_support::PostId;
+
+use crate::{mock::*, tests_utils::*};
+
+fn _delete_post(origin: Option<RuntimeOrigin>, post_id: Option<PostId>) -> DispatchResult {
+    Posts::delete_post(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        post_id.unwrap_or(POST1),
+    )
+}
+
+#[test]
+fn delete_post_should_work() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(Posts::pin_post(RuntimeOrigin::signed(ACCOUNT1), SPACE1, POST1));
+
+        assert_ok!(_delete_post(None, None));
+
+        assert!(Posts::post_by_id(POST1).is_none());
+        assert!(Posts::post_ids_by_space_id(SPACE1).is_empty());
+        assert!(Posts::get_pinned_post_ids(SPACE1).is_empty());
+        assert_eq!(Spaces::space_by_id(SPACE1).unwrap().posts_count, 0);
+
+        let tombstone = Posts::post_tombstone_by_id(POST1).expect("tombstone should exist");
+        assert_eq!(tombstone.space_id, Some(SPACE1));
+        assert_eq!(tombstone.owner, ACCOUNT1);
+        assert_eq!(tombstone.deleted.account, ACCOUNT1);
+
+        System::assert_last_event(
+            PostsEvent::PostDeleted { account: ACCOUNT1, post_id: POST1 }.into(),
+        );
+        assert_noop!(_delete_post(None, None), PostsError::<Test>::PostNotFound);
+    });
+}
+
+#[test]
+fn delete_post_should_fail_when_account_has_no_permission() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_noop!(
+            _delete_post(Some(RuntimeOrigin::signed(ACCOUNT2)), None),
+            PostsError::<Test>::NoPermissionToDeleteAnyPost
+        );
+    });
+}
+
+#[test]
+fn delete_post_should_fail_when_space_is_archived() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_ok!(Spaces::set_space_archived(RuntimeOrigin::signed(ACCOUNT1), SPACE1, true));
+
+        assert_noop!(_delete_post(None, None), SpacesError::<Test>::SpaceIsArchived);
+        assert_noop!(_delete_post(None, Some(POST2)), SpacesError::<Test>::SpaceIsArchived);
+    });
+}
+
+#[test]
+fn delete_post_should_allow_comment_author_and_space_owner() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_comment(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None, None)); // PostId 2
+        assert_ok!(_create_comment(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None, None)); // PostId 3
+
+        // The author deletes their own comment with `DeleteOwnComments`.
+        assert_ok!(_delete_post(Some(RuntimeOrigin::signed(ACCOUNT2)), Some(POST2)));
+        // The space owner deletes someone else's comment with `DeleteAnyPost`.
+        assert_ok!(_delete_post(None, Some(POST3)));
+
+        assert!(Posts::reply_ids_by_post_id(POST1).is_empty());
+        assert_eq!(Posts::post_by_id(POST1).unwrap().replies_count, 0);
+        assert_eq!(Posts::post_tombstone_by_id(POST3).unwrap().deleted.account, ACCOUNT1);
+        // Deleted comments are not counted among the posts of a space.
+        assert_eq!(Spaces::space_by_id(SPACE1).unwrap().posts_count, 1);
+    });
+}
+
+#[test]
+fn delete_post_should_keep_replies_of_deleted_comment_navigable() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_ok!(_create_comment(None, None, Some(Some(POST2)), None)); // PostId 3
+
+        assert_ok!(_delete_post(None, Some(POST2)));
+
+        assert!(Posts::post_by_id(POST2).is_none());
+        // The deleted comment stays among the replies of the root post,
+        // and its replies are still linked to it.
+        assert_eq!(Posts::reply_ids_by_post_id(POST1), vec![POST2]);
+        assert_eq!(Posts::reply_ids_by_post_id(POST2), vec![POST3]);
+        assert_eq!(Posts::post_by_id(POST1).unwrap().replies_count, 1);
+
+        let tombstone = Posts::post_tombstone_by_id(POST2).expect("tombstone should exist");
+        assert_eq!(tombstone.extension, extension_comment(None, POST1));
+        assert!(Posts::post_by_id(POST3).is_some());
+    });
+}
+
+#[test]
+fn delete_post_should_update_shares_of_original_post() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_post(None, None, Some(extension_shared_post(POST1)), None)); // PostId 2
+        assert_eq!(Posts::post_by_id(POST1).unwrap().shares_count, 1);
+
+        assert_ok!(_delete_post(None, Some(POST2)));
+
+        assert_eq!(Posts::post_by_id(POST1).unwrap().shares_count, 0);
+        assert!(Posts::shared_post_ids_by_original_post_id(POST1).is_empty());
+    });
+}
@@ -4,13 +4,33 @@
 // Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
 // Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
 
+#[cfg(test)]
+mod access_policy_tests;
 #[cfg(test)]
 mod comments_tests;
 #[cfg(test)]
+mod delete_post_tests;
+#[cfg(test)]
+mod migration_tests;
+#[cfg(test)]
 mod mock;
 #[cfg(test)]
+mod pinned_posts_tests;
+#[cfg(test)]
+mod poll_tests;
+#[cfg(test)]
+mod post_listing_tests;
+#[cfg(test)]
+mod post_revisions_tests;
+#[cfg(test)]
 mod post_tests;
 #[cfg(test)]
+mod scheduled_posts_tests;
+#[cfg(test)]
 mod shared_posts_tests;
 #[cfg(test)]
+mod space_authors_tests;
+#[cfg(test)]
 mod tests_utils;
+#[cfg(test)]
+mod tips_tests;
@@ -0,0 +1,124 @@
+// Copyright (C) DAPPFORCE PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
+//
+// Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
+// Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
+
+use frame_support::traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion};
+
+use pallet_posts::{
+    migration::v2::{self as posts_v2, OldPost, OldPostById, POSTS_PER_BLOCK},
+    Comment, PostExtension,
+};
+use pallet_spaces::migration::v5::{self as spaces_v5, OldSpace, OldSpaceById};
+use subsocial_support::{new_who_and_when, Content, PostId, SpaceId, User};
+
+use crate::{mock::*, tests_utils::*};
+
+fn insert_old_space(space_id: SpaceId) {
+    OldSpaceById::<Test>::insert(
+        space_id,
+        OldSpace {
+            id: space_id,
+            created: new_who_and_when::<Test>(ACCOUNT1),
+            edited: false,
+            owner: ACCOUNT1,
+            content: Content::None,
+            hidden: false,
+            permissions: None,
+        },
+    );
+}
+
+fn insert_old_post(post_id: PostId, extension: PostExtension, hidden: bool) {
+    OldPostById::<Test>::insert(
+        post_id,
+        OldPost {
+            id: post_id,
+            created: new_who_and_when::<Test>(ACCOUNT2),
+            edited: false,
+            owner: ACCOUNT1,
+            extension,
+            space_id: Some(SPACE1),
+            content: Content::None,
+            hidden,
+            upvotes_count: 0,
+            downvotes_count: 0,
+        },
+    );
+}
+
+fn start_migrations() {
+    StorageVersion::new(0).put::<Spaces>();
+    StorageVersion::new(0).put::<Posts>();
+
+    spaces_v5::MigrateToV5::<Test>::on_runtime_upgrade();
+    posts_v2::MigrateToV2::<Test>::on_runtime_upgrade();
+}
+
+#[test]
+fn posts_migration_should_wait_for_spaces_and_migrate_posts_across_blocks() {
+    ExtBuilder::build().execute_with(|| {
+        insert_old_space(SPACE1);
+        insert_old_space(SPACE2);
+
+        let posts_count = POSTS_PER_BLOCK + 1;
+        for post_id in 1..=posts_count {
+            insert_old_post(post_id, PostExtension::RegularPost, post_id == POST1);
+        }
+        let comment_id = posts_count + 1;
+        insert_old_post(
+            comment_id,
+            PostExtension::Comment(Comment { root_post_id: POST1, parent_id: None }),
+            false,
+        );
+
+        start_migrations();
+        assert!(spaces_v5::is_migration_in_progress::<Test>());
+        assert!(posts_v2::is_migration_in_progress::<Test>());
+
+        // Posts are not migrated before spaces.
+        Posts::on_initialize(1);
+        assert!(Posts::post_by_id(POST1).is_none());
+
+        Spaces::on_initialize(1);
+        assert!(!spaces_v5::is_migration_in_progress::<Test>());
+        assert_eq!(Spaces::on_chain_storage_version(), 5);
+        assert_eq!(Spaces::space_by_id(SPACE1).unwrap().posts_count, 0);
+
+        // Posts are migrated in chunks, and the migration is not restarted by another upgrade.
+        Posts::on_initialize(2);
+        posts_v2::MigrateToV2::<Test>::on_runtime_upgrade();
+        assert!(posts_v2::is_migration_in_progress::<Test>());
+        assert_eq!(Posts::on_chain_storage_version(), 0);
+
+        Posts::on_initialize(3);
+        assert!(!posts_v2::is_migration_in_progress::<Test>());
+        assert_eq!(Posts::on_chain_storage_version(), 2);
+
+        let post = Posts::post_by_id(POST1).unwrap();
+        assert_eq!(post.author, User::Account(ACCOUNT2));
+        assert_eq!(post.deposit.depositor, ACCOUNT1);
+        assert!(Posts::post_by_id(comment_id).is_some());
+
+        let space = Spaces::space_by_id(SPACE1).unwrap();
+        assert_eq!(space.posts_count, posts_count as u32);
+        assert_eq!(space.hidden_posts_count, 1);
+        assert_eq!(Spaces::space_by_id(SPACE2).unwrap().posts_count, 0);
+    });
+}
+
+#[test]
+fn migrations_should_not_start_when_storage_is_up_to_date() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        StorageVersion::new(5).put::<Spaces>();
+        StorageVersion::new(2).put::<Posts>();
+
+        spaces_v5::MigrateToV5::<Test>::on_runtime_upgrade();
+        posts_v2::MigrateToV2::<Test>::on_runtime_upgrade();
+
+        assert!(!spaces_v5::is_migration_in_progress::<Test>());
+        assert!(!posts_v2::is_migration_in_progress::<Test>());
+        assert!(Posts::post_by_id(POST1).is_some());
+    });
+}
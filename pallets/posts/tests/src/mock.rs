@@ -103,6 +103,14 @@ impl pallet_posts::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type MaxCommentDepth = MaxCommentDepth;
     type IsPostBlocked = MockModeration;
+    type PostDeletionHandler = ();
+    type PostVisibilityHandler = ();
+    type PostDeposit = frame_support::traits::ConstU64<0>;
+    type MaxPinnedPostsPerSpace = frame_support::traits::ConstU32<3>;
+    type MaxPostRevisions = frame_support::traits::ConstU32<3>;
+    type MaxScheduledPostsPerBlock = frame_support::traits::ConstU32<3>;
+    type MaxPollOptions = frame_support::traits::ConstU32<3>;
+    type BackerStakesProvider = MockBackerStakes;
     type WeightInfo = ();
 }
 
@@ -117,6 +125,8 @@ parameter_types! {
 impl pallet_roles::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type MaxUsersToProcessPerDeleteRole = MaxUsersToProcessPerDeleteRole;
+    type Currency = Balances;
+    type RoleDeposit = frame_support::traits::ConstU64<0>;
     type SpacePermissionsProvider = Spaces;
     type SpaceFollows = SpaceFollows;
     type IsAccountBlocked = MockModeration;
@@ -129,6 +139,8 @@ impl pallet_profiles::Config for Test {
     type SpacePermissionsProvider = Spaces;
     type SpacesProvider = Spaces;
     type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
 }
 
 impl pallet_spaces::Config for Test {
@@ -138,11 +150,24 @@ impl pallet_spaces::Config for Test {
     type IsAccountBlocked = MockModeration;
     type IsContentBlocked = MockModeration;
     type MaxSpacesPerAccount = ConstU32<100>;
+    type MaxSubspaceDepth = ConstU32<10>;
+    type DomainsProvider = MockEmptyDomainsProvider;
+    type MinHandleLen = ConstU32<5>;
+    type MaxHandleLen = ConstU32<50>;
+    type ProfileManager = ();
+    type CreatorStakingProvider = ();
+    type SpaceDeletionHandler = ();
+    type SpaceSettingsRegistry = Posts;
+    type Currency = Balances;
+    type SpaceDeposit = frame_support::traits::ConstU64<0>;
+    type MaxSpaceCoOwners = frame_support::traits::ConstU32<3>;
     type WeightInfo = ();
 }
 
 impl pallet_space_follows::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type FollowDeposit = frame_support::traits::ConstU64<0>;
+    type InvitationDeposit = frame_support::traits::ConstU64<0>;
     type WeightInfo = ();
 }
 
@@ -0,0 +1,121 @@
+// Copyright (C) DAPPFORCE PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
+//
+// Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
+// Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
+
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResult};
+
+use pallet_posts::Error as PostsError;
+use subsocial_support::{PostId, SpaceId};
+
+use crate::{mock::*, tests_utils::*};
+
+const POST4: PostId = 4;
+
+fn _pin_post(
+    origin: Option<RuntimeOrigin>,
+    space_id: Option<SpaceId>,
+    post_id: Option<PostId>,
+) -> DispatchResult {
+    Posts::pin_post(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        space_id.unwrap_or(SPACE1),
+        post_id.unwrap_or(POST1),
+    )
+}
+
+fn _unpin_post(
+    origin: Option<RuntimeOrigin>,
+    space_id: Option<SpaceId>,
+    post_id: Option<PostId>,
+) -> DispatchResult {
+    Posts::unpin_post(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        space_id.unwrap_or(SPACE1),
+        post_id.unwrap_or(POST1),
+    )
+}
+
+#[test]
+fn pin_post_should_work() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_default_post()); // PostId 2
+        assert_ok!(_create_default_post()); // PostId 3
+
+        assert_ok!(_pin_post(None, None, Some(POST3)));
+        assert_ok!(_pin_post(None, None, Some(POST1)));
+        assert_ok!(_pin_post(None, None, Some(POST2)));
+
+        // Pinned posts should be returned in the order they were pinned
+        assert_eq!(Posts::get_pinned_post_ids(SPACE1), vec![POST3, POST1, POST2]);
+
+        // Unpinning should keep the order of the remaining posts
+        assert_ok!(_unpin_post(None, None, Some(POST3)));
+        assert_eq!(Posts::get_pinned_post_ids(SPACE1), vec![POST1, POST2]);
+    });
+}
+
+#[test]
+fn pin_post_should_fail_when_account_has_no_permission() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_noop!(
+            _pin_post(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None),
+            PostsError::<Test>::NoPermissionToPinPosts
+        );
+    });
+}
+
+#[test]
+fn pin_post_should_fail_when_post_is_a_comment() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_noop!(_pin_post(None, None, Some(POST2)), PostsError::<Test>::CannotPinPost);
+    });
+}
+
+#[test]
+fn pin_post_should_fail_when_post_is_already_pinned() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_pin_post(None, None, None));
+        assert_noop!(_pin_post(None, None, None), PostsError::<Test>::PostIsAlreadyPinned);
+    });
+}
+
+#[test]
+fn pin_post_should_fail_when_too_many_posts_pinned() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_default_post()); // PostId 2
+        assert_ok!(_create_default_post()); // PostId 3
+        assert_ok!(_create_default_post()); // PostId 4
+
+        assert_ok!(_pin_post(None, None, Some(POST1)));
+        assert_ok!(_pin_post(None, None, Some(POST2)));
+        assert_ok!(_pin_post(None, None, Some(POST3)));
+
+        assert_noop!(_pin_post(None, None, Some(POST4)), PostsError::<Test>::TooManyPinnedPosts);
+    });
+}
+
+#[test]
+fn unpin_post_should_fail_when_post_is_not_pinned() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_noop!(_unpin_post(None, None, None), PostsError::<Test>::PostIsNotPinned);
+    });
+}
+
+#[test]
+fn hiding_or_moving_post_should_unpin_it() {
+    ExtBuilder::build_with_post_and_two_spaces().execute_with(|| {
+        assert_ok!(_create_default_post()); // PostId 2
+
+        assert_ok!(_pin_post(None, None, Some(POST1)));
+        assert_ok!(_pin_post(None, None, Some(POST2)));
+
+        assert_ok!(_update_post(None, Some(POST2), Some(post_update(None, None, Some(true)))));
+        assert_eq!(Posts::get_pinned_post_ids(SPACE1), vec![POST1]);
+
+        assert_ok!(_move_post_1_to_space_2());
+        assert!(Posts::get_pinned_post_ids(SPACE1).is_empty());
+        assert!(Posts::get_pinned_post_ids(SPACE2).is_empty());
+    });
+}
@@ -0,0 +1,170 @@
+// Copyright (C) DAPPFORCE PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
+//
+// Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
+// Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
+
+use frame_support::{
+    assert_noop, assert_ok, dispatch::DispatchResult, traits::Hooks, weights::Weight,
+};
+
+use pallet_permissions::{SpacePermission as SP, SpacePermissions};
+use pallet_posts::{Error as PostsError, Event as PostsEvent, PollExtension, PostExtension};
+use pallet_spaces::types::SpaceUpdate;
+use subsocial_support::PostId;
+
+use crate::{mock::*, tests_utils::*};
+
+const VOTING_PERIOD: u32 = 10;
+
+fn extension_poll(options_count: u8) -> PostExtension {
+    PostExtension::Poll(PollExtension { options_count, voting_period: VOTING_PERIOD })
+}
+
+fn _create_poll(options_count: u8) -> DispatchResult {
+    _create_post(None, None, Some(extension_poll(options_count)), None)
+}
+
+fn _vote_in_poll(
+    origin: Option<RuntimeOrigin>,
+    post_id: Option<PostId>,
+    option_index: u8,
+) -> DispatchResult {
+    Posts::vote_in_poll(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        post_id.unwrap_or(POST1),
+        option_index,
+    )
+}
+
+#[test]
+fn create_poll_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_poll(3));
+
+        let post = Posts::post_by_id(POST1).unwrap();
+        assert!(post.is_poll());
+        assert_eq!(Posts::post_ids_by_space_id(SPACE1), vec![POST1]);
+
+        let poll = Posts::poll_by_post_id(POST1).expect("poll should be created");
+        assert_eq!(poll.closes_at, System::block_number() + VOTING_PERIOD as u64);
+        assert_eq!(poll.tallies, vec![0, 0, 0]);
+    });
+}
+
+#[test]
+fn create_poll_should_fail_with_invalid_options_count_or_voting_period() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        // `MaxPollOptions` is 3 in the mock runtime.
+        assert_noop!(_create_poll(1), PostsError::<Test>::InvalidPollOptionsCount);
+        assert_noop!(_create_poll(4), PostsError::<Test>::InvalidPollOptionsCount);
+
+        let ext = PostExtension::Poll(PollExtension { options_count: 2, voting_period: 0 });
+        assert_noop!(
+            _create_post(None, None, Some(ext), None),
+            PostsError::<Test>::PollVotingPeriodIsZero
+        );
+    });
+}
+
+#[test]
+fn vote_in_poll_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_poll(3));
+
+        assert_ok!(_vote_in_poll(None, None, 2));
+        assert_ok!(_vote_in_poll(Some(RuntimeOrigin::signed(ACCOUNT2)), None, 2));
+
+        assert_eq!(Posts::poll_by_post_id(POST1).unwrap().tallies, vec![0, 0, 2]);
+        assert_eq!(Posts::poll_vote_by_account(POST1, ACCOUNT2), Some(2));
+
+        System::assert_last_event(
+            PostsEvent::PollVoted { account: ACCOUNT2, post_id: POST1, option_index: 2 }.into(),
+        );
+    });
+}
+
+#[test]
+fn vote_in_poll_should_fail_when_account_already_voted() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_poll(2));
+        assert_ok!(_vote_in_poll(None, None, 0));
+
+        assert_noop!(_vote_in_poll(None, None, 1), PostsError::<Test>::AlreadyVotedInPoll);
+    });
+}
+
+#[test]
+fn vote_in_poll_should_fail_with_invalid_option_or_not_a_poll() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_noop!(_vote_in_poll(None, None, 0), PostsError::<Test>::NotAPoll);
+
+        assert_ok!(_create_poll(2)); // PostId 2
+        assert_noop!(_vote_in_poll(None, Some(POST2), 2), PostsError::<Test>::InvalidPollOption);
+    });
+}
+
+#[test]
+fn vote_in_poll_should_fail_when_poll_is_closed() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_poll(2));
+
+        System::set_block_number(System::block_number() + VOTING_PERIOD as u64);
+
+        assert_noop!(_vote_in_poll(None, None, 0), PostsError::<Test>::PollIsClosed);
+    });
+}
+
+#[test]
+fn vote_in_poll_should_fail_when_account_has_no_permission() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_poll(2));
+
+        let permissions = SpacePermissions {
+            none: Some(vec![SP::VoteInPolls].into_iter().collect()),
+            ..Default::default()
+        };
+        assert_ok!(_update_space(
+            None,
+            None,
+            Some(SpaceUpdate { permissions: Some(Some(permissions)), ..Default::default() })
+        ));
+
+        assert_noop!(
+            _vote_in_poll(Some(RuntimeOrigin::signed(ACCOUNT2)), None, 0),
+            PostsError::<Test>::NoPermissionToVoteInPolls
+        );
+    });
+}
+
+#[test]
+fn delete_post_should_remove_poll_and_its_votes() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_poll(2));
+        assert_ok!(_vote_in_poll(Some(RuntimeOrigin::signed(ACCOUNT2)), None, 1));
+
+        assert_ok!(Posts::delete_post(RuntimeOrigin::signed(ACCOUNT1), POST1));
+
+        assert!(Posts::poll_by_post_id(POST1).is_none());
+        assert_eq!(post_ids_pending_cleanup(), vec![POST1]);
+
+        Posts::on_idle(System::block_number(), Weight::MAX);
+
+        assert!(Posts::poll_vote_by_account(POST1, ACCOUNT2).is_none());
+        assert!(post_ids_pending_cleanup().is_empty());
+    });
+}
+
+#[test]
+fn on_idle_should_keep_deleted_post_pending_when_there_is_no_spare_weight() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_poll(2));
+        assert_ok!(_vote_in_poll(Some(RuntimeOrigin::signed(ACCOUNT2)), None, 1));
+        assert_ok!(Posts::delete_post(RuntimeOrigin::signed(ACCOUNT1), POST1));
+
+        Posts::on_idle(System::block_number(), Weight::zero());
+
+        assert_eq!(Posts::poll_vote_by_account(POST1, ACCOUNT2), Some(1));
+        assert_eq!(post_ids_pending_cleanup(), vec![POST1]);
+    });
+}
@@ -0,0 +1,67 @@
+// Copyright (C) DAPPFORCE PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
+//
+// Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
+// Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
+
+use frame_support::assert_ok;
+
+use pallet_posts::rpc::{FlatPostOf, PostsFilter};
+use subsocial_support::PostId;
+
+use crate::{mock::*, tests_utils::*};
+
+const POST4: PostId = 4;
+
+fn ids(posts: Vec<FlatPostOf<Test>>) -> Vec<PostId> {
+    posts.into_iter().map(|post| post.id).collect()
+}
+
+#[test]
+fn get_posts_by_space_should_return_newest_posts_first() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_default_post()); // PostId 2
+        assert_ok!(_create_default_post()); // PostId 3
+        assert_ok!(_update_post(None, Some(POST2), Some(post_update(None, None, Some(true)))));
+
+        assert_eq!(
+            ids(Posts::get_posts_by_space(SPACE1, PostsFilter::All, 0, 10)),
+            vec![POST3, POST2, POST1]
+        );
+        assert_eq!(
+            ids(Posts::get_posts_by_space(SPACE1, PostsFilter::Public, 0, 10)),
+            vec![POST3, POST1]
+        );
+        assert_eq!(ids(Posts::get_posts_by_space(SPACE1, PostsFilter::Hidden, 0, 10)), vec![POST2]);
+
+        // The second page of one post.
+        assert_eq!(ids(Posts::get_posts_by_space(SPACE1, PostsFilter::All, 1, 1)), vec![POST2]);
+        assert!(Posts::get_posts_by_space(SPACE1, PostsFilter::All, 3, 10).is_empty());
+    });
+}
+
+#[test]
+fn get_reply_tree_should_return_each_reply_after_its_parent() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_ok!(_create_default_comment()); // PostId 3
+        assert_ok!(_create_comment(None, None, Some(Some(POST2)), None)); // PostId 4
+
+        assert_eq!(ids(Posts::get_reply_tree(POST1)), vec![POST2, POST4, POST3]);
+        assert_eq!(ids(Posts::get_reply_tree(POST2)), vec![POST4]);
+
+        let post = Posts::get_posts_by_ids(vec![POST4]).pop().unwrap();
+        assert_eq!(post.extension, extension_comment(Some(POST2), POST1));
+        assert_eq!(post.created_by_account, ACCOUNT1);
+    });
+}
+
+#[test]
+fn get_posts_by_ids_and_shared_posts_should_skip_missing_posts() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_post(None, None, Some(extension_shared_post(POST1)), None)); // PostId 2
+        assert_ok!(_create_post(None, None, Some(extension_shared_post(POST1)), None)); // PostId 3
+
+        assert_eq!(ids(Posts::get_shared_posts(POST1, 0, 10)), vec![POST3, POST2]);
+        assert_eq!(ids(Posts::get_posts_by_ids(vec![POST3, 100, POST1])), vec![POST3, POST1]);
+    });
+}
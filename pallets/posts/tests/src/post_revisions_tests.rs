@@ -0,0 +1,170 @@
+// Copyright (C) DAPPFORCE PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
+//
+// Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
+// Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
+
+use codec::Encode;
+use frame_support::{assert_ok, dispatch::DispatchResult};
+
+use pallet_posts::{PostRevisionsRetention, PostRevisionsRetentionSetting};
+use subsocial_support::{mock_functions::*, traits::SpaceSetting, Content};
+
+use crate::{mock::*, tests_utils::*};
+
+fn _set_post_revisions_retention(retention: PostRevisionsRetention) -> DispatchResult {
+    Spaces::update_space_setting(
+        RuntimeOrigin::signed(ACCOUNT1),
+        SPACE1,
+        PostRevisionsRetentionSetting::KEY.to_vec(),
+        Some(retention.encode()),
+    )
+}
+
+fn _update_post_content(content: Content) -> DispatchResult {
+    _update_post(None, None, Some(post_update(None, Some(content), None)))
+}
+
+#[test]
+fn update_post_should_keep_revisions_by_default() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_update_post_content(updated_post_content()));
+        assert_ok!(_update_post_content(valid_content_ipfs()));
+        assert_ok!(_update_post_content(another_valid_content_ipfs()));
+        assert_ok!(_update_post_content(post_content_ipfs()));
+
+        // `DEFAULT_POST_REVISIONS_TO_KEEP` is capped by `MaxPostRevisions`, which is 3 in the mock.
+        let revisions = Posts::get_post_revisions(POST1);
+        assert_eq!(revisions.len(), 3);
+        assert_eq!(revisions[0].content, updated_post_content());
+    });
+}
+
+#[test]
+fn update_post_should_not_keep_revisions_when_retention_is_disabled() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_set_post_revisions_retention(PostRevisionsRetention::Disabled));
+
+        assert_ok!(_update_post_content(updated_post_content()));
+
+        assert!(Posts::post_revisions_by_post_id(POST1).is_empty());
+        assert!(Posts::get_post_revisions(POST1).is_empty());
+    });
+}
+
+#[test]
+fn update_post_should_keep_previous_content_as_revision() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_set_post_revisions_retention(PostRevisionsRetention::KeepLatest(2)));
+
+        assert_ok!(_update_post_content(updated_post_content()));
+        // Updates that do not change the content are not recorded.
+        assert_ok!(_update_post(None, None, Some(post_update(None, None, Some(true)))));
+
+        let revisions = Posts::get_post_revisions(POST1);
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].content, post_content_ipfs());
+        assert_eq!(revisions[0].edited_by_account, ACCOUNT1);
+        assert_eq!(Posts::post_by_id(POST1).unwrap().content, updated_post_content());
+    });
+}
+
+#[test]
+fn update_post_should_prune_oldest_revisions() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_set_post_revisions_retention(PostRevisionsRetention::KeepLatest(2)));
+
+        assert_ok!(_update_post_content(updated_post_content()));
+        assert_ok!(_update_post_content(valid_content_ipfs()));
+        assert_ok!(_update_post_content(another_valid_content_ipfs()));
+
+        let contents: Vec<_> = Posts::post_revisions_by_post_id(POST1)
+            .into_iter()
+            .map(|revision| revision.content)
+            .collect();
+        assert_eq!(contents, vec![updated_post_content(), valid_content_ipfs()]);
+    });
+}
+
+#[test]
+fn update_post_should_keep_no_more_than_max_post_revisions() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_set_post_revisions_retention(PostRevisionsRetention::KeepLatest(100)));
+
+        assert_ok!(_update_post_content(updated_post_content()));
+        assert_ok!(_update_post_content(valid_content_ipfs()));
+        assert_ok!(_update_post_content(another_valid_content_ipfs()));
+        assert_ok!(_update_post_content(post_content_ipfs()));
+
+        // `MaxPostRevisions` is 3 in the mock runtime.
+        let revisions = Posts::post_revisions_by_post_id(POST1);
+        assert_eq!(revisions.len(), 3);
+        assert_eq!(revisions[0].content, updated_post_content());
+    });
+}
+
+#[test]
+fn update_post_should_keep_stored_revisions_when_retention_is_disabled() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_set_post_revisions_retention(PostRevisionsRetention::KeepLatest(2)));
+        assert_ok!(_update_post_content(updated_post_content()));
+
+        assert_ok!(_set_post_revisions_retention(PostRevisionsRetention::Disabled));
+        assert_ok!(_update_post_content(valid_content_ipfs()));
+
+        let revisions = Posts::post_revisions_by_post_id(POST1);
+        assert_eq!(revisions.len(), 1);
+        assert_eq!(revisions[0].content, post_content_ipfs());
+    });
+}
+
+#[test]
+fn update_post_should_not_shrink_revisions_when_retention_is_lowered() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_set_post_revisions_retention(PostRevisionsRetention::KeepLatest(3)));
+        assert_ok!(_update_post_content(updated_post_content()));
+        assert_ok!(_update_post_content(valid_content_ipfs()));
+
+        assert_ok!(_set_post_revisions_retention(PostRevisionsRetention::KeepLatest(1)));
+        assert_ok!(_update_post_content(another_valid_content_ipfs()));
+
+        // The oldest revision makes room for the new one.
+        let contents: Vec<_> = Posts::post_revisions_by_post_id(POST1)
+            .into_iter()
+            .map(|revision| revision.content)
+            .collect();
+        assert_eq!(contents, vec![updated_post_content(), valid_content_ipfs()]);
+    });
+}
+
+#[test]
+fn post_revision_should_record_when_its_content_was_set() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_set_post_revisions_retention(PostRevisionsRetention::KeepLatest(2)));
+
+        System::set_block_number(2);
+        assert_ok!(_update_post_content(updated_post_content()));
+        System::set_block_number(3);
+        assert_ok!(_update_post_content(valid_content_ipfs()));
+
+        let revisions = Posts::get_post_revisions(POST1);
+        // The original content was set when the post was created.
+        assert_eq!(revisions[0].edited_by_account, ACCOUNT1);
+        assert_eq!(revisions[0].edited_at_block, 1);
+        // The next content was set by the first edit.
+        assert_eq!(revisions[1].content, updated_post_content());
+        assert_eq!(revisions[1].edited_at_block, 2);
+    });
+}
+
+#[test]
+fn delete_post_should_remove_revisions() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_set_post_revisions_retention(PostRevisionsRetention::KeepLatest(2)));
+        assert_ok!(_update_post_content(updated_post_content()));
+
+        assert_ok!(Posts::delete_post(RuntimeOrigin::signed(ACCOUNT1), POST1));
+
+        assert!(Posts::post_revisions_by_post_id(POST1).is_empty());
+    });
+}
@@ -119,6 +119,10 @@ fn create_post_should_work() {
 
         assert_eq!(post.upvotes_count, 0);
         assert_eq!(post.downvotes_count, 0);
+        assert_eq!(post.replies_count, 0);
+        assert_eq!(post.shares_count, 0);
+
+        assert_eq!(Spaces::space_by_id(SPACE1).unwrap().posts_count, 1);
     });
 }
 
@@ -210,6 +214,28 @@ fn update_post_should_work() {
         assert_eq!(post.space_id, Some(SPACE1));
         assert_eq!(post.content, expected_content_ipfs);
         assert!(post.hidden);
+
+        let space = Spaces::space_by_id(SPACE1).unwrap();
+        assert_eq!(space.posts_count, 1);
+        assert_eq!(space.hidden_posts_count, 1);
+    });
+}
+
+#[test]
+fn update_post_should_update_hidden_posts_count_of_space() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        let hide = post_update(None, None, Some(true));
+        let unhide = post_update(None, None, Some(false));
+
+        assert_ok!(_update_post(None, None, Some(hide.clone())));
+        assert_eq!(Spaces::space_by_id(SPACE1).unwrap().hidden_posts_count, 1);
+
+        // Hiding an already hidden post should not change the counter
+        assert_ok!(_update_post(None, None, Some(hide)));
+        assert_eq!(Spaces::space_by_id(SPACE1).unwrap().hidden_posts_count, 1);
+
+        assert_ok!(_update_post(None, None, Some(unhide)));
+        assert_eq!(Spaces::space_by_id(SPACE1).unwrap().hidden_posts_count, 0);
     });
 }
 
@@ -236,6 +262,10 @@ fn move_post_should_work() {
 
         // Check that there is the post id in the new space
         assert_eq!(Posts::post_ids_by_space_id(expected_new_space_id), vec![moved_post_id]);
+
+        // Check that the post is counted in the new space only
+        assert_eq!(Spaces::space_by_id(old_space_id).unwrap().posts_count, 0);
+        assert_eq!(Spaces::space_by_id(expected_new_space_id).unwrap().posts_count, 1);
     });
 }
 
@@ -247,7 +277,10 @@ fn move_post_should_work_when_space_id_none() {
         let expected_new_space_id = SPACE2;
 
         assert_ok!(_move_post_to_nowhere(moved_post_id));
+        assert_eq!(Spaces::space_by_id(old_space_id).unwrap().posts_count, 0);
+
         assert_ok!(_move_post_1_to_space_2());
+        assert_eq!(Spaces::space_by_id(expected_new_space_id).unwrap().posts_count, 1);
 
         check_if_post_moved_correctly(moved_post_id, expected_new_space_id);
 
@@ -282,6 +315,12 @@ fn move_hidden_post_should_work() {
 
         // Check that there is the post id in the new space
         assert_eq!(Posts::post_ids_by_space_id(expected_new_space_id), vec![moved_post_id]);
+
+        // Check that the hidden post is counted in the new space only
+        let old_space = Spaces::space_by_id(old_space_id).unwrap();
+        assert_eq!((old_space.posts_count, old_space.hidden_posts_count), (0, 0));
+        let new_space = Spaces::space_by_id(expected_new_space_id).unwrap();
+        assert_eq!((new_space.posts_count, new_space.hidden_posts_count), (1, 1));
     });
 }
 
@@ -0,0 +1,181 @@
+// Copyright (C) DAPPFORCE PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
+//
+// Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
+// Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
+
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResult, traits::Hooks};
+use frame_system::pallet_prelude::BlockNumberFor;
+
+use pallet_posts::{Error as PostsError, Event as PostsEvent};
+use subsocial_support::{ModerationError, PostId};
+
+use crate::{mock::*, tests_utils::*};
+
+const PUBLISH_AT: BlockNumberFor<Test> = 5;
+
+fn _schedule_post(
+    origin: Option<RuntimeOrigin>,
+    publish_at: Option<BlockNumberFor<Test>>,
+) -> DispatchResult {
+    Posts::schedule_post(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        SPACE1,
+        post_content_ipfs(),
+        publish_at.unwrap_or(PUBLISH_AT),
+    )
+}
+
+fn _cancel_scheduled_post(
+    origin: Option<RuntimeOrigin>,
+    post_id: Option<PostId>,
+) -> DispatchResult {
+    Posts::cancel_scheduled_post(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        post_id.unwrap_or(POST1),
+    )
+}
+
+fn run_to_publish_block() {
+    System::set_block_number(PUBLISH_AT);
+    Posts::on_initialize(PUBLISH_AT);
+}
+
+#[test]
+fn schedule_post_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_schedule_post(None, None));
+
+        let scheduled_post = Posts::scheduled_post_by_id(POST1).expect("post should be scheduled");
+        assert_eq!(scheduled_post.created.account, ACCOUNT1);
+        assert_eq!(scheduled_post.space_id, SPACE1);
+        assert_eq!(scheduled_post.publish_at, PUBLISH_AT);
+        assert_eq!(Posts::scheduled_post_ids_by_block(PUBLISH_AT).into_inner(), vec![POST1]);
+
+        // The post id is reserved, but the post is not created yet.
+        assert_eq!(Posts::next_post_id(), POST1 + 1);
+        assert!(Posts::post_by_id(POST1).is_none());
+        assert!(Posts::post_ids_by_space_id(SPACE1).is_empty());
+
+        System::assert_last_event(
+            PostsEvent::PostScheduled { account: ACCOUNT1, post_id: POST1, publish_at: PUBLISH_AT }
+                .into(),
+        );
+    });
+}
+
+#[test]
+fn schedule_post_should_fail_when_publish_block_is_not_in_future() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _schedule_post(None, Some(System::block_number())),
+            PostsError::<Test>::PublishBlockIsNotInFuture
+        );
+    });
+}
+
+#[test]
+fn schedule_post_should_fail_when_account_has_no_permission() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _schedule_post(Some(RuntimeOrigin::signed(ACCOUNT2)), None),
+            PostsError::<Test>::NoPermissionToCreatePosts
+        );
+    });
+}
+
+#[test]
+fn schedule_post_should_fail_when_too_many_posts_are_scheduled_at_block() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        // `MaxScheduledPostsPerBlock` is 3 in the mock runtime.
+        for _ in 0..3 {
+            assert_ok!(_schedule_post(None, None));
+        }
+
+        assert_noop!(_schedule_post(None, None), PostsError::<Test>::TooManyPostsScheduledAtBlock);
+        assert_ok!(_schedule_post(None, Some(PUBLISH_AT + 1)));
+    });
+}
+
+#[test]
+fn scheduled_post_should_be_published_at_its_block() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_schedule_post(None, None));
+
+        Posts::on_initialize(PUBLISH_AT - 1);
+        assert!(Posts::post_by_id(POST1).is_none());
+
+        run_to_publish_block();
+
+        let post = Posts::post_by_id(POST1).expect("post should be published");
+        assert_eq!(post.owner, ACCOUNT1);
+        assert_eq!(post.content, post_content_ipfs());
+        assert!(!post.hidden);
+        assert_eq!(post.created.block, PUBLISH_AT);
+
+        assert_eq!(Posts::post_ids_by_space_id(SPACE1), vec![POST1]);
+        assert_eq!(Spaces::space_by_id(SPACE1).unwrap().posts_count, 1);
+        assert!(Posts::scheduled_post_by_id(POST1).is_none());
+        assert!(Posts::scheduled_post_ids_by_block(PUBLISH_AT).is_empty());
+
+        System::assert_last_event(
+            PostsEvent::PostCreated { account: ACCOUNT1, post_id: POST1 }.into(),
+        );
+    });
+}
+
+#[test]
+fn scheduled_post_should_be_dropped_when_author_cannot_create_it_anymore() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_schedule_post(None, None));
+
+        block_account_in_space_1();
+        run_to_publish_block();
+
+        assert!(Posts::post_by_id(POST1).is_none());
+        assert!(Posts::scheduled_post_by_id(POST1).is_none());
+        assert!(Posts::post_ids_by_space_id(SPACE1).is_empty());
+
+        System::assert_last_event(
+            PostsEvent::ScheduledPostDropped {
+                post_id: POST1,
+                error: ModerationError::AccountIsBlocked.into(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn cancel_scheduled_post_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_schedule_post(None, None));
+
+        assert_ok!(_cancel_scheduled_post(None, None));
+
+        assert!(Posts::scheduled_post_by_id(POST1).is_none());
+        assert!(Posts::scheduled_post_ids_by_block(PUBLISH_AT).is_empty());
+        System::assert_last_event(
+            PostsEvent::ScheduledPostCanceled { account: ACCOUNT1, post_id: POST1 }.into(),
+        );
+
+        run_to_publish_block();
+        assert!(Posts::post_by_id(POST1).is_none());
+    });
+}
+
+#[test]
+fn cancel_scheduled_post_should_fail_when_not_author() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_schedule_post(None, None));
+
+        assert_noop!(
+            _cancel_scheduled_post(Some(RuntimeOrigin::signed(ACCOUNT2)), None),
+            PostsError::<Test>::NotAScheduledPostAuthor
+        );
+        assert_noop!(
+            _cancel_scheduled_post(None, Some(POST1 + 1)),
+            PostsError::<Test>::ScheduledPostNotFound
+        );
+    });
+}
@@ -35,6 +35,9 @@ fn share_post_should_work() {
         assert_eq!(shared_post.space_id, Some(SPACE2));
         assert_eq!(shared_post.created.account, ACCOUNT2);
         assert_eq!(shared_post.extension, extension_shared_post(POST1));
+
+        assert_eq!(Posts::post_by_id(POST1).unwrap().shares_count, 1);
+        assert_eq!(Spaces::space_by_id(SPACE2).unwrap().posts_count, 1);
     });
 }
 
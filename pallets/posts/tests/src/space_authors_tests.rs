@@ -0,0 +1,111 @@
+// Copyright (C) DAPPFORCE PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
+//
+// Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
+// Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
+
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResult};
+
+use pallet_permissions::SpacePermission as SP;
+use pallet_posts::{Error as PostsError, Event as PostsEvent, PostExtension};
+use pallet_spaces::Error as SpacesError;
+use subsocial_support::{SpaceId, User};
+
+use crate::{mock::*, tests_utils::*};
+
+fn _create_post_as_space(
+    origin: Option<RuntimeOrigin>,
+    author_space_id: SpaceId,
+    space_id_opt: Option<SpaceId>,
+    extension: PostExtension,
+) -> DispatchResult {
+    Posts::create_post_as_space(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        author_space_id,
+        space_id_opt,
+        extension,
+        post_content_ipfs(),
+    )
+}
+
+fn _create_comment_as_space_1_by_account2() -> DispatchResult {
+    _create_post_as_space(
+        Some(RuntimeOrigin::signed(ACCOUNT2)),
+        SPACE1,
+        None,
+        extension_comment(None, POST1),
+    )
+}
+
+#[test]
+fn create_post_as_space_should_work() {
+    ExtBuilder::build_with_post_and_two_spaces().execute_with(|| {
+        assert_ok!(_create_post_as_space(None, SPACE2, Some(SPACE1), extension_regular_post()));
+
+        let post = Posts::post_by_id(POST2).unwrap();
+        assert_eq!(post.owner, ACCOUNT1);
+        assert_eq!(post.space_id, Some(SPACE1));
+        assert_eq!(post.author, User::Space(SPACE2));
+        assert_eq!(Posts::post_by_id(POST1).unwrap().author, User::Account(ACCOUNT1));
+
+        System::assert_last_event(
+            PostsEvent::PostCreated { account: ACCOUNT1, post_id: POST2 }.into(),
+        );
+    });
+}
+
+#[test]
+fn create_post_as_space_should_fail_without_permission_to_represent_space() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_noop!(
+            _create_comment_as_space_1_by_account2(),
+            SpacesError::<Test>::NoPermissionToRepresentSpace
+        );
+
+        assert_ok!(_create_role(None, None, None, None, Some(vec![SP::RepresentSpaceInternally])));
+        assert_ok!(_grant_role(None, None, None));
+
+        assert_ok!(_create_comment_as_space_1_by_account2());
+        assert_eq!(Posts::post_by_id(POST2).unwrap().author, User::Space(SPACE1));
+
+        // Acting on behalf of a space in another space requires another permission.
+        assert_ok!(_create_space_with_content(another_space_content_ipfs()));
+        assert_noop!(
+            _create_post_as_space(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                SPACE1,
+                Some(SPACE2),
+                extension_regular_post()
+            ),
+            SpacesError::<Test>::NoPermissionToRepresentSpace
+        );
+    });
+}
+
+#[test]
+fn tip_post_should_go_to_owner_of_author_space() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_role(None, None, None, None, Some(vec![SP::RepresentSpaceInternally])));
+        assert_ok!(_grant_role(None, None, None));
+        assert_ok!(_create_comment_as_space_1_by_account2());
+
+        let space_owner_balance = Balances::free_balance(ACCOUNT1);
+        assert_ok!(Posts::tip_post(RuntimeOrigin::signed(ACCOUNT2), POST2, 10));
+
+        assert_eq!(Balances::free_balance(ACCOUNT1), space_owner_balance + 10);
+        System::assert_last_event(
+            PostsEvent::PostTipped {
+                account: ACCOUNT2,
+                post_id: POST2,
+                recipient: ACCOUNT1,
+                amount: 10,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            Posts::tip_post(RuntimeOrigin::signed(ACCOUNT1), POST2, 10),
+            PostsError::<Test>::CannotTipOwnPost
+        );
+    });
+}
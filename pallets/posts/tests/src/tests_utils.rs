@@ -18,11 +18,16 @@ use sp_io::TestExternalities;
 use sp_runtime::BuildStorage;
 
 use pallet_permissions::{SpacePermission as SP, SpacePermission, SpacePermissions};
-use pallet_posts::{Comment, PostExtension, PostUpdate};
+use pallet_posts::{
+    Comment, PostCleanupQueueHead, PostCleanupQueueTail, PostExtension, PostIdsPendingCleanup,
+    PostUpdate,
+};
 use pallet_spaces::types::SpaceUpdate;
 use subsocial_support::{
     mock_functions::*,
-    traits::{IsAccountBlocked, IsContentBlocked, IsPostBlocked, IsSpaceBlocked},
+    traits::{
+        BackerStakesProvider, IsAccountBlocked, IsContentBlocked, IsPostBlocked, IsSpaceBlocked,
+    },
     Content, PostId, SpaceId, User,
 };
 
@@ -256,8 +261,35 @@ pub(crate) fn block_content_in_space_1() {
     );
 }
 
+////// Creator Staking Utils
+
+thread_local! {
+    pub static MOCK_BACKER_STAKES: RefCell<HashMap<(AccountId, SpaceId), Balance>> = RefCell::new(Default::default());
+}
+pub struct MockBackerStakes;
+
+impl BackerStakesProvider<AccountId, Balance> for MockBackerStakes {
+    fn get_backer_stake(backer: &AccountId, creator_id: SpaceId) -> Balance {
+        MOCK_BACKER_STAKES
+            .with(|stakes| stakes.borrow().get(&(*backer, creator_id)).copied().unwrap_or_default())
+    }
+}
+
+pub(crate) fn set_backer_stake(backer: AccountId, creator_id: SpaceId, stake: Balance) {
+    MOCK_BACKER_STAKES.with(|stakes| {
+        stakes.borrow_mut().insert((backer, creator_id), stake);
+    });
+}
+
 ///////////// Space Utils
 
+/// The ids of deleted posts waiting for a cleanup, in the order of deletion.
+pub(crate) fn post_ids_pending_cleanup() -> Vec<PostId> {
+    (PostCleanupQueueHead::<Test>::get()..PostCleanupQueueTail::<Test>::get())
+        .filter_map(PostIdsPendingCleanup::<Test>::get)
+        .collect()
+}
+
 pub(crate) fn space_content_ipfs() -> Content {
     Content::IPFS(b"bafyreib3mgbou4xln42qqcgj6qlt3cif35x4ribisxgq7unhpun525l54e".to_vec())
 }
@@ -0,0 +1,107 @@
+// Copyright (C) DAPPFORCE PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
+//
+// Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
+// Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
+
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResult};
+use sp_runtime::TokenError;
+
+use pallet_posts::{Error as PostsError, Event as PostsEvent};
+use subsocial_support::{ModerationError, PostId};
+
+use crate::{mock::*, tests_utils::*};
+
+fn _tip_post(
+    origin: Option<RuntimeOrigin>,
+    post_id: Option<PostId>,
+    amount: Balance,
+) -> DispatchResult {
+    Posts::tip_post(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT2)),
+        post_id.unwrap_or(POST1),
+        amount,
+    )
+}
+
+#[test]
+fn tip_post_should_work() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        let owner_balance = Balances::free_balance(ACCOUNT1);
+        let tipper_balance = Balances::free_balance(ACCOUNT2);
+
+        assert_ok!(_tip_post(None, None, 10));
+        assert_ok!(_tip_post(None, None, 5));
+
+        assert_eq!(Posts::tips_total_by_post_id(POST1), 15);
+        assert_eq!(Balances::free_balance(ACCOUNT1), owner_balance + 15);
+        assert_eq!(Balances::free_balance(ACCOUNT2), tipper_balance - 15);
+
+        System::assert_last_event(
+            PostsEvent::PostTipped {
+                account: ACCOUNT2,
+                post_id: POST1,
+                recipient: ACCOUNT1,
+                amount: 5,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn tip_post_should_work_for_comments() {
+    ExtBuilder::build_with_comment().execute_with(|| {
+        assert_ok!(_tip_post(None, Some(POST2), 10));
+
+        assert_eq!(Posts::tips_total_by_post_id(POST2), 10);
+        assert_eq!(Posts::tips_total_by_post_id(POST1), 0);
+    });
+}
+
+#[test]
+fn tip_post_should_fail_with_zero_amount_or_own_post() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_noop!(_tip_post(None, None, 0), PostsError::<Test>::TipAmountIsZero);
+        assert_noop!(
+            _tip_post(Some(RuntimeOrigin::signed(ACCOUNT1)), None, 10),
+            PostsError::<Test>::CannotTipOwnPost
+        );
+        assert_noop!(_tip_post(None, Some(POST2), 10), PostsError::<Test>::PostNotFound);
+    });
+}
+
+#[test]
+fn tip_post_should_fail_when_tipper_has_not_enough_balance() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_noop!(
+            _tip_post(None, None, Balances::free_balance(ACCOUNT2) + 1),
+            TokenError::FundsUnavailable
+        );
+    });
+}
+
+#[test]
+fn tip_post_should_fail_when_post_owner_is_blocked() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        block_account_in_space_1();
+
+        assert_noop!(_tip_post(None, None, 10), ModerationError::AccountIsBlocked);
+    });
+}
+
+#[test]
+fn get_top_tipped_posts_should_return_most_tipped_posts_first() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_default_post()); // PostId 2
+        assert_ok!(_create_default_post()); // PostId 3
+        assert_ok!(_create_default_post()); // PostId 4
+
+        assert_ok!(_tip_post(None, Some(POST1), 5));
+        assert_ok!(_tip_post(None, Some(3), 20));
+        assert_ok!(_tip_post(None, Some(4), 5));
+
+        assert_eq!(Posts::get_top_tipped_posts(SPACE1, 10), vec![(3, 20), (POST1, 5), (4, 5)]);
+        assert_eq!(Posts::get_top_tipped_posts(SPACE1, 1), vec![(3, 20)]);
+    });
+}
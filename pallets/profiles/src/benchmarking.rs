@@ -16,6 +16,7 @@ fn create_space<T: Config>(
     owner: &T::AccountId,
     content: Content,
 ) -> Result<SpaceId, DispatchError> {
+    T::BenchmarkHelper::fund_account(owner);
     let space_id = T::SpacesProvider::create_space(owner, content)?;
     Ok(space_id)
 }
@@ -40,6 +41,7 @@ benchmarks! {
 
     create_space_as_profile {
         let caller: T::AccountId = whitelisted_caller();
+        T::BenchmarkHelper::fund_account(&caller);
         let content = Content::default();
     }: _(RawOrigin::Signed(caller.clone()), content)
     verify {
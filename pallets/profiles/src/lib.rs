@@ -21,6 +21,18 @@ pub mod weights;
 
 pub use crate::weights::WeightInfo;
 
+/// Prepares the state required by the benchmarks of this pallet.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId> {
+    /// Make sure that a given account can pay a deposit for creating a space.
+    fn fund_account(account: &AccountId);
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<AccountId> BenchmarkHelper<AccountId> for () {
+    fn fund_account(_account: &AccountId) {}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -49,6 +61,9 @@ pub mod pallet {
         type SpacesProvider: SpacesProvider<Self::AccountId, SpaceId>;
 
         type WeightInfo: WeightInfo;
+
+        #[cfg(feature = "runtime-benchmarks")]
+        type BenchmarkHelper: BenchmarkHelper<Self::AccountId>;
     }
 
     #[pallet::pallet]
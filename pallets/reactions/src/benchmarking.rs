@@ -10,16 +10,24 @@
 
 use super::*;
 use frame_benchmarking::{benchmarks, whitelisted_caller};
-use frame_support::dispatch::DispatchError;
+use frame_support::{dispatch::DispatchError, traits::Currency};
 use frame_system::RawOrigin;
 use pallet_posts::{Post, PostExtension};
 use pallet_spaces::types::Space;
+use sp_runtime::traits::Bounded;
 use sp_std::vec;
 use subsocial_support::Content;
 
 fn create_dummy_space<T: Config>(
     origin: RawOrigin<T::AccountId>,
 ) -> Result<Space<T>, DispatchError> {
+    if let RawOrigin::Signed(owner) = &origin {
+        <T as pallet_spaces::Config>::Currency::make_free_balance_be(
+            owner,
+            BalanceOf::<T>::max_value(),
+        );
+    }
+
     let space_id = pallet_spaces::NextSpaceId::<T>::get();
 
     pallet_spaces::Pallet::<T>::create_space(origin.clone().into(), Content::None, None)?;
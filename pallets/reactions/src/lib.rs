@@ -18,19 +18,26 @@ use sp_std::prelude::*;
 
 use pallet_permissions::SpacePermission;
 use pallet_posts::{Pallet as Posts, PostById};
-use pallet_spaces::Pallet as Spaces;
+use pallet_spaces::{
+    types::{BalanceOf, StorageDepositOf},
+    Pallet as Spaces,
+};
 use subsocial_support::{
-    new_who_and_when, remove_from_vec, traits::IsAccountBlocked, ModerationError, PostId,
-    WhoAndWhenOf,
+    new_who_and_when, remove_from_vec,
+    traits::{IsAccountBlocked, PostDeletionHandler},
+    ModerationError, PostId, SpaceId, StorageDeposit, User, WhoAndWhenOf,
 };
 
 pub use pallet::*;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migration;
 pub mod weights;
 
 // pub mod rpc;
 
+pub const LOG_TARGET: &str = "runtime::reactions";
+
 pub type ReactionId = u64;
 
 #[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, TypeInfo)]
@@ -56,6 +63,13 @@ pub struct Reaction<T: Config> {
 
     pub created: WhoAndWhenOf<T>,
     pub kind: ReactionKind,
+
+    /// The author of a reaction: either the account that left it or a space on behalf of which
+    /// it was left.
+    pub author: User<T::AccountId>,
+
+    /// The amount reserved for storing this reaction, returned when the reaction is deleted.
+    pub deposit: StorageDepositOf<T>,
 }
 
 pub const FIRST_REACTION_ID: u64 = 1;
@@ -74,11 +88,19 @@ pub mod pallet {
         /// The overarching event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+        /// The amount reserved from an account for storing its reaction.
+        #[pallet::constant]
+        type ReactionDeposit: Get<BalanceOf<Self>>;
+
         type WeightInfo: WeightInfo;
     }
 
+    /// The current storage version
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
     #[pallet::without_storage_info]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(PhantomData<T>);
 
     #[pallet::type_value]
@@ -106,6 +128,12 @@ pub mod pallet {
     pub type PostReactionIdByAccount<T: Config> =
         StorageMap<_, Blake2_128Concat, (T::AccountId, PostId), ReactionId, ValueQuery>;
 
+    /// The reaction that a space left on a post via one of its representatives.
+    #[pallet::storage]
+    #[pallet::getter(fn post_reaction_id_by_space)]
+    pub type PostReactionIdBySpace<T: Config> =
+        StorageMap<_, Twox64Concat, (SpaceId, PostId), ReactionId>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -156,6 +184,18 @@ pub mod pallet {
         NoPermissionToUpvote,
         /// User has no permission to downvote posts/comments in this space.
         NoPermissionToDownvote,
+
+        /// Space has already reacted to this post/comment.
+        SpaceAlreadyReacted,
+        /// There is no reaction by space on this post/comment.
+        ReactionBySpaceNotFound,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+            migration::v1::migrate_next_reactions::<T>()
+        }
     }
 
     #[pallet::call]
@@ -169,48 +209,12 @@ pub mod pallet {
         ) -> DispatchResult {
             let owner = ensure_signed(origin)?;
 
-            let post = &mut Posts::require_post(post_id)?;
             ensure!(
                 !<PostReactionIdByAccount<T>>::contains_key((owner.clone(), post_id)),
                 Error::<T>::AccountAlreadyReacted
             );
 
-            let space = post.get_space()?;
-            ensure!(!space.hidden, Error::<T>::CannotReactWhenSpaceHidden);
-            ensure!(
-                Posts::<T>::is_root_post_visible(post_id)?,
-                Error::<T>::CannotReactWhenPostHidden
-            );
-
-            ensure!(
-                T::IsAccountBlocked::is_allowed_account(owner.clone(), space.id),
-                ModerationError::AccountIsBlocked
-            );
-
-            match kind {
-                ReactionKind::Upvote => {
-                    Spaces::ensure_account_has_space_permission(
-                        owner.clone(),
-                        &post.get_space()?,
-                        SpacePermission::Upvote,
-                        Error::<T>::NoPermissionToUpvote.into(),
-                    )?;
-                    post.inc_upvotes();
-                },
-                ReactionKind::Downvote => {
-                    Spaces::ensure_account_has_space_permission(
-                        owner.clone(),
-                        &post.get_space()?,
-                        SpacePermission::Downvote,
-                        Error::<T>::NoPermissionToDownvote.into(),
-                    )?;
-                    post.inc_downvotes();
-                },
-            }
-
-            PostById::<T>::insert(post_id, post.clone());
-            let reaction_id = Self::insert_new_reaction(owner.clone(), kind);
-            ReactionIdsByPostId::<T>::mutate(post.id, |ids| ids.push(reaction_id));
+            let reaction_id = Self::do_create_post_reaction(owner.clone(), post_id, kind, None)?;
             PostReactionIdByAccount::<T>::insert((owner.clone(), post_id), reaction_id);
 
             Self::deposit_event(Event::PostReactionCreated {
@@ -240,7 +244,7 @@ pub mod pallet {
             let mut reaction = Self::require_reaction(reaction_id)?;
             let post = &mut Posts::require_post(post_id)?;
 
-            ensure!(owner == reaction.created.account, Error::<T>::NotReactionOwner);
+            ensure!(reaction.author == User::Account(owner.clone()), Error::<T>::NotReactionOwner);
             ensure!(reaction.kind != new_kind, Error::<T>::SameReaction);
 
             if let Some(space_id) = post.try_get_space_id() {
@@ -249,6 +253,7 @@ pub mod pallet {
                     ModerationError::AccountIsBlocked
                 );
             }
+            Posts::<T>::ensure_can_access_post(&owner, post)?;
 
             reaction.kind = new_kind;
 
@@ -291,31 +296,18 @@ pub mod pallet {
 
             // TODO extract Self::require_reaction(reaction_id)?;
             let reaction = Self::require_reaction(reaction_id)?;
-            let post = &mut Posts::require_post(post_id)?;
 
-            ensure!(owner == reaction.created.account, Error::<T>::NotReactionOwner);
-            if let Some(space_id) = post.try_get_space_id() {
-                ensure!(
-                    T::IsAccountBlocked::is_allowed_account(owner.clone(), space_id),
-                    ModerationError::AccountIsBlocked
-                );
-            }
-
-            match reaction.kind {
-                ReactionKind::Upvote => post.dec_upvotes(),
-                ReactionKind::Downvote => post.dec_downvotes(),
-            }
+            ensure!(reaction.author == User::Account(owner.clone()), Error::<T>::NotReactionOwner);
 
-            PostById::<T>::insert(post_id, post.clone());
-            ReactionById::<T>::remove(reaction_id);
-            ReactionIdsByPostId::<T>::mutate(post.id, |ids| remove_from_vec(ids, reaction_id));
+            let reaction_kind = reaction.kind;
+            Self::do_delete_post_reaction(&owner, post_id, reaction, None)?;
             PostReactionIdByAccount::<T>::remove((owner.clone(), post_id));
 
             Self::deposit_event(Event::PostReactionDeleted {
                 account: owner,
                 post_id,
                 reaction_id,
-                reaction_kind: reaction.kind,
+                reaction_kind,
             });
             Ok(())
         }
@@ -342,8 +334,13 @@ pub mod pallet {
             let new_who_and_when =
                 WhoAndWhen { account, block: frame_system::Pallet::<T>::block_number(), time };
 
-            let reaction =
-                Reaction { id: reaction_id, created: new_who_and_when, kind: reaction_kind };
+            let reaction = Reaction {
+                id: reaction_id,
+                created: new_who_and_when,
+                kind: reaction_kind,
+                author: User::Account(who.clone()),
+                deposit: StorageDeposit::free(who.clone()),
+            };
             ReactionById::<T>::insert(reaction_id, reaction);
             ReactionIdsByPostId::<T>::mutate(post_id, |ids| ids.push(reaction_id));
             PostReactionIdByAccount::<T>::insert((who.clone(), post_id), reaction_id);
@@ -372,7 +369,7 @@ pub mod pallet {
         ) -> DispatchResultWithPostInfo {
             ensure_root(origin)?;
 
-            ensure!(Self::reaction_by_id(&reaction_id).is_some(), Error::<T>::ReactionNotFound);
+            let reaction = Self::require_reaction(reaction_id)?;
 
             let post_reaction_id_by_account =
                 Self::post_reaction_id_by_account((who.clone(), post_id));
@@ -386,6 +383,7 @@ pub mod pallet {
                 remove_from_vec(ids, reaction_id);
                 Ok(Pays::No.into())
             })?;
+            reaction.deposit.release::<<T as pallet_spaces::Config>::Currency>();
             ReactionById::<T>::remove(reaction_id);
             PostReactionIdByAccount::<T>::remove((who, post_id));
 
@@ -406,13 +404,83 @@ pub mod pallet {
             NextReactionId::<T>::put(reaction_id);
             Ok(Pays::No.into())
         }
+
+        /// React to a post on behalf of a space, if the caller is allowed to represent that space
+        /// in the space of the post.
+        #[pallet::call_index(6)]
+        #[pallet::weight(
+            < T as Config >::WeightInfo::create_post_reaction()
+                .saturating_add(T::DbWeight::get().reads(2))
+        )]
+        pub fn create_post_reaction_as_space(
+            origin: OriginFor<T>,
+            author_space_id: SpaceId,
+            post_id: PostId,
+            kind: ReactionKind,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+
+            ensure!(
+                !PostReactionIdBySpace::<T>::contains_key((author_space_id, post_id)),
+                Error::<T>::SpaceAlreadyReacted
+            );
+
+            let reaction_id =
+                Self::do_create_post_reaction(owner.clone(), post_id, kind, Some(author_space_id))?;
+            PostReactionIdBySpace::<T>::insert((author_space_id, post_id), reaction_id);
+
+            Self::deposit_event(Event::PostReactionCreated {
+                account: owner,
+                post_id,
+                reaction_id,
+                reaction_kind: kind,
+            });
+            Ok(())
+        }
+
+        /// Remove a reaction that a space left on a post. Any account that is allowed to represent
+        /// the space in the space of the post can do it.
+        #[pallet::call_index(7)]
+        #[pallet::weight(
+            < T as Config >::WeightInfo::delete_post_reaction()
+                .saturating_add(T::DbWeight::get().reads_writes(2, 1))
+        )]
+        pub fn delete_post_reaction_as_space(
+            origin: OriginFor<T>,
+            author_space_id: SpaceId,
+            post_id: PostId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let reaction_id = Self::post_reaction_id_by_space((author_space_id, post_id))
+                .ok_or(Error::<T>::ReactionBySpaceNotFound)?;
+            let reaction = Self::require_reaction(reaction_id)?;
+
+            let reaction_kind = reaction.kind;
+            Self::do_delete_post_reaction(&who, post_id, reaction, Some(author_space_id))?;
+            PostReactionIdBySpace::<T>::remove((author_space_id, post_id));
+
+            Self::deposit_event(Event::PostReactionDeleted {
+                account: who,
+                post_id,
+                reaction_id,
+                reaction_kind,
+            });
+            Ok(())
+        }
     }
 }
 
 impl<T: Config> Pallet<T> {
-    pub fn insert_new_reaction(account: T::AccountId, kind: ReactionKind) -> ReactionId {
+    pub fn insert_new_reaction(
+        account: T::AccountId,
+        kind: ReactionKind,
+        author: User<T::AccountId>,
+        deposit: StorageDepositOf<T>,
+    ) -> ReactionId {
         let id = Self::next_reaction_id();
-        let reaction: Reaction<T> = Reaction { id, created: new_who_and_when::<T>(account), kind };
+        let reaction: Reaction<T> =
+            Reaction { id, created: new_who_and_when::<T>(account), kind, author, deposit };
 
         ReactionById::<T>::insert(id, reaction);
         NextReactionId::<T>::mutate(|n| {
@@ -422,8 +490,141 @@ impl<T: Config> Pallet<T> {
         id
     }
 
+    /// Check that a reaction can be left on a post, update the post's counters, reserve
+    /// the deposit and store the new reaction.
+    ///
+    /// If `author_space_opt` is provided, the reaction is left on behalf of that space,
+    /// which is recorded as its author, but the deposit is still reserved from `owner`.
+    pub(crate) fn do_create_post_reaction(
+        owner: T::AccountId,
+        post_id: PostId,
+        kind: ReactionKind,
+        author_space_opt: Option<SpaceId>,
+    ) -> Result<ReactionId, DispatchError> {
+        let post = &mut Posts::require_post(post_id)?;
+
+        let space = post.get_space()?;
+        ensure!(!space.hidden, Error::<T>::CannotReactWhenSpaceHidden);
+        space.ensure_not_archived()?;
+        ensure!(Posts::<T>::is_root_post_visible(post_id)?, Error::<T>::CannotReactWhenPostHidden);
+
+        ensure!(
+            T::IsAccountBlocked::is_allowed_account(owner.clone(), space.id),
+            ModerationError::AccountIsBlocked
+        );
+        if let Some(author_space_id) = author_space_opt {
+            Spaces::<T>::ensure_can_represent_space(&owner, author_space_id, space.id)?;
+        }
+        Posts::<T>::ensure_can_access_post(&owner, post)?;
+
+        match kind {
+            ReactionKind::Upvote => {
+                Spaces::ensure_account_has_space_permission(
+                    owner.clone(),
+                    &space,
+                    SpacePermission::Upvote,
+                    Error::<T>::NoPermissionToUpvote.into(),
+                )?;
+                post.inc_upvotes();
+            },
+            ReactionKind::Downvote => {
+                Spaces::ensure_account_has_space_permission(
+                    owner.clone(),
+                    &space,
+                    SpacePermission::Downvote,
+                    Error::<T>::NoPermissionToDownvote.into(),
+                )?;
+                post.inc_downvotes();
+            },
+        }
+
+        let deposit = StorageDeposit::reserve::<<T as pallet_spaces::Config>::Currency>(
+            owner.clone(),
+            T::ReactionDeposit::get(),
+        )?;
+
+        let author = author_space_opt.map_or_else(|| User::Account(owner.clone()), User::Space);
+
+        PostById::<T>::insert(post_id, post.clone());
+        let reaction_id = Self::insert_new_reaction(owner, kind, author, deposit);
+        ReactionIdsByPostId::<T>::mutate(post.id, |ids| ids.push(reaction_id));
+
+        Ok(reaction_id)
+    }
+
+    /// Check that `who` can remove a reaction from a post, update the post's counters,
+    /// release the deposit and remove the reaction.
+    ///
+    /// If `author_space_opt` is provided, `who` must be able to represent that space.
+    pub(crate) fn do_delete_post_reaction(
+        who: &T::AccountId,
+        post_id: PostId,
+        reaction: Reaction<T>,
+        author_space_opt: Option<SpaceId>,
+    ) -> DispatchResult {
+        let post = &mut Posts::require_post(post_id)?;
+
+        if let Some(space_id) = post.try_get_space_id() {
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(who.clone(), space_id),
+                ModerationError::AccountIsBlocked
+            );
+        }
+        if let Some(author_space_id) = author_space_opt {
+            Spaces::<T>::ensure_can_represent_space(who, author_space_id, post.get_space()?.id)?;
+        }
+
+        match reaction.kind {
+            ReactionKind::Upvote => post.dec_upvotes(),
+            ReactionKind::Downvote => post.dec_downvotes(),
+        }
+
+        reaction.deposit.release::<<T as pallet_spaces::Config>::Currency>();
+
+        PostById::<T>::insert(post_id, post.clone());
+        ReactionById::<T>::remove(reaction.id);
+        ReactionIdsByPostId::<T>::mutate(post.id, |ids| remove_from_vec(ids, reaction.id));
+
+        Ok(())
+    }
+
     /// Get `Reaction` by id from the storage or return `ReactionNotFound` error.
     pub fn require_reaction(reaction_id: ReactionId) -> Result<Reaction<T>, DispatchError> {
         Ok(Self::reaction_by_id(reaction_id).ok_or(Error::<T>::ReactionNotFound)?)
     }
 }
+
+impl<T: Config> PostDeletionHandler for Pallet<T> {
+    /// Remove the reactions of a deleted post one by one, releasing their deposits.
+    fn cleanup_deleted_post(post_id: PostId, limit: u32) -> u32 {
+        let mut reaction_ids = ReactionIdsByPostId::<T>::get(post_id);
+        let mut removed = 0;
+
+        while removed < limit {
+            let reaction_id = match reaction_ids.pop() {
+                Some(reaction_id) => reaction_id,
+                None => break,
+            };
+
+            if let Some(reaction) = ReactionById::<T>::take(reaction_id) {
+                reaction.deposit.release::<<T as pallet_spaces::Config>::Currency>();
+                match reaction.author {
+                    User::Account(account) =>
+                        PostReactionIdByAccount::<T>::remove((account, post_id)),
+                    User::Space(space_id) =>
+                        PostReactionIdBySpace::<T>::remove((space_id, post_id)),
+                }
+            }
+
+            removed += 1;
+        }
+
+        if reaction_ids.is_empty() {
+            ReactionIdsByPostId::<T>::remove(post_id);
+        } else {
+            ReactionIdsByPostId::<T>::insert(post_id, reaction_ids);
+        }
+
+        removed
+    }
+}
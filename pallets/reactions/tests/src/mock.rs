@@ -92,6 +92,14 @@ impl pallet_balances::Config for Test {
     type MaxHolds = ();
 }
 
+parameter_types! {
+    pub static SpaceDeposit: Balance = 0;
+    pub static PostDeposit: Balance = 0;
+    pub static ReactionDeposit: Balance = 0;
+    pub static RoleDeposit: Balance = 0;
+    pub static FollowDeposit: Balance = 0;
+}
+
 parameter_types! {
     pub const MaxCommentDepth: u32 = 10;
 }
@@ -100,6 +108,14 @@ impl pallet_posts::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type MaxCommentDepth = MaxCommentDepth;
     type IsPostBlocked = ();
+    type PostDeletionHandler = Reactions;
+    type PostVisibilityHandler = ();
+    type PostDeposit = PostDeposit;
+    type MaxPinnedPostsPerSpace = frame_support::traits::ConstU32<3>;
+    type MaxPostRevisions = frame_support::traits::ConstU32<3>;
+    type MaxScheduledPostsPerBlock = frame_support::traits::ConstU32<3>;
+    type MaxPollOptions = frame_support::traits::ConstU32<3>;
+    type BackerStakesProvider = ();
     type WeightInfo = ();
 }
 
@@ -114,6 +130,8 @@ parameter_types! {
 impl pallet_roles::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type MaxUsersToProcessPerDeleteRole = MaxUsersToProcessPerDeleteRole;
+    type Currency = Balances;
+    type RoleDeposit = RoleDeposit;
     type SpacePermissionsProvider = Spaces;
     type SpaceFollows = SpaceFollows;
     type IsAccountBlocked = ();
@@ -128,15 +146,29 @@ impl pallet_spaces::Config for Test {
     type IsAccountBlocked = ();
     type IsContentBlocked = ();
     type MaxSpacesPerAccount = ConstU32<100>;
+    type MaxSubspaceDepth = ConstU32<10>;
+    type DomainsProvider = ();
+    type MinHandleLen = ConstU32<5>;
+    type MaxHandleLen = ConstU32<50>;
+    type ProfileManager = ();
+    type CreatorStakingProvider = ();
+    type SpaceDeletionHandler = ();
+    type SpaceSettingsRegistry = Posts;
+    type Currency = Balances;
+    type SpaceDeposit = SpaceDeposit;
+    type MaxSpaceCoOwners = frame_support::traits::ConstU32<3>;
     type WeightInfo = ();
 }
 
 impl pallet_space_follows::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type FollowDeposit = FollowDeposit;
+    type InvitationDeposit = frame_support::traits::ConstU64<0>;
     type WeightInfo = ();
 }
 
 impl pallet_reactions::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type ReactionDeposit = ReactionDeposit;
     type WeightInfo = ();
 }
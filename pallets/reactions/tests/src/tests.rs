@@ -4,10 +4,19 @@
 // Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
 // Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
 
-use frame_support::{assert_noop, assert_ok};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+    weights::Weight,
+};
 
-use pallet_posts::Error as PostsError;
-use pallet_reactions::Error as ReactionsError;
+use pallet_posts::{Error as PostsError, PostAccessPolicy};
+use pallet_reactions::{
+    migration::v1::{self as reactions_v1, OldReaction, OldReactionById, REACTIONS_PER_BLOCK},
+    Error as ReactionsError, ReactionKind,
+};
+use pallet_spaces::Error as SpacesError;
+use subsocial_support::{new_who_and_when, User};
 
 use crate::{mock::*, tests_utils::*};
 
@@ -89,6 +98,15 @@ fn create_post_reaction_should_fail_when_trying_to_react_in_hidden_space() {
     });
 }
 
+#[test]
+fn create_post_reaction_should_fail_when_trying_to_react_in_archived_space() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(Spaces::set_space_archived(RuntimeOrigin::signed(ACCOUNT1), SPACE1, true));
+
+        assert_noop!(_create_default_post_reaction(), SpacesError::<Test>::SpaceIsArchived);
+    });
+}
+
 #[test]
 fn create_post_reaction_should_fail_when_trying_to_react_on_hidden_post() {
     ExtBuilder::build_with_post().execute_with(|| {
@@ -101,3 +119,175 @@ fn create_post_reaction_should_fail_when_trying_to_react_on_hidden_post() {
         );
     });
 }
+
+#[test]
+fn create_post_reaction_should_fail_without_access_to_post() {
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(Posts::set_post_access_policy(
+            RuntimeOrigin::signed(ACCOUNT1),
+            POST1,
+            Some(PostAccessPolicy::CreatorBackers { min_stake: 10 })
+        ));
+
+        assert_noop!(
+            _create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None),
+            PostsError::<Test>::NoAccessToPost
+        );
+        // The post owner always has access to their post.
+        assert_ok!(_create_default_post_reaction());
+    });
+}
+
+#[test]
+fn create_post_reaction_should_reserve_deposit() {
+    ReactionDeposit::set(5);
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None));
+
+        let reaction = Reactions::reaction_by_id(REACTION1).unwrap();
+        assert_eq!(reaction.deposit.depositor, ACCOUNT2);
+        assert_eq!(reaction.deposit.deposit, 5);
+        assert_eq!(Balances::reserved_balance(ACCOUNT2), 5);
+    });
+}
+
+#[test]
+fn delete_post_reaction_should_release_deposit() {
+    ReactionDeposit::set(5);
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None));
+        assert_ok!(_delete_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, REACTION1));
+
+        assert_eq!(Balances::reserved_balance(ACCOUNT2), 0);
+    });
+}
+
+#[test]
+fn force_remove_post_should_release_deposits_of_post_and_its_reactions() {
+    PostDeposit::set(10);
+    ReactionDeposit::set(5);
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None));
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), 10);
+
+        assert_ok!(Posts::force_remove_post(RuntimeOrigin::root(), POST1));
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), 0);
+
+        Posts::on_idle(System::block_number(), Weight::MAX);
+
+        assert_eq!(Balances::reserved_balance(ACCOUNT2), 0);
+        assert!(Reactions::reaction_ids_by_post_id(POST1).is_empty());
+    });
+}
+
+#[test]
+fn delete_post_should_remove_reactions_in_on_idle() {
+    ReactionDeposit::set(5);
+    ExtBuilder::build_with_post().execute_with(|| {
+        assert_ok!(_create_post_reaction(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None));
+        assert_ok!(Posts::delete_post(RuntimeOrigin::signed(ACCOUNT1), POST1));
+
+        assert_eq!(Reactions::reaction_ids_by_post_id(POST1), vec![REACTION1]);
+        assert_eq!(Balances::reserved_balance(ACCOUNT2), 5);
+
+        Posts::on_idle(System::block_number(), Weight::MAX);
+
+        assert!(Reactions::reaction_ids_by_post_id(POST1).is_empty());
+        assert!(Reactions::reaction_by_id(REACTION1).is_none());
+        assert_eq!(Balances::reserved_balance(ACCOUNT2), 0);
+    });
+}
+
+#[test]
+fn create_post_reaction_as_space_should_work() {
+    ExtBuilder::build_with_reacted_post_and_two_spaces().execute_with(|| {
+        assert_ok!(Reactions::create_post_reaction_as_space(
+            RuntimeOrigin::signed(ACCOUNT1),
+            SPACE2,
+            POST1,
+            reaction_upvote()
+        ));
+
+        assert_eq!(Reactions::post_reaction_id_by_space((SPACE2, POST1)), Some(REACTION2));
+        let reaction = Reactions::reaction_by_id(REACTION2).unwrap();
+        assert_eq!(reaction.author, User::Space(SPACE2));
+        assert_eq!(reaction.created.account, ACCOUNT1);
+        assert_eq!(Posts::post_by_id(POST1).unwrap().upvotes_count, 2);
+
+        assert_noop!(
+            Reactions::create_post_reaction_as_space(
+                RuntimeOrigin::signed(ACCOUNT1),
+                SPACE2,
+                POST1,
+                reaction_downvote()
+            ),
+            ReactionsError::<Test>::SpaceAlreadyReacted
+        );
+        // A reaction of a space cannot be removed as a reaction of an account.
+        assert_noop!(
+            _delete_post_reaction(None, None, REACTION2),
+            ReactionsError::<Test>::NotReactionOwner
+        );
+
+        assert_ok!(Reactions::delete_post_reaction_as_space(
+            RuntimeOrigin::signed(ACCOUNT1),
+            SPACE2,
+            POST1
+        ));
+
+        assert_eq!(Reactions::post_reaction_id_by_space((SPACE2, POST1)), None);
+        assert!(Reactions::reaction_by_id(REACTION2).is_none());
+        assert_eq!(Reactions::reaction_ids_by_post_id(POST1), vec![REACTION1]);
+        assert_eq!(Posts::post_by_id(POST1).unwrap().upvotes_count, 1);
+    });
+}
+
+#[test]
+fn create_post_reaction_as_space_should_fail_without_permission_to_represent_space() {
+    ExtBuilder::build_with_post_and_two_spaces().execute_with(|| {
+        assert_noop!(
+            Reactions::create_post_reaction_as_space(
+                RuntimeOrigin::signed(ACCOUNT2),
+                SPACE2,
+                POST1,
+                reaction_upvote()
+            ),
+            SpacesError::<Test>::NoPermissionToRepresentSpace
+        );
+    });
+}
+
+#[test]
+fn reactions_migration_should_migrate_reactions_across_blocks() {
+    ExtBuilder::build().execute_with(|| {
+        let reactions_count = REACTIONS_PER_BLOCK + 1;
+        for reaction_id in 1..=reactions_count {
+            OldReactionById::<Test>::insert(
+                reaction_id,
+                OldReaction {
+                    id: reaction_id,
+                    created: new_who_and_when::<Test>(ACCOUNT2),
+                    kind: ReactionKind::Upvote,
+                },
+            );
+        }
+
+        StorageVersion::new(0).put::<Reactions>();
+        reactions_v1::MigrateToV1::<Test>::on_runtime_upgrade();
+        assert!(reactions_v1::is_migration_in_progress::<Test>());
+
+        Reactions::on_initialize(1);
+        assert!(reactions_v1::is_migration_in_progress::<Test>());
+        assert_eq!(Reactions::on_chain_storage_version(), 0);
+
+        Reactions::on_initialize(2);
+        assert!(!reactions_v1::is_migration_in_progress::<Test>());
+        assert_eq!(Reactions::on_chain_storage_version(), 1);
+
+        for reaction_id in 1..=reactions_count {
+            let reaction = Reactions::reaction_by_id(reaction_id).unwrap();
+            assert_eq!(reaction.author, User::Account(ACCOUNT2));
+            assert_eq!(reaction.deposit.depositor, ACCOUNT2);
+        }
+    });
+}
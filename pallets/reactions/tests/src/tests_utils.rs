@@ -90,6 +90,7 @@ pub(crate) const ACCOUNT2: AccountId = 2;
 pub(crate) const ACCOUNT3: AccountId = 3;
 
 pub(crate) const SPACE1: SpaceId = 1001;
+pub(crate) const SPACE2: SpaceId = 1002;
 
 pub(crate) const POST1: PostId = 1;
 
@@ -7,8 +7,10 @@
 
 
 use frame_benchmarking::{account, benchmarks};
-use frame_support::ensure;
+use frame_support::{ensure, traits::Currency};
 use frame_system::RawOrigin;
+use pallet_spaces::types::BalanceOf;
+use sp_runtime::traits::Bounded;
 use sp_std::convert::TryFrom;
 use subsocial_support::Content;
 
@@ -23,6 +25,8 @@ benchmarks! {
         let space_id = pallet_spaces::NextSpaceId::<T>::get();
         let post_id = pallet_posts::NextPostId::<T>::get();
 
+        <T as pallet_spaces::Config>::Currency::make_free_balance_be(&account, BalanceOf::<T>::max_value());
+
         ensure!(pallet_spaces::Pallet::<T>::create_space(
             RawOrigin::Signed(account.clone()).into(),
             Content::None,
@@ -48,6 +52,8 @@ benchmarks! {
         let space_id = pallet_spaces::NextSpaceId::<T>::get();
         let post_id = pallet_posts::NextPostId::<T>::get();
 
+        <T as pallet_spaces::Config>::Currency::make_free_balance_be(&account, BalanceOf::<T>::max_value());
+
         ensure!(pallet_spaces::Pallet::<T>::create_space(
             RawOrigin::Signed(account.clone()).into(),
             Content::None,
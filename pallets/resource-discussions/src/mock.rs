@@ -104,6 +104,8 @@ impl pallet_permissions::Config for Test {
 
 impl pallet_space_follows::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type FollowDeposit = frame_support::traits::ConstU64<0>;
+    type InvitationDeposit = frame_support::traits::ConstU64<0>;
     type WeightInfo = ();
 }
 
@@ -115,6 +117,14 @@ impl pallet_posts::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type MaxCommentDepth = MaxCommentDepth;
     type IsPostBlocked = ();
+    type PostDeletionHandler = ();
+    type PostVisibilityHandler = ();
+    type PostDeposit = frame_support::traits::ConstU64<0>;
+    type MaxPinnedPostsPerSpace = frame_support::traits::ConstU32<3>;
+    type MaxPostRevisions = frame_support::traits::ConstU32<3>;
+    type MaxScheduledPostsPerBlock = frame_support::traits::ConstU32<3>;
+    type MaxPollOptions = frame_support::traits::ConstU32<3>;
+    type BackerStakesProvider = ();
     type WeightInfo = ();
 }
 
@@ -131,6 +141,10 @@ impl PermissionChecker for FakeImpls {
     ) -> DispatchResult {
         Ok(())
     }
+
+    fn has_user_role(_user: User<Self::AccountId>, _space_id: SpaceId, _role_id: u64) -> bool {
+        false
+    }
 }
 
 impl SpaceFollowsProvider for FakeImpls {
@@ -139,10 +153,17 @@ impl SpaceFollowsProvider for FakeImpls {
     fn is_space_follower(_account: Self::AccountId, _space_id: SpaceId) -> bool {
         false
     }
+
+    fn space_followers_count(_space_id: SpaceId) -> u32 {
+        0
+    }
 }
 
 parameter_types! {
     pub const MaxSpacesPerAccount: u32 = 4096;
+    pub const MaxSubspaceDepth: u32 = 10;
+    pub const MinHandleLen: u32 = 5;
+    pub const MaxHandleLen: u32 = 50;
 }
 
 impl pallet_spaces::Config for Test {
@@ -152,6 +173,17 @@ impl pallet_spaces::Config for Test {
     type IsAccountBlocked = ();
     type IsContentBlocked = ();
     type MaxSpacesPerAccount = MaxSpacesPerAccount;
+    type MaxSubspaceDepth = MaxSubspaceDepth;
+    type DomainsProvider = ();
+    type MinHandleLen = MinHandleLen;
+    type MaxHandleLen = MaxHandleLen;
+    type ProfileManager = ();
+    type CreatorStakingProvider = ();
+    type SpaceDeletionHandler = ();
+    type SpaceSettingsRegistry = Posts;
+    type Currency = Balances;
+    type SpaceDeposit = frame_support::traits::ConstU64<0>;
+    type MaxSpaceCoOwners = frame_support::traits::ConstU32<3>;
     type WeightInfo = ();
 }
 
@@ -17,6 +17,7 @@ use frame_system::pallet_prelude::BlockNumberFor;
 use frame_system::RawOrigin;
 use pallet_permissions::SpacePermission as SP;
 use pallet_spaces::types::Space;
+use sp_runtime::traits::Bounded;
 use sp_std::{prelude::Vec, vec};
 use subsocial_support::{Content, User};
 use subsocial_support::mock_functions::{valid_content_ipfs, another_valid_content_ipfs};
@@ -24,6 +25,14 @@ use subsocial_support::mock_functions::{valid_content_ipfs, another_valid_conten
 fn create_dummy_space<T: Config + pallet_spaces::Config>(
     origin: RawOrigin<T::AccountId>,
 ) -> Result<Space<T>, DispatchError> {
+    if let RawOrigin::Signed(owner) = &origin {
+        <T as pallet_spaces::Config>::Currency::make_free_balance_be(
+            owner,
+            pallet_spaces::types::BalanceOf::<T>::max_value(),
+        );
+        <T as Config>::Currency::make_free_balance_be(owner, BalanceOf::<T>::max_value());
+    }
+
     let space_id = pallet_spaces::NextSpaceId::<T>::get();
 
     pallet_spaces::Pallet::<T>::create_space(origin.into(), Content::None, None)?;
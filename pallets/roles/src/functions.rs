@@ -6,7 +6,7 @@
 
 use super::*;
 
-use frame_support::dispatch::DispatchError;
+use frame_support::{dispatch::DispatchError, weights::Weight};
 use frame_system::pallet_prelude::BlockNumberFor;
 use pallet_permissions::SpacePermissionsContext;
 
@@ -37,6 +37,13 @@ impl<T: Config> Pallet<T> {
         )
     }
 
+    /// Ensure that a given space is not archived, so that roles can be created or granted in it.
+    pub fn ensure_space_not_archived(space_id: SpaceId) -> DispatchResult {
+        let space = T::SpacePermissionsProvider::space_permissions_info(space_id)?;
+        ensure!(!space.archived, Error::<T>::SpaceIsArchived);
+        Ok(())
+    }
+
     fn ensure_user_has_space_permission_with_load_space(
         user: User<T::AccountId>,
         space_id: SpaceId,
@@ -50,7 +57,7 @@ impl<T: Config> Pallet<T> {
 
         match &user {
             User::Account(account) => {
-                is_owner = *account == space.owner;
+                is_owner = space.is_owner(account);
 
                 // No need to check if a user is follower, if they already are an owner:
                 is_follower =
@@ -97,18 +104,7 @@ impl<T: Config> Pallet<T> {
 
         for role_id in role_ids {
             if let Some(role) = Self::role_by_id(role_id) {
-                if role.disabled {
-                    continue
-                }
-
-                let mut is_expired = false;
-                if let Some(expires_at) = role.expires_at {
-                    if expires_at <= <system::Pallet<T>>::block_number() {
-                        is_expired = true;
-                    }
-                }
-
-                if !is_expired && role.permissions.contains(&permission) {
+                if role.is_active() && role.permissions.contains(&permission) {
                     return Ok(())
                 }
             }
@@ -125,6 +121,7 @@ impl<T: Config> Role<T> {
         time_to_live: Option<BlockNumberFor<T>>,
         content: Content,
         permissions: BTreeSet<SpacePermission>,
+        deposit: StorageDepositOf<T>,
     ) -> Result<Self, DispatchError> {
         let role_id = Pallet::<T>::next_role_id();
 
@@ -141,11 +138,21 @@ impl<T: Config> Role<T> {
             expires_at,
             content,
             permissions,
+            deposit,
         };
 
         Ok(new_role)
     }
 
+    /// Check whether this role is neither disabled nor expired.
+    pub fn is_active(&self) -> bool {
+        let is_expired = self
+            .expires_at
+            .map_or(false, |expires_at| expires_at <= <system::Pallet<T>>::block_number());
+
+        !self.disabled && !is_expired
+    }
+
     pub fn set_disabled(&mut self, disable: bool) -> DispatchResult {
         if self.disabled && disable {
             return Err(Error::<T>::RoleAlreadyDisabled.into())
@@ -180,6 +187,55 @@ impl<T: Config> Role<T> {
     }
 }
 
+impl<T: Config> SpaceDeletionHandler for Pallet<T> {
+    /// Remove the roles of a deleted space one by one,
+    /// revoking each role from its users before removing the role itself.
+    fn cleanup_deleted_space(space_id: SpaceId, limit: u32) -> u32 {
+        let mut removed = 0;
+
+        while removed < limit {
+            let role_id = match Self::role_ids_by_space_id(space_id).last() {
+                Some(role_id) => *role_id,
+                None => break,
+            };
+
+            let mut users = Self::users_by_role_id(role_id);
+            let users_to_revoke = users.len().min((limit - removed) as usize);
+
+            for user in users.split_off(users.len() - users_to_revoke) {
+                RoleIdsByUserInSpace::<T>::remove(user, space_id);
+            }
+            removed += users_to_revoke as u32;
+
+            if !users.is_empty() {
+                UsersByRoleId::<T>::insert(role_id, users);
+                break;
+            }
+
+            if let Some(role) = RoleById::<T>::take(role_id) {
+                role.deposit.release::<T::Currency>();
+            }
+            UsersByRoleId::<T>::remove(role_id);
+            RoleIdsBySpaceId::<T>::mutate(space_id, |role_ids| role_ids.pop());
+            removed += 1;
+        }
+
+        if removed < limit {
+            RoleIdsBySpaceId::<T>::remove(space_id);
+        }
+
+        removed
+    }
+
+    /// An item is either a role or a user the role is revoked from.
+    fn cleanup_deleted_space_weight(limit: u32) -> Weight {
+        let item_weight = T::DbWeight::get().reads_writes(4, 4);
+        T::DbWeight::get()
+            .reads_writes(1, 1)
+            .saturating_add(item_weight.saturating_mul(limit.into()))
+    }
+}
+
 impl<T: Config> PermissionChecker for Pallet<T> {
     type AccountId = T::AccountId;
 
@@ -191,4 +247,10 @@ impl<T: Config> PermissionChecker for Pallet<T> {
     ) -> DispatchResult {
         Self::ensure_user_has_space_permission(user, ctx, permission, error)
     }
+
+    fn has_user_role(user: User<Self::AccountId>, space_id: SpaceId, role_id: RoleId) -> bool {
+        Self::role_ids_by_user_in_space(user, space_id).contains(&role_id) &&
+            Self::role_by_id(role_id)
+                .map_or(false, |role| role.space_id == space_id && role.is_active())
+    }
 }
@@ -13,11 +13,18 @@
 //! you would create a role "Editor" with permissions such as `CreatePosts`, `UpdateAnyPost`,
 //! and `HideAnyComment`. Then you would grant this role to the specific accounts you would like
 //! to make editors.
+//!
+//! Creating a role reserves `RoleDeposit` from its creator, which is returned when the role
+//! is deleted, either directly or together with its space.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Decode, Encode};
-use frame_support::{dispatch::DispatchResult, ensure, traits::Get};
+use frame_support::{
+    dispatch::DispatchResult,
+    ensure,
+    traits::{Currency, Get, ReservableCurrency},
+};
 use frame_system::{self as system, ensure_signed};
 use scale_info::TypeInfo;
 use sp_runtime::RuntimeDebug;
@@ -28,12 +35,16 @@ use pallet_permissions::{
 };
 use subsocial_support::{
     convert_users_vec_to_btree_set, ensure_content_is_valid, new_who_and_when,
-    traits::{IsAccountBlocked, IsContentBlocked, SpaceFollowsProvider, SpacePermissionsProvider},
-    Content, ModerationError, SpaceId, User, WhoAndWhenOf,
+    traits::{
+        IsAccountBlocked, IsContentBlocked, SpaceDeletionHandler, SpaceFollowsProvider,
+        SpacePermissionsProvider,
+    },
+    Content, ModerationError, SpaceId, StorageDeposit, User, WhoAndWhenOf,
 };
 
 pub use pallet::*;
 pub mod functions;
+pub mod migration;
 
 pub mod types;
 pub use types::*;
@@ -48,6 +59,8 @@ mod benchmarking;
 mod tests;
 pub mod weights;
 
+pub const LOG_TARGET: &str = "runtime::roles";
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -81,12 +94,23 @@ pub mod pallet {
 
         type IsContentBlocked: IsContentBlocked;
 
+        /// The currency in which storage deposits of roles are reserved.
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// The amount reserved from a creator for storing a role.
+        #[pallet::constant]
+        type RoleDeposit: Get<BalanceOf<Self>>;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
 
+    /// The current storage version
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
     #[pallet::pallet]
     #[pallet::without_storage_info]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::event]
@@ -133,6 +157,9 @@ pub mod pallet {
 
         /// Cannot enable a role that is already enabled.
         RoleAlreadyEnabled,
+
+        /// Cannot create or grant roles in an archived space.
+        SpaceIsArchived,
     }
 
     #[pallet::type_value]
@@ -177,6 +204,13 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+            migration::v1::migrate_next_roles::<T>()
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Create a new role, with a list of permissions, within a given space.
@@ -205,10 +239,19 @@ pub mod pallet {
             );
 
             Self::ensure_role_manager(who.clone(), space_id)?;
+            Self::ensure_space_not_archived(space_id)?;
 
             let permissions_set = permissions.into_iter().collect();
-            let new_role =
-                Role::<T>::new(who.clone(), space_id, time_to_live, content, permissions_set)?;
+            let deposit =
+                StorageDeposit::reserve::<T::Currency>(who.clone(), T::RoleDeposit::get())?;
+            let new_role = Role::<T>::new(
+                who.clone(),
+                space_id,
+                time_to_live,
+                content,
+                permissions_set,
+                deposit,
+            )?;
 
             // TODO review strange code:
             let next_role_id = new_role.id.checked_add(1).ok_or(Error::<T>::RoleIdOverflow)?;
@@ -317,6 +360,7 @@ pub mod pallet {
             }
 
             role.revoke_from_users(users);
+            role.deposit.release::<T::Currency>();
 
             <RoleById<T>>::remove(role_id);
             <UsersByRoleId<T>>::remove(role_id);
@@ -342,6 +386,7 @@ pub mod pallet {
             let role = Self::require_role(role_id)?;
 
             Self::ensure_role_manager(who.clone(), role.space_id)?;
+            Self::ensure_space_not_archived(role.space_id)?;
 
             for user in users_set.iter() {
                 if !Self::users_by_role_id(role_id).contains(user) {
@@ -412,6 +457,13 @@ pub mod pallet {
                 time,
             };
 
+            let old_role_opt = Self::role_by_id(role_id);
+            // A role created by root is stored for free, unless it has a deposit already.
+            let deposit = old_role_opt
+                .as_ref()
+                .map(|role| role.deposit.clone())
+                .unwrap_or_else(|| StorageDeposit::free(account.clone()));
+
             let new_role = Role::<T> {
                 created: new_who_and_when,
                 id: role_id,
@@ -420,9 +472,10 @@ pub mod pallet {
                 expires_at: None,
                 content,
                 permissions,
+                deposit,
             };
 
-            if let Ok(role) = Self::require_role(role_id) {
+            if let Some(role) = old_role_opt {
                 if role.space_id != space_id {
                     RoleIdsBySpaceId::<T>::mutate(role.space_id, |role_ids| {
                         remove_from_vec(role_ids, role_id)
@@ -0,0 +1,151 @@
+// Copyright (C) DAPPFORCE PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
+//
+// Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
+// Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
+
+use frame_support::{log, traits::OnRuntimeUpgrade};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::Saturating;
+use sp_std::vec::Vec;
+
+use super::*;
+
+pub mod v1 {
+    use frame_support::{pallet_prelude::*, storage::StoragePrefixedMap, weights::Weight};
+
+    use super::*;
+
+    /// The maximum number of roles migrated in a single block.
+    pub const ROLES_PER_BLOCK: u64 = 500;
+
+    // Old role, before storage deposits were introduced
+    #[derive(Encode, Decode)]
+    pub struct OldRole<T: Config> {
+        pub created: WhoAndWhenOf<T>,
+        pub id: RoleId,
+        pub space_id: SpaceId,
+        pub disabled: bool,
+        pub expires_at: Option<BlockNumberFor<T>>,
+        pub content: Content,
+        pub permissions: SpacePermissionSet,
+    }
+
+    #[frame_support::storage_alias]
+    pub type OldRoleById<T: Config> = StorageMap<Pallet<T>, Twox64Concat, RoleId, OldRole<T>>;
+
+    /// The raw key of the last migrated role, or the prefix of the roles map if no
+    /// role has been migrated yet. It exists only while the migration is in progress.
+    #[frame_support::storage_alias]
+    type MigrationCursor<T: Config> = StorageValue<Pallet<T>, Vec<u8>>;
+
+    impl<T: Config> OldRole<T> {
+        fn migrate_to_v1(self) -> Role<T> {
+            Role {
+                // Existing roles are grandfathered and keep being stored for free.
+                deposit: StorageDeposit::free(self.created.account.clone()),
+                created: self.created,
+                id: self.id,
+                space_id: self.space_id,
+                disabled: self.disabled,
+                expires_at: self.expires_at,
+                content: self.content,
+                permissions: self.permissions,
+            }
+        }
+    }
+
+    /// Whether roles are being migrated. Calls that use roles should not be dispatched
+    /// until the migration is over.
+    pub fn is_migration_in_progress<T: Config>() -> bool {
+        MigrationCursor::<T>::exists()
+    }
+
+    /// Migrate up to `ROLES_PER_BLOCK` roles, if the migration is in progress,
+    /// and put the new storage version once every role is migrated.
+    pub(crate) fn migrate_next_roles<T: Config>() -> Weight {
+        let cursor = match MigrationCursor::<T>::get() {
+            Some(cursor) => cursor,
+            None => return T::DbWeight::get().reads(1),
+        };
+
+        let mut old_roles = OldRoleById::<T>::iter_from(cursor);
+        let mut migrated = 0u64;
+        let mut is_finished = true;
+
+        for (role_id, old_role) in old_roles.by_ref() {
+            RoleById::<T>::insert(role_id, old_role.migrate_to_v1());
+            migrated.saturating_inc();
+
+            if migrated >= ROLES_PER_BLOCK {
+                is_finished = false;
+                break;
+            }
+        }
+
+        if is_finished {
+            MigrationCursor::<T>::kill();
+            let current_version = Pallet::<T>::current_storage_version();
+            current_version.put::<Pallet<T>>();
+            log::info!(
+                target: LOG_TARGET,
+                "Upgraded the last {} roles, storage to version {:?}",
+                migrated,
+                current_version
+            );
+        } else {
+            MigrationCursor::<T>::put(old_roles.last_raw_key().to_vec());
+        }
+
+        T::DbWeight::get().reads_writes(migrated + 1, migrated + 1)
+    }
+
+    /// Starts a migration of roles from the storage version 0 to 1, which translates
+    /// up to `ROLES_PER_BLOCK` roles in `on_initialize` of every block.
+    pub struct MigrateToV1<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let current_version = Pallet::<T>::current_storage_version();
+            let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+            log::info!(
+                target: LOG_TARGET,
+                "Running migration with current storage version {:?} / onchain {:?}",
+                current_version,
+                onchain_version
+            );
+
+            if onchain_version == 0 && current_version == 1 && !is_migration_in_progress::<T>() {
+                MigrationCursor::<T>::put(RoleById::<T>::final_prefix().to_vec());
+
+                log::info!(
+                    target: LOG_TARGET,
+                    "Started migrating roles to storage version {:?}",
+                    current_version
+                );
+                T::DbWeight::get().reads_writes(2, 1)
+            } else {
+                log::info!(
+                    target: LOG_TARGET,
+                    "Migration did not execute. This probably should be removed"
+                );
+                T::DbWeight::get().reads(2)
+            }
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+            let current_version = Pallet::<T>::current_storage_version();
+            let onchain_version = Pallet::<T>::on_chain_storage_version();
+            ensure!(onchain_version == 0 && current_version == 1, "migration from version 0 to 1.");
+            Ok(Vec::new())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), DispatchError> {
+            ensure!(is_migration_in_progress::<T>(), "roles should be migrated in next blocks");
+            Ok(())
+        }
+    }
+}
@@ -118,6 +118,8 @@ parameter_types! {
 impl Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type MaxUsersToProcessPerDeleteRole = MaxUsersToProcessPerDeleteRole;
+    type Currency = Balances;
+    type RoleDeposit = frame_support::traits::ConstU64<0>;
     #[cfg(feature = "runtime-benchmarks")]
     type SpacePermissionsProvider = Spaces;
     #[cfg(not(feature = "runtime-benchmarks"))]
@@ -135,6 +137,17 @@ impl pallet_spaces::Config for Test {
     type IsAccountBlocked = ();
     type IsContentBlocked = ();
     type MaxSpacesPerAccount = ConstU32<100>;
+    type MaxSubspaceDepth = ConstU32<10>;
+    type DomainsProvider = ();
+    type MinHandleLen = ConstU32<5>;
+    type MaxHandleLen = ConstU32<50>;
+    type ProfileManager = ();
+    type CreatorStakingProvider = ();
+    type SpaceDeletionHandler = ();
+    type SpaceSettingsRegistry = ();
+    type Currency = Balances;
+    type SpaceDeposit = frame_support::traits::ConstU64<0>;
+    type MaxSpaceCoOwners = frame_support::traits::ConstU32<3>;
     type WeightInfo = ();
 }
 
@@ -147,7 +160,12 @@ impl SpacePermissionsProviderT<AccountId, SpacePermissionsInfo<AccountId, SpaceP
         id: SpaceId,
     ) -> Result<SpacePermissionsInfo<AccountId, SpacePermissions>, DispatchError> {
         if valid_space_ids().contains(&id) {
-            return Ok(SpacePermissionsInfo { owner: ACCOUNT1, permissions: None })
+            return Ok(SpacePermissionsInfo {
+                owner: ACCOUNT1,
+                co_owners: vec![],
+                permissions: None,
+                archived: false,
+            })
         }
 
         Err("mock:SpaceNotFound".into())
@@ -170,6 +188,10 @@ impl<T: Config> SpaceFollowsProvider for Pallet<T> {
     fn is_space_follower(_account: Self::AccountId, _space_id: u64) -> bool {
         true
     }
+
+    fn space_followers_count(_space_id: u64) -> u32 {
+        0
+    }
 }
 
 pub struct ExtBuilder;
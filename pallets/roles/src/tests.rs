@@ -6,8 +6,13 @@
 
 use crate::{mock::*, *};
 
-use frame_support::{assert_noop, assert_ok};
-use subsocial_support::ContentError;
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+};
+use subsocial_support::{new_who_and_when, Content, ContentError};
+
+use crate::migration::v1::{self as roles_v1, OldRole, OldRoleById, ROLES_PER_BLOCK};
 
 #[test]
 fn create_role_should_work() {
@@ -571,3 +576,42 @@ fn delete_role_should_fail_with_a_few_roles_no_permission() {
         );
     });
 }
+
+#[test]
+fn roles_migration_should_migrate_roles_across_blocks() {
+    ExtBuilder::build().execute_with(|| {
+        let roles_count = ROLES_PER_BLOCK + 1;
+        for role_id in 1..=roles_count {
+            OldRoleById::<Test>::insert(
+                role_id,
+                OldRole {
+                    created: new_who_and_when::<Test>(ACCOUNT1),
+                    id: role_id,
+                    space_id: SPACE1,
+                    disabled: false,
+                    expires_at: None,
+                    content: Content::None,
+                    permissions: self::permission_set_default().into_iter().collect(),
+                },
+            );
+        }
+
+        StorageVersion::new(0).put::<Roles>();
+        roles_v1::MigrateToV1::<Test>::on_runtime_upgrade();
+        assert!(roles_v1::is_migration_in_progress::<Test>());
+
+        Roles::on_initialize(1);
+        assert!(roles_v1::is_migration_in_progress::<Test>());
+        assert_eq!(Roles::on_chain_storage_version(), 0);
+
+        Roles::on_initialize(2);
+        assert!(!roles_v1::is_migration_in_progress::<Test>());
+        assert_eq!(Roles::on_chain_storage_version(), 1);
+
+        for role_id in 1..=roles_count {
+            let role = Roles::role_by_id(role_id).unwrap();
+            assert_eq!(role.deposit.depositor, ACCOUNT1);
+            assert_eq!(role.permissions, self::permission_set_default().into_iter().collect());
+        }
+    });
+}
@@ -9,6 +9,11 @@ use super::*;
 
 pub type RoleId = u64;
 
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type StorageDepositOf<T> = StorageDeposit<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
 pub const FIRST_ROLE_ID: u64 = 1;
 
 /// Information about a role's permissions, its' containing space, and its' content.
@@ -40,6 +45,9 @@ pub struct Role<T: Config> {
     /// A set of permisions granted to owners of a particular role which are valid
     /// only within the space containing this role
     pub permissions: SpacePermissionSet,
+
+    /// The amount reserved for storing this role, returned when the role is deleted.
+    pub deposit: StorageDepositOf<T>,
 }
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
@@ -9,15 +9,31 @@
 #![cfg(feature = "runtime-benchmarks")]
 
 use super::*;
+use crate::types::Invitee;
 use frame_benchmarking::{account, benchmarks};
-use frame_support::{dispatch::DispatchError, ensure};
+use frame_support::{
+    dispatch::DispatchError,
+    ensure,
+    sp_io::crypto::{sr25519_generate, sr25519_sign},
+    sp_runtime::{traits::Bounded, KeyTypeId},
+    traits::Currency,
+};
 use frame_system::RawOrigin;
 use pallet_spaces::types::Space;
 use subsocial_support::Content;
 
+const INVITATION_CODE_KEY_TYPE: KeyTypeId = KeyTypeId(*b"invc");
+
 fn create_dummy_space<T: Config>(
     origin: RawOrigin<T::AccountId>,
 ) -> Result<Space<T>, DispatchError> {
+    if let RawOrigin::Signed(owner) = &origin {
+        <T as pallet_spaces::Config>::Currency::make_free_balance_be(
+            owner,
+            BalanceOf::<T>::max_value(),
+        );
+    }
+
     let space_id = pallet_spaces::NextSpaceId::<T>::get();
 
     pallet_spaces::Pallet::<T>::create_space(origin.clone().into(), Content::None, None)?;
@@ -33,6 +49,7 @@ benchmarks! {
     follow_space {
         let space_owner_origin = RawOrigin::Signed(account::<T::AccountId>("SpaceOwner", 2, 0));
         let space_follower = account::<T::AccountId>("SpaceFollower", 1, 0);
+        <T as pallet_spaces::Config>::Currency::make_free_balance_be(&space_follower, BalanceOf::<T>::max_value());
 
         let space = create_dummy_space::<T>(space_owner_origin.clone())?;
     }: _(RawOrigin::Signed(space_follower.clone()), space.id)
@@ -45,6 +62,7 @@ benchmarks! {
     unfollow_space {
         let space_owner_origin = RawOrigin::Signed(account::<T::AccountId>("SpaceOwner", 2, 0));
         let space_follower = account::<T::AccountId>("SpaceFollower", 1, 0);
+        <T as pallet_spaces::Config>::Currency::make_free_balance_be(&space_follower, BalanceOf::<T>::max_value());
 
         let space = create_dummy_space::<T>(space_owner_origin.clone())?;
         Pallet::<T>::follow_space(RawOrigin::Signed(space_follower.clone()).into(),space.id)?;
@@ -55,4 +73,45 @@ benchmarks! {
         ensure!(!SpaceFollowedByAccount::<T>::get(&(space_follower.clone(), space.id)), "SpaceFollowedByAccount was not updated");
         ensure!(!SpacesFollowedByAccount::<T>::get(&space_follower).contains(&space.id), "SpacesFollowedByAccount was not updated");
     }
+
+    invite_to_space {
+        let space_owner = account::<T::AccountId>("SpaceOwner", 2, 0);
+        let invitee = Invitee::Account(account::<T::AccountId>("Invitee", 1, 0));
+
+        let space = create_dummy_space::<T>(RawOrigin::Signed(space_owner.clone()))?;
+    }: _(RawOrigin::Signed(space_owner), space.id, invitee.clone())
+    verify {
+        ensure!(SpaceInvitations::<T>::contains_key(space.id, &invitee), "Invitation was not issued");
+    }
+
+    revoke_invitation {
+        let space_owner = account::<T::AccountId>("SpaceOwner", 2, 0);
+        let invitee = Invitee::Account(account::<T::AccountId>("Invitee", 1, 0));
+
+        let space = create_dummy_space::<T>(RawOrigin::Signed(space_owner.clone()))?;
+        Pallet::<T>::invite_to_space(RawOrigin::Signed(space_owner.clone()).into(), space.id, invitee.clone())?;
+    }: _(RawOrigin::Signed(space_owner), space.id, invitee.clone())
+    verify {
+        ensure!(!SpaceInvitations::<T>::contains_key(space.id, &invitee), "Invitation was not revoked");
+    }
+
+    follow_space_with_code {
+        let space_owner = account::<T::AccountId>("SpaceOwner", 2, 0);
+        let space_follower = account::<T::AccountId>("SpaceFollower", 1, 0);
+        <T as pallet_spaces::Config>::Currency::make_free_balance_be(&space_follower, BalanceOf::<T>::max_value());
+
+        let space = create_dummy_space::<T>(RawOrigin::Signed(space_owner.clone()))?;
+
+        let code_key = sr25519_generate(INVITATION_CODE_KEY_TYPE, None);
+        let payload = Pallet::<T>::invitation_code_payload(space.id, &space_follower);
+        let signature = sr25519_sign(INVITATION_CODE_KEY_TYPE, &code_key, &payload)
+            .ok_or("Invitation code was not signed")?;
+        let invitee = Invitee::CodeKey(code_key);
+
+        Pallet::<T>::invite_to_space(RawOrigin::Signed(space_owner).into(), space.id, invitee.clone())?;
+    }: _(RawOrigin::Signed(space_follower.clone()), space.id, code_key, signature)
+    verify {
+        ensure!(SpaceFollowedByAccount::<T>::get(&(space_follower, space.id)), "Space was not followed");
+        ensure!(!SpaceInvitations::<T>::contains_key(space.id, &invitee), "Invitation was not redeemed");
+    }
 }
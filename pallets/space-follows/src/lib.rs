@@ -5,15 +5,29 @@
 // Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
 // Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
 
+//! # Space Follows Module
+//!
+//! Accounts can follow and unfollow spaces.
+//!
+//! A space can be made invite-only with the follow policy space setting, see `FollowPolicySetting`.
+//! Then only the space owner and invited accounts can follow it. Invitations are issued by
+//! accounts with the `InviteMembers` permission, either for a given account or as a one-time
+//! code keypair. A code is redeemed with a signature of the follower account made by its secret
+//! key, so a pending redemption cannot be taken over by another account. The issuer reserves
+//! a deposit for each invitation, which is returned when the invitation is redeemed or revoked.
+//!
+//! A space can also follow other spaces. Accounts with the `RepresentSpaceExternally` permission
+//! in a follower space can follow and unfollow spaces on its behalf.
 
 pub use pallet::*;
 
-use frame_support::dispatch::DispatchResult;
+use frame_support::{dispatch::DispatchResult, traits::ReservableCurrency};
 
-use pallet_spaces::Pallet as Spaces;
+use pallet_spaces::{types::BalanceOf, Pallet as Spaces};
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod types;
 pub mod weights;
 
 // pub mod rpc;
@@ -22,14 +36,20 @@ pub mod weights;
 pub mod pallet {
     use super::*;
 
-    use crate::weights::WeightInfo;
-    use frame_support::pallet_prelude::*;
+    use crate::{types::*, weights::WeightInfo};
+    use frame_support::{pallet_prelude::*, sp_runtime::traits::Verify};
     use frame_system::pallet_prelude::*;
-    use sp_std::vec::Vec;
+    use pallet_permissions::SpacePermission;
+    use pallet_spaces::types::{Space, StorageDepositOf};
+    use sp_core::sr25519;
+    use sp_std::{vec, vec::Vec};
     use subsocial_support::{
         remove_from_vec,
-        traits::{IsAccountBlocked, SpaceFollowsProvider},
-        ModerationError, SpaceId,
+        traits::{
+            IsAccountBlocked, SpaceDeletionHandler, SpaceFollowsProvider, SpaceSetting,
+            SpaceSettingInfo, SpaceSettingsRegistry,
+        },
+        ModerationError, SpaceId, StorageDeposit,
     };
 
     #[pallet::config]
@@ -37,6 +57,15 @@ pub mod pallet {
         /// The overarching event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
+        /// The amount reserved from a follower for storing their follow of a space.
+        #[pallet::constant]
+        type FollowDeposit: Get<BalanceOf<Self>>;
+
+        /// The amount reserved from an issuer for storing an invitation until it is redeemed
+        /// or revoked.
+        #[pallet::constant]
+        type InvitationDeposit: Get<BalanceOf<Self>>;
+
         type WeightInfo: WeightInfo;
     }
 
@@ -52,6 +81,24 @@ pub mod pallet {
         NotSpaceFollower,
         /// Not allowed to follow a hidden space.
         CannotFollowHiddenSpace,
+        /// The space is invite-only and there is no invitation for this account.
+        InvitationRequired,
+        /// Invitation was not found.
+        InvitationNotFound,
+        /// The same invitation has already been issued in this space.
+        InvitationAlreadyExists,
+        /// Only the issuer of an invitation can revoke it.
+        NotInvitationIssuer,
+        /// Account has no permission to invite members to this space.
+        NoPermissionToInviteMembers,
+        /// Invitation code signature is not valid for this account and space.
+        InvalidInvitationSignature,
+        /// Space already follows this space.
+        AlreadySpaceFollowerSpace,
+        /// Space does not follow this space.
+        NotSpaceFollowerSpace,
+        /// A space cannot follow itself.
+        CannotFollowItself,
     }
 
     #[pallet::storage]
@@ -69,11 +116,49 @@ pub mod pallet {
     pub type SpacesFollowedByAccount<T: Config> =
         StorageMap<_, Twox64Concat, T::AccountId, Vec<SpaceId>, ValueQuery>;
 
+    /// The amount reserved from a follower for following a given space.
+    /// Follows created before the deposits were introduced, or by root, have no deposit.
+    #[pallet::storage]
+    #[pallet::getter(fn space_follow_deposit)]
+    pub type SpaceFollowDeposit<T: Config> =
+        StorageMap<_, Blake2_128Concat, (T::AccountId, SpaceId), BalanceOf<T>, ValueQuery>;
+
+    /// Invitations to follow a given space that have not been redeemed yet,
+    /// along with their issuers and the amounts reserved from them.
+    #[pallet::storage]
+    #[pallet::getter(fn space_invitation)]
+    pub type SpaceInvitations<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        SpaceId,
+        Blake2_128Concat,
+        InviteeOf<T>,
+        StorageDepositOf<T>,
+    >;
+
+    /// Spaces followed by a given follower space, along with the amount reserved for each follow
+    /// from the account that created it.
+    #[pallet::storage]
+    #[pallet::getter(fn space_followed_by_space)]
+    pub type SpaceFollowedBySpace<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, SpaceId, Twox64Concat, SpaceId, StorageDepositOf<T>>;
+
+    /// Spaces that follow a given space.
+    #[pallet::storage]
+    #[pallet::getter(fn follower_spaces)]
+    pub type FollowerSpacesBySpaceId<T: Config> =
+        StorageMap<_, Twox64Concat, SpaceId, Vec<SpaceId>, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         SpaceFollowed { follower: T::AccountId, space_id: SpaceId },
         SpaceUnfollowed { follower: T::AccountId, space_id: SpaceId },
+        InvitationIssued { issuer: T::AccountId, space_id: SpaceId, invitee: InviteeOf<T> },
+        InvitationRevoked { issuer: T::AccountId, space_id: SpaceId, invitee: InviteeOf<T> },
+        InvitationRedeemed { follower: T::AccountId, space_id: SpaceId, invitee: InviteeOf<T> },
+        SpaceFollowedBySpace { account: T::AccountId, follower: SpaceId, space_id: SpaceId },
+        SpaceUnfollowedBySpace { account: T::AccountId, follower: SpaceId, space_id: SpaceId },
     }
 
     #[pallet::call]
@@ -83,22 +168,19 @@ pub mod pallet {
         pub fn follow_space(origin: OriginFor<T>, space_id: SpaceId) -> DispatchResult {
             let follower = ensure_signed(origin)?;
 
-            ensure!(
-                !Self::space_followed_by_account((follower.clone(), space_id)),
-                Error::<T>::AlreadySpaceFollower
-            );
+            let space = Self::ensure_can_follow_space(&follower, space_id)?;
 
-            let space = Spaces::<T>::require_space(space_id)?;
-            ensure!(!space.hidden, Error::<T>::CannotFollowHiddenSpace);
+            let invitee = Invitee::Account(follower.clone());
+            if SpaceInvitations::<T>::contains_key(space_id, &invitee) {
+                Self::redeem_invitation(follower.clone(), space_id, invitee);
+            } else {
+                ensure!(
+                    !Self::is_invite_only(space_id) || space.is_owner(&follower),
+                    Error::<T>::InvitationRequired
+                );
+            }
 
-            ensure!(
-                T::IsAccountBlocked::is_allowed_account(follower.clone(), space.id),
-                ModerationError::AccountIsBlocked
-            );
-
-            Self::add_space_follower(follower, space_id);
-
-            Ok(())
+            Self::do_follow_space(follower, space_id)
         }
 
         #[pallet::call_index(1)]
@@ -138,15 +220,246 @@ pub mod pallet {
 
             Ok(Pays::No.into())
         }
+
+        /// Invite an account, or anyone who knows a code, to follow a space.
+        /// Requires the `InviteMembers` permission in the space.
+        #[pallet::call_index(3)]
+        #[pallet::weight(<T as Config>::WeightInfo::invite_to_space())]
+        pub fn invite_to_space(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            invitee: InviteeOf<T>,
+        ) -> DispatchResult {
+            let issuer = ensure_signed(origin)?;
+
+            let space = Spaces::<T>::require_space(space_id)?;
+            space.ensure_not_archived()?;
+
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(issuer.clone(), space_id),
+                ModerationError::AccountIsBlocked
+            );
+
+            Spaces::<T>::ensure_account_has_space_permission(
+                issuer.clone(),
+                &space,
+                SpacePermission::InviteMembers,
+                Error::<T>::NoPermissionToInviteMembers.into(),
+            )?;
+
+            if let Invitee::Account(account) = &invitee {
+                ensure!(
+                    !Self::space_followed_by_account((account.clone(), space_id)),
+                    Error::<T>::AlreadySpaceFollower
+                );
+            }
+
+            ensure!(
+                !SpaceInvitations::<T>::contains_key(space_id, &invitee),
+                Error::<T>::InvitationAlreadyExists
+            );
+
+            let deposit = StorageDeposit::reserve::<<T as pallet_spaces::Config>::Currency>(
+                issuer.clone(),
+                T::InvitationDeposit::get(),
+            )?;
+            SpaceInvitations::<T>::insert(space_id, &invitee, deposit);
+
+            Self::deposit_event(Event::InvitationIssued { issuer, space_id, invitee });
+            Ok(())
+        }
+
+        /// Revoke an invitation that has not been redeemed yet. Only its issuer can do this.
+        #[pallet::call_index(4)]
+        #[pallet::weight(<T as Config>::WeightInfo::revoke_invitation())]
+        pub fn revoke_invitation(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            invitee: InviteeOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let invitation =
+                Self::space_invitation(space_id, &invitee).ok_or(Error::<T>::InvitationNotFound)?;
+            ensure!(invitation.depositor == who, Error::<T>::NotInvitationIssuer);
+
+            SpaceInvitations::<T>::remove(space_id, &invitee);
+            invitation.release::<<T as pallet_spaces::Config>::Currency>();
+
+            Self::deposit_event(Event::InvitationRevoked { issuer: who, space_id, invitee });
+            Ok(())
+        }
+
+        /// Follow a space by redeeming a one-time invitation code.
+        /// `signature` must be made by the secret key of `code_key` over
+        /// `invitation_code_payload(space_id, follower)`.
+        #[pallet::call_index(5)]
+        #[pallet::weight(<T as Config>::WeightInfo::follow_space_with_code())]
+        pub fn follow_space_with_code(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            code_key: sr25519::Public,
+            signature: sr25519::Signature,
+        ) -> DispatchResult {
+            let follower = ensure_signed(origin)?;
+
+            Self::ensure_can_follow_space(&follower, space_id)?;
+
+            let invitee = Invitee::CodeKey(code_key);
+            ensure!(
+                SpaceInvitations::<T>::contains_key(space_id, &invitee),
+                Error::<T>::InvitationNotFound
+            );
+
+            let payload = Self::invitation_code_payload(space_id, &follower);
+            ensure!(
+                signature.verify(payload.as_slice(), &code_key),
+                Error::<T>::InvalidInvitationSignature
+            );
+            Self::redeem_invitation(follower.clone(), space_id, invitee);
+
+            Self::do_follow_space(follower, space_id)
+        }
+
+        /// Follow a space on behalf of another space. The caller must be able to represent
+        /// the follower space, and an invite-only space can only be followed by its owners.
+        #[pallet::call_index(6)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::follow_space()
+                .saturating_add(T::DbWeight::get().reads(2))
+        )]
+        pub fn follow_space_as_space(
+            origin: OriginFor<T>,
+            follower_space_id: SpaceId,
+            space_id: SpaceId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(follower_space_id != space_id, Error::<T>::CannotFollowItself);
+            ensure!(
+                !SpaceFollowedBySpace::<T>::contains_key(follower_space_id, space_id),
+                Error::<T>::AlreadySpaceFollowerSpace
+            );
+
+            let space = Spaces::<T>::require_space(space_id)?;
+            ensure!(!space.hidden, Error::<T>::CannotFollowHiddenSpace);
+            space.ensure_not_archived()?;
+            ensure!(
+                !Self::is_invite_only(space_id) || space.is_owner(&who),
+                Error::<T>::InvitationRequired
+            );
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(who.clone(), space_id),
+                ModerationError::AccountIsBlocked
+            );
+
+            Spaces::<T>::ensure_can_represent_space(&who, follower_space_id, space_id)?;
+
+            let deposit = StorageDeposit::reserve::<<T as pallet_spaces::Config>::Currency>(
+                who.clone(),
+                T::FollowDeposit::get(),
+            )?;
+            SpaceFollowedBySpace::<T>::insert(follower_space_id, space_id, deposit);
+            FollowerSpacesBySpaceId::<T>::mutate(space_id, |space_ids| {
+                space_ids.push(follower_space_id)
+            });
+
+            Self::deposit_event(Event::SpaceFollowedBySpace {
+                account: who,
+                follower: follower_space_id,
+                space_id,
+            });
+            Ok(())
+        }
+
+        /// Unfollow a space on behalf of another space.
+        /// The caller must be able to represent the follower space.
+        #[pallet::call_index(7)]
+        #[pallet::weight(
+            <T as Config>::WeightInfo::unfollow_space()
+                .saturating_add(T::DbWeight::get().reads(2))
+        )]
+        pub fn unfollow_space_as_space(
+            origin: OriginFor<T>,
+            follower_space_id: SpaceId,
+            space_id: SpaceId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                SpaceFollowedBySpace::<T>::contains_key(follower_space_id, space_id),
+                Error::<T>::NotSpaceFollowerSpace
+            );
+
+            Spaces::<T>::ensure_can_represent_space(&who, follower_space_id, space_id)?;
+
+            Self::remove_follower_space(follower_space_id, space_id);
+
+            Self::deposit_event(Event::SpaceUnfollowedBySpace {
+                account: who,
+                follower: follower_space_id,
+                space_id,
+            });
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
+        fn ensure_can_follow_space(
+            follower: &T::AccountId,
+            space_id: SpaceId,
+        ) -> Result<Space<T>, DispatchError> {
+            ensure!(
+                !Self::space_followed_by_account((follower.clone(), space_id)),
+                Error::<T>::AlreadySpaceFollower
+            );
+
+            let space = Spaces::<T>::require_space(space_id)?;
+            ensure!(!space.hidden, Error::<T>::CannotFollowHiddenSpace);
+            space.ensure_not_archived()?;
+
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(follower.clone(), space.id),
+                ModerationError::AccountIsBlocked
+            );
+
+            Ok(space)
+        }
+
+        pub fn is_invite_only(space_id: SpaceId) -> bool {
+            Spaces::<T>::space_setting::<FollowPolicySetting>(space_id) == FollowPolicy::InviteOnly
+        }
+
+        /// The message that the follower has to sign with an invitation code key
+        /// to redeem the code in a given space.
+        pub fn invitation_code_payload(space_id: SpaceId, follower: &T::AccountId) -> Vec<u8> {
+            (INVITATION_CODE_CONTEXT, space_id, follower).encode()
+        }
+
+        fn redeem_invitation(follower: T::AccountId, space_id: SpaceId, invitee: InviteeOf<T>) {
+            if let Some(invitation) = SpaceInvitations::<T>::take(space_id, &invitee) {
+                invitation.release::<<T as pallet_spaces::Config>::Currency>();
+            }
+            Self::deposit_event(Event::InvitationRedeemed { follower, space_id, invitee });
+        }
+
+        fn do_follow_space(follower: T::AccountId, space_id: SpaceId) -> DispatchResult {
+            let deposit = T::FollowDeposit::get();
+            <T as pallet_spaces::Config>::Currency::reserve(&follower, deposit)?;
+            SpaceFollowDeposit::<T>::insert((follower.clone(), space_id), deposit);
+
+            Self::add_space_follower(follower, space_id);
+
+            Ok(())
+        }
+
         fn add_space_follower(follower: T::AccountId, space_id: SpaceId) {
             SpaceFollowers::<T>::mutate(space_id, |followers| followers.push(follower.clone()));
             SpaceFollowedByAccount::<T>::insert((follower.clone(), space_id), true);
             SpacesFollowedByAccount::<T>::mutate(follower.clone(), |space_ids| {
                 space_ids.push(space_id)
             });
+            let _ = Spaces::<T>::mutate_space_by_id(space_id, |space| space.inc_followers());
 
             Self::deposit_event(Event::SpaceFollowed { follower, space_id });
         }
@@ -159,10 +472,26 @@ pub mod pallet {
                 remove_from_vec(account_ids, follower.clone())
             });
             SpaceFollowedByAccount::<T>::remove((follower.clone(), space_id));
+            Self::release_follow_deposit(&follower, space_id);
+            let _ = Spaces::<T>::mutate_space_by_id(space_id, |space| space.dec_followers());
 
             Self::deposit_event(Event::SpaceUnfollowed { follower, space_id });
             Ok(())
         }
+
+        fn release_follow_deposit(follower: &T::AccountId, space_id: SpaceId) {
+            let deposit = SpaceFollowDeposit::<T>::take((follower.clone(), space_id));
+            <T as pallet_spaces::Config>::Currency::unreserve(follower, deposit);
+        }
+
+        fn remove_follower_space(follower_space_id: SpaceId, space_id: SpaceId) {
+            if let Some(deposit) = SpaceFollowedBySpace::<T>::take(follower_space_id, space_id) {
+                deposit.release::<<T as pallet_spaces::Config>::Currency>();
+            }
+            FollowerSpacesBySpaceId::<T>::mutate(space_id, |space_ids| {
+                remove_from_vec(space_ids, follower_space_id)
+            });
+        }
     }
 
     impl<T: Config> SpaceFollowsProvider for Pallet<T> {
@@ -171,5 +500,95 @@ pub mod pallet {
         fn is_space_follower(account: Self::AccountId, space_id: SpaceId) -> bool {
             Pallet::<T>::space_followed_by_account((account, space_id))
         }
+
+        fn space_followers_count(space_id: SpaceId) -> u32 {
+            Pallet::<T>::space_followers(space_id).len() as u32
+        }
+    }
+
+    impl<T: Config> SpaceDeletionHandler for Pallet<T> {
+        /// Unfollow a deleted space on behalf of its followers, including follower spaces,
+        /// and unfollow all spaces that the deleted space followed.
+        fn cleanup_deleted_space(space_id: SpaceId, limit: u32) -> u32 {
+            let mut followers = Self::space_followers(space_id);
+            let mut removed = followers.len().min(limit as usize);
+
+            for follower in followers.split_off(followers.len() - removed) {
+                SpacesFollowedByAccount::<T>::mutate(&follower, |space_ids| {
+                    remove_from_vec(space_ids, space_id)
+                });
+                SpaceFollowedByAccount::<T>::remove((follower.clone(), space_id));
+                Self::release_follow_deposit(&follower, space_id);
+            }
+
+            if followers.is_empty() {
+                SpaceFollowers::<T>::remove(space_id);
+            } else {
+                SpaceFollowers::<T>::insert(space_id, followers);
+            }
+
+            if removed < limit as usize {
+                let mut follower_spaces = Self::follower_spaces(space_id);
+                let count = follower_spaces.len().min(limit as usize - removed);
+
+                for follower_space_id in follower_spaces.split_off(follower_spaces.len() - count) {
+                    if let Some(deposit) =
+                        SpaceFollowedBySpace::<T>::take(follower_space_id, space_id)
+                    {
+                        deposit.release::<<T as pallet_spaces::Config>::Currency>();
+                    }
+                }
+                removed += count;
+
+                if follower_spaces.is_empty() {
+                    FollowerSpacesBySpaceId::<T>::remove(space_id);
+                } else {
+                    FollowerSpacesBySpaceId::<T>::insert(space_id, follower_spaces);
+                }
+            }
+
+            if removed < limit as usize {
+                let followed = SpaceFollowedBySpace::<T>::drain_prefix(space_id)
+                    .take(limit as usize - removed)
+                    .collect::<Vec<_>>();
+                removed += followed.len();
+
+                for (followed_space_id, deposit) in followed {
+                    deposit.release::<<T as pallet_spaces::Config>::Currency>();
+                    FollowerSpacesBySpaceId::<T>::mutate(followed_space_id, |space_ids| {
+                        remove_from_vec(space_ids, space_id)
+                    });
+                }
+            }
+
+            if removed < limit as usize {
+                for (_, invitation) in
+                    SpaceInvitations::<T>::drain_prefix(space_id).take(limit as usize - removed)
+                {
+                    invitation.release::<<T as pallet_spaces::Config>::Currency>();
+                    removed += 1;
+                }
+            }
+
+            removed as u32
+        }
+
+        /// An item is either a follower, a follower space, a followed space or an invitation.
+        fn cleanup_deleted_space_weight(limit: u32) -> Weight {
+            let item_weight = T::DbWeight::get().reads_writes(3, 4);
+            T::DbWeight::get()
+                .reads_writes(3, 3)
+                .saturating_add(item_weight.saturating_mul(limit.into()))
+        }
+    }
+
+    impl<T: Config> SpaceSettingsRegistry for Pallet<T> {
+        fn registered_settings() -> Vec<SpaceSettingInfo> {
+            vec![SpaceSettingInfo::of::<FollowPolicySetting>()]
+        }
+
+        fn check_setting_value(key: &[u8], value: &[u8]) -> Option<bool> {
+            FollowPolicySetting::check_encoded_value(key, value)
+        }
     }
 }
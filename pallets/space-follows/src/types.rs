@@ -0,0 +1,47 @@
+// Copyright (C) DAPPFORCE PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
+//
+// Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
+// Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
+
+use codec::{Decode, Encode};
+use frame_support::RuntimeDebug;
+use scale_info::TypeInfo;
+use sp_core::sr25519;
+
+use subsocial_support::traits::SpaceSetting;
+
+/// Who can follow a space.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
+pub enum FollowPolicy {
+    /// Everyone can follow the space.
+    #[default]
+    Open,
+    /// Only the space owner and invited accounts can follow the space.
+    InviteOnly,
+}
+
+/// A space setting that defines the follow policy of a space.
+pub struct FollowPolicySetting;
+
+impl SpaceSetting for FollowPolicySetting {
+    const KEY: &'static [u8] = b"space-follows:follow_policy";
+    const VERSION: u16 = 1;
+
+    type Value = FollowPolicy;
+}
+
+/// Who an invitation to follow a space is issued for.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum Invitee<AccountId> {
+    /// Only a given account can redeem the invitation.
+    Account(AccountId),
+    /// Anyone who holds the secret key of a given one-time code keypair can redeem
+    /// the invitation once, by signing their own account with it.
+    CodeKey(sr25519::Public),
+}
+
+pub type InviteeOf<T> = Invitee<<T as frame_system::Config>::AccountId>;
+
+/// The context of a message signed with an invitation code key, see `Invitee::CodeKey`.
+pub const INVITATION_CODE_CONTEXT: &[u8] = b"space-follows:invitation";
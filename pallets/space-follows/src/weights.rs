@@ -49,6 +49,9 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
     fn follow_space() -> Weight;
     fn unfollow_space() -> Weight;
+    fn invite_to_space() -> Weight;
+    fn revoke_invitation() -> Weight;
+    fn follow_space_with_code() -> Weight;
 }
 
 /// Weights for pallet_space_follows using the Substrate node and recommended hardware.
@@ -74,6 +77,37 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
             .saturating_add(T::DbWeight::get().reads(4))
             .saturating_add(T::DbWeight::get().writes(3))
         }
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:2 w:0)
+            // Storage: SpaceFollows SpaceInvitations (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+        fn invite_to_space() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(46_390_000, 0)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(2))
+        }
+            // Storage: SpaceFollows SpaceInvitations (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+        fn revoke_invitation() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(33_671_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+        }
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:1)
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: SpaceFollows SpaceInvitations (r:1 w:1)
+            // Storage: System Account (r:2 w:2)
+            // Storage: SpaceFollows SpaceFollowers (r:1 w:1)
+            // Storage: SpaceFollows SpacesFollowedByAccount (r:1 w:1)
+            // Storage: SpaceFollows SpaceFollowDeposit (r:0 w:1)
+        fn follow_space_with_code() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(126_310_000, 0)
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().writes(7))
+        }
     }
 
     // For backwards compatibility and tests
@@ -98,4 +132,35 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
             .saturating_add(RocksDbWeight::get().reads(4))
             .saturating_add(RocksDbWeight::get().writes(3))
         }
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:2 w:0)
+            // Storage: SpaceFollows SpaceInvitations (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+        fn invite_to_space() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(46_390_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(2))
+        }
+            // Storage: SpaceFollows SpaceInvitations (r:1 w:1)
+            // Storage: System Account (r:1 w:1)
+        fn revoke_invitation() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(33_671_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(2))
+        }
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:1)
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: SpaceFollows SpaceInvitations (r:1 w:1)
+            // Storage: System Account (r:2 w:2)
+            // Storage: SpaceFollows SpaceFollowers (r:1 w:1)
+            // Storage: SpaceFollows SpacesFollowedByAccount (r:1 w:1)
+            // Storage: SpaceFollows SpaceFollowDeposit (r:0 w:1)
+        fn follow_space_with_code() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(126_310_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(7))
+            .saturating_add(RocksDbWeight::get().writes(7))
+        }
     }
@@ -28,6 +28,8 @@ frame_support::construct_runtime!(
 pub(super) type AccountId = u64;
 pub(super) type Balance = u64;
 
+pub(super) const INVITATION_DEPOSIT: Balance = 10;
+
 parameter_types! {
     pub const BlockHashCount: u64 = 250;
     pub const SS58Prefix: u8 = 42;
@@ -101,6 +103,8 @@ parameter_types! {
 impl pallet_roles::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type MaxUsersToProcessPerDeleteRole = MaxUsersToProcessPerDeleteRole;
+    type Currency = Balances;
+    type RoleDeposit = frame_support::traits::ConstU64<0>;
     type SpacePermissionsProvider = Spaces;
     type SpaceFollows = SpaceFollows;
     type IsAccountBlocked = ();
@@ -115,10 +119,23 @@ impl pallet_spaces::Config for Test {
     type IsAccountBlocked = ();
     type IsContentBlocked = ();
     type MaxSpacesPerAccount = ConstU32<100>;
+    type MaxSubspaceDepth = ConstU32<10>;
+    type DomainsProvider = ();
+    type MinHandleLen = ConstU32<5>;
+    type MaxHandleLen = ConstU32<50>;
+    type ProfileManager = ();
+    type CreatorStakingProvider = ();
+    type SpaceDeletionHandler = ();
+    type SpaceSettingsRegistry = SpaceFollows;
+    type Currency = Balances;
+    type SpaceDeposit = frame_support::traits::ConstU64<0>;
+    type MaxSpaceCoOwners = frame_support::traits::ConstU32<3>;
     type WeightInfo = ();
 }
 
 impl pallet_space_follows::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type FollowDeposit = frame_support::traits::ConstU64<0>;
+    type InvitationDeposit = frame_support::traits::ConstU64<INVITATION_DEPOSIT>;
     type WeightInfo = ();
 }
@@ -8,6 +8,7 @@ use crate::{mock::*, tests_utils::*};
 use frame_support::{assert_noop, assert_ok};
 use pallet_space_follows::Error as SpaceFollowsError;
 use pallet_spaces::Error as SpacesError;
+use sp_core::{sr25519, Pair};
 
 #[test]
 fn follow_space_should_work() {
@@ -17,6 +18,7 @@ fn follow_space_should_work() {
         assert_eq!(SpaceFollows::spaces_followed_by_account(ACCOUNT2), vec![SPACE1]);
         assert_eq!(SpaceFollows::space_followers(SPACE1), vec![ACCOUNT2]);
         assert!(SpaceFollows::space_followed_by_account((ACCOUNT2, SPACE1)));
+        assert_eq!(Spaces::space_by_id(SPACE1).unwrap().followers_count, 1);
     });
 }
 
@@ -54,6 +56,7 @@ fn unfollow_space_should_work() {
 
         assert!(SpaceFollows::spaces_followed_by_account(ACCOUNT2).is_empty());
         assert!(SpaceFollows::space_followers(SPACE1).is_empty());
+        assert_eq!(Spaces::space_by_id(SPACE1).unwrap().followers_count, 0);
     });
 }
 #[test]
@@ -69,3 +72,174 @@ fn unfollow_space_should_fail_when_account_is_not_space_follower_yet() {
         assert_noop!(_default_unfollow_space(), SpaceFollowsError::<Test>::NotSpaceFollower);
     });
 }
+
+#[test]
+fn follow_space_should_fail_when_space_is_invite_only_and_account_not_invited() {
+    ExtBuilder::build_with_invite_only_space().execute_with(|| {
+        assert_noop!(_default_follow_space(), SpaceFollowsError::<Test>::InvitationRequired);
+
+        // The space owner doesn't need an invitation
+        assert_ok!(_follow_space(Some(RuntimeOrigin::signed(ACCOUNT1)), None));
+    });
+}
+
+#[test]
+fn follow_space_should_work_with_account_invitation() {
+    ExtBuilder::build_with_invite_only_space().execute_with(|| {
+        assert_ok!(_invite_to_space(None, account_invitee(ACCOUNT2)));
+
+        // Invitation is bound to ACCOUNT2
+        assert_noop!(
+            _follow_space(Some(RuntimeOrigin::signed(ACCOUNT3)), None),
+            SpaceFollowsError::<Test>::InvitationRequired
+        );
+
+        assert_ok!(_default_follow_space());
+        assert!(SpaceFollows::space_followed_by_account((ACCOUNT2, SPACE1)));
+        assert!(SpaceFollows::space_invitation(SPACE1, account_invitee(ACCOUNT2)).is_none());
+    });
+}
+
+#[test]
+fn follow_space_with_code_should_work_only_once() {
+    ExtBuilder::build_with_invite_only_space().execute_with(|| {
+        assert_ok!(_invite_to_space(None, code_invitee()));
+
+        let wrong_code = sr25519::Pair::from_seed(&[8u8; 32]);
+        assert_noop!(
+            _follow_space_with_code(None, invitation_code_signature(&wrong_code, ACCOUNT2)),
+            SpaceFollowsError::<Test>::InvalidInvitationSignature
+        );
+
+        let signature = invitation_code_signature(&invitation_code(), ACCOUNT2);
+        assert_ok!(_follow_space_with_code(None, signature));
+        assert!(SpaceFollows::space_followed_by_account((ACCOUNT2, SPACE1)));
+
+        let signature = invitation_code_signature(&invitation_code(), ACCOUNT3);
+        assert_noop!(
+            _follow_space_with_code(Some(RuntimeOrigin::signed(ACCOUNT3)), signature),
+            SpaceFollowsError::<Test>::InvitationNotFound
+        );
+    });
+}
+
+#[test]
+fn follow_space_with_code_should_fail_with_signature_of_another_account() {
+    ExtBuilder::build_with_invite_only_space().execute_with(|| {
+        assert_ok!(_invite_to_space(None, code_invitee()));
+
+        // A signature seen in a pending ACCOUNT2 transaction cannot be reused by ACCOUNT3
+        let signature = invitation_code_signature(&invitation_code(), ACCOUNT2);
+        assert_noop!(
+            _follow_space_with_code(Some(RuntimeOrigin::signed(ACCOUNT3)), signature),
+            SpaceFollowsError::<Test>::InvalidInvitationSignature
+        );
+    });
+}
+
+#[test]
+fn invitation_deposit_should_be_returned_to_issuer_when_redeemed() {
+    ExtBuilder::build_with_invite_only_space().execute_with(|| {
+        let free_balance = Balances::free_balance(ACCOUNT1);
+
+        assert_ok!(_invite_to_space(None, code_invitee()));
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), INVITATION_DEPOSIT);
+        assert_eq!(Balances::free_balance(ACCOUNT1), free_balance - INVITATION_DEPOSIT);
+
+        let signature = invitation_code_signature(&invitation_code(), ACCOUNT2);
+        assert_ok!(_follow_space_with_code(None, signature));
+
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), 0);
+        assert_eq!(Balances::free_balance(ACCOUNT1), free_balance);
+    });
+}
+
+#[test]
+fn invite_to_space_should_fail_when_account_has_no_permission() {
+    ExtBuilder::build_with_invite_only_space().execute_with(|| {
+        assert_noop!(
+            _invite_to_space(Some(RuntimeOrigin::signed(ACCOUNT2)), account_invitee(ACCOUNT3)),
+            SpaceFollowsError::<Test>::NoPermissionToInviteMembers
+        );
+    });
+}
+
+#[test]
+fn invite_to_space_should_fail_when_invitation_already_exists() {
+    ExtBuilder::build_with_invite_only_space().execute_with(|| {
+        assert_ok!(_invite_to_space(None, account_invitee(ACCOUNT2)));
+        assert_noop!(
+            _invite_to_space(None, account_invitee(ACCOUNT2)),
+            SpaceFollowsError::<Test>::InvitationAlreadyExists
+        );
+    });
+}
+
+#[test]
+fn revoke_invitation_should_work() {
+    ExtBuilder::build_with_invite_only_space().execute_with(|| {
+        assert_ok!(_invite_to_space(None, account_invitee(ACCOUNT2)));
+        assert_ok!(_revoke_invitation(None, account_invitee(ACCOUNT2)));
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), 0);
+
+        assert_noop!(_default_follow_space(), SpaceFollowsError::<Test>::InvitationRequired);
+    });
+}
+
+#[test]
+fn revoke_invitation_should_fail_when_account_is_not_issuer() {
+    ExtBuilder::build_with_invite_only_space().execute_with(|| {
+        assert_ok!(_invite_to_space(None, code_invitee()));
+
+        assert_noop!(
+            _revoke_invitation(Some(RuntimeOrigin::signed(ACCOUNT2)), code_invitee()),
+            SpaceFollowsError::<Test>::NotInvitationIssuer
+        );
+        assert_noop!(
+            _revoke_invitation(None, account_invitee(ACCOUNT2)),
+            SpaceFollowsError::<Test>::InvitationNotFound
+        );
+    });
+}
+
+#[test]
+fn follow_space_as_space_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        // SpaceId 2 owned by ACCOUNT2
+        assert_ok!(_create_space(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None, None));
+
+        assert_ok!(_follow_space_1_as_space_2(None));
+
+        assert!(SpaceFollows::space_followed_by_space(SPACE2, SPACE1).is_some());
+        assert_eq!(SpaceFollows::follower_spaces(SPACE1), vec![SPACE2]);
+        assert_noop!(
+            _follow_space_1_as_space_2(None),
+            SpaceFollowsError::<Test>::AlreadySpaceFollowerSpace
+        );
+
+        assert_ok!(_unfollow_space_1_as_space_2(None));
+
+        assert!(SpaceFollows::space_followed_by_space(SPACE2, SPACE1).is_none());
+        assert!(SpaceFollows::follower_spaces(SPACE1).is_empty());
+        assert_noop!(
+            _unfollow_space_1_as_space_2(None),
+            SpaceFollowsError::<Test>::NotSpaceFollowerSpace
+        );
+    });
+}
+
+#[test]
+fn follow_space_as_space_should_fail_without_permission_to_represent_space() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_space(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None, None));
+
+        assert_noop!(
+            _follow_space_1_as_space_2(Some(RuntimeOrigin::signed(ACCOUNT3))),
+            SpacesError::<Test>::NoPermissionToRepresentSpace
+        );
+        assert_noop!(
+            SpaceFollows::follow_space_as_space(RuntimeOrigin::signed(ACCOUNT1), SPACE1, SPACE1),
+            SpaceFollowsError::<Test>::CannotFollowItself
+        );
+    });
+}
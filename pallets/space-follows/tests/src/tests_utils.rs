@@ -4,13 +4,15 @@
 // Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
 // Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
 
+use codec::Encode;
 use frame_support::{assert_ok, pallet_prelude::*};
 use pallet_permissions::SpacePermissions;
+use pallet_space_follows::types::{FollowPolicy, FollowPolicySetting, Invitee, InviteeOf};
 use pallet_spaces::{types::SpaceUpdate, SpaceById};
-use sp_core::storage::Storage;
+use sp_core::{sr25519, storage::Storage, Pair};
 use sp_io::TestExternalities;
 use sp_runtime::BuildStorage;
-use subsocial_support::{Content, SpaceId};
+use subsocial_support::{traits::SpaceSetting, Content, SpaceId};
 
 use crate::mock::*;
 
@@ -54,6 +56,13 @@ impl ExtBuilder {
         ext
     }
 
+    /// Custom ext configuration with invite-only SpaceId 1 and BlockNumber 1
+    pub fn build_with_invite_only_space() -> TestExternalities {
+        let mut ext = Self::build_with_space();
+        ext.execute_with(|| assert_ok!(_set_follow_policy(FollowPolicy::InviteOnly)));
+        ext
+    }
+
     /// Custom ext configuration with space follow without Space
     pub fn build_with_space_follow_no_space() -> TestExternalities {
         let mut ext = Self::build_with_space();
@@ -74,6 +83,7 @@ pub(crate) const ACCOUNT2: AccountId = 2;
 pub(crate) const ACCOUNT3: AccountId = 3;
 
 pub(crate) const SPACE1: SpaceId = 1001;
+pub(crate) const SPACE2: SpaceId = 1002;
 
 ///////////// Space Utils
 
@@ -144,3 +154,84 @@ pub(crate) fn _unfollow_space(
         space_id.unwrap_or(SPACE1),
     )
 }
+
+pub(crate) fn _follow_space_1_as_space_2(origin: Option<RuntimeOrigin>) -> DispatchResult {
+    SpaceFollows::follow_space_as_space(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT2)),
+        SPACE2,
+        SPACE1,
+    )
+}
+
+pub(crate) fn _unfollow_space_1_as_space_2(origin: Option<RuntimeOrigin>) -> DispatchResult {
+    SpaceFollows::unfollow_space_as_space(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT2)),
+        SPACE2,
+        SPACE1,
+    )
+}
+
+pub(crate) fn _set_follow_policy(policy: FollowPolicy) -> DispatchResult {
+    Spaces::update_space_setting(
+        RuntimeOrigin::signed(ACCOUNT1),
+        SPACE1,
+        FollowPolicySetting::KEY.to_vec(),
+        Some(policy.encode()),
+    )
+}
+
+//// Invitations utils
+
+pub(crate) fn invitation_code() -> sr25519::Pair {
+    sr25519::Pair::from_seed(&[7u8; 32])
+}
+
+/// Sign the follower account with the invitation code, the way the invitee's wallet would do.
+pub(crate) fn invitation_code_signature(
+    code: &sr25519::Pair,
+    follower: AccountId,
+) -> sr25519::Signature {
+    code.sign(&SpaceFollows::invitation_code_payload(SPACE1, &follower))
+}
+
+pub(crate) fn account_invitee(account: AccountId) -> InviteeOf<Test> {
+    Invitee::Account(account)
+}
+
+pub(crate) fn code_invitee() -> InviteeOf<Test> {
+    Invitee::CodeKey(invitation_code().public())
+}
+
+pub(crate) fn _invite_to_space(
+    origin: Option<RuntimeOrigin>,
+    invitee: InviteeOf<Test>,
+) -> DispatchResult {
+    SpaceFollows::invite_to_space(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        SPACE1,
+        invitee,
+    )
+}
+
+pub(crate) fn _revoke_invitation(
+    origin: Option<RuntimeOrigin>,
+    invitee: InviteeOf<Test>,
+) -> DispatchResult {
+    SpaceFollows::revoke_invitation(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        SPACE1,
+        invitee,
+    )
+}
+
+pub(crate) fn _follow_space_with_code(
+    origin: Option<RuntimeOrigin>,
+    signature: sr25519::Signature,
+) -> DispatchResult {
+    SpaceFollows::follow_space_with_code(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT2)),
+        SPACE1,
+        invitation_code().public(),
+        signature,
+    )
+}
@@ -1,38 +1,41 @@
-#![cfg_attr(not(feature = "std"), no_std)]
 // Copyright (C) DAPPFORCE PTE. LTD.
 // SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
 //
 // Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
 // Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
 
+//! Runtime API definition for spaces pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::Codec;
+use sp_runtime::traits::MaybeDisplay;
 use sp_std::vec::Vec;
 
-use pallet_spaces::rpc::FlatSpace;
-use pallet_utils::SpaceId;
+use pallet_spaces::rpc::{FlatSpace, FlatSpaceSetting};
+use subsocial_support::SpaceId;
 
 sp_api::decl_runtime_apis! {
-    pub trait SpacesApi<AccountId, BlockNumber> where
-        AccountId: Codec,
-        BlockNumber: Codec
+    /// Version 2 replaces the owner and handle lookups with `get_space_ids_by_owner`,
+    /// drops `get_next_space_id`, changes the `FlatSpace` layout and adds `get_space_settings`.
+    #[api_version(2)]
+    pub trait SpacesApi<AccountId, BlockNumber>
+        where
+            AccountId: Codec + MaybeDisplay,
+            BlockNumber: Codec + MaybeDisplay,
     {
-        fn get_next_space_id() -> SpaceId;
-
-        fn get_spaces(start_id: u64, limit: u64) -> Vec<FlatSpace<AccountId, BlockNumber>>;
-
         fn get_spaces_by_ids(space_ids: Vec<SpaceId>) -> Vec<FlatSpace<AccountId, BlockNumber>>;
 
-        fn get_public_spaces(start_id: u64, limit: u64) -> Vec<FlatSpace<AccountId, BlockNumber>>;
-
-        fn get_unlisted_spaces(start_id: u64, limit: u64) -> Vec<FlatSpace<AccountId, BlockNumber>>;
+        fn get_spaces(start_id: SpaceId, limit: u64) -> Vec<FlatSpace<AccountId, BlockNumber>>;
 
-        fn get_public_space_ids_by_owner(owner: AccountId) -> Vec<SpaceId>;
+        fn get_public_spaces(start_id: SpaceId, limit: u64) -> Vec<FlatSpace<AccountId, BlockNumber>>;
 
-        fn get_unlisted_space_ids_by_owner(owner: AccountId) -> Vec<SpaceId>;
+        fn get_unlisted_spaces(start_id: SpaceId, limit: u64) -> Vec<FlatSpace<AccountId, BlockNumber>>;
 
-        fn get_space_by_handle(handle: Vec<u8>) -> Option<FlatSpace<AccountId, BlockNumber>>;
+        fn get_space_ids_by_owner(owner: AccountId) -> Vec<SpaceId>;
 
         fn get_space_id_by_handle(handle: Vec<u8>) -> Option<SpaceId>;
+
+        fn get_space_settings(space_id: SpaceId) -> Vec<FlatSpaceSetting>;
     }
 }
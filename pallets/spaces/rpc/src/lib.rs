@@ -4,198 +4,286 @@
 // Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
 // Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
 
-use std::sync::Arc;
+//! RPC interface for the spaces pallet.
+
+use std::{fmt::Display, sync::Arc};
+
 use codec::Codec;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{CallError, ErrorObject},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::{ApiError, ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
-use sp_runtime::{generic::BlockId, traits::Block as BlockT};
-use jsonrpc_core::Result;
-use jsonrpc_derive::rpc;
-use sp_api::ProvideRuntimeApi;
-
-use pallet_spaces::rpc::FlatSpace;
-use pallet_utils::{SpaceId, rpc::map_rpc_error};
-pub use spaces_runtime_api::SpacesApi as SpacesRuntimeApi;
-
-#[rpc]
-pub trait SpacesApi<BlockHash, AccountId, BlockNumber> {
-    #[rpc(name = "spaces_getSpaces")]
-    fn get_spaces(
+use sp_runtime::traits::Block as BlockT;
+
+use pallet_spaces::rpc::{FlatSpace, FlatSpaceSetting, MAX_SPACES_PER_CALL};
+pub use pallet_spaces_rpc_runtime_api::SpacesApi as SpacesRuntimeApi;
+use subsocial_support::SpaceId;
+
+#[rpc(client, server)]
+pub trait SpacesApi<AccountId, BlockNumber, BlockHash> {
+    #[method(name = "spaces_getSpacesByIds")]
+    fn get_spaces_by_ids(
         &self,
+        space_ids: Vec<SpaceId>,
         at: Option<BlockHash>,
-        start_id: u64,
-        limit: u64,
-    ) -> Result<Vec<FlatSpace<AccountId, BlockNumber>>>;
+    ) -> RpcResult<Vec<FlatSpace<AccountId, BlockNumber>>>;
 
-    #[rpc(name = "spaces_getSpacesByIds")]
-    fn get_spaces_by_ids(
+    /// Get up to `limit` spaces, starting from `start_id` and going down to the first space.
+    /// At most `MAX_SPACES_PER_CALL` spaces are returned.
+    #[method(name = "spaces_getSpaces")]
+    fn get_spaces(
         &self,
+        start_id: SpaceId,
+        limit: u64,
         at: Option<BlockHash>,
-        space_ids: Vec<SpaceId>,
-    ) -> Result<Vec<FlatSpace<AccountId, BlockNumber>>>;
+    ) -> RpcResult<Vec<FlatSpace<AccountId, BlockNumber>>>;
 
-    #[rpc(name = "spaces_getPublicSpaces")]
+    /// Same as `spaces_getSpaces`, but only visible spaces with content are returned.
+    #[method(name = "spaces_getPublicSpaces")]
     fn get_public_spaces(
         &self,
-        at: Option<BlockHash>,
-        start_id: u64,
+        start_id: SpaceId,
         limit: u64,
-    ) -> Result<Vec<FlatSpace<AccountId, BlockNumber>>>;
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<FlatSpace<AccountId, BlockNumber>>>;
 
-    #[rpc(name = "spaces_getUnlistedSpaces")]
+    /// Same as `spaces_getSpaces`, but only hidden spaces or spaces without content are returned.
+    #[method(name = "spaces_getUnlistedSpaces")]
     fn get_unlisted_spaces(
         &self,
-        at: Option<BlockHash>,
-        start_id: u64,
+        start_id: SpaceId,
         limit: u64,
-    ) -> Result<Vec<FlatSpace<AccountId, BlockNumber>>>;
-
-    #[rpc(name = "spaces_getSpaceIdByHandle")]
-    fn get_space_id_by_handle(
-        &self,
         at: Option<BlockHash>,
-        handle: Vec<u8>,
-    ) -> Result<Option<SpaceId>>;
+    ) -> RpcResult<Vec<FlatSpace<AccountId, BlockNumber>>>;
 
-    #[rpc(name = "spaces_getSpaceByHandle")]
-    fn get_space_by_handle(
+    #[method(name = "spaces_getSpaceIdsByOwner")]
+    fn get_space_ids_by_owner(
         &self,
+        owner: AccountId,
         at: Option<BlockHash>,
-        handle: Vec<u8>,
-    ) -> Result<Option<FlatSpace<AccountId, BlockNumber>>>;
+    ) -> RpcResult<Vec<SpaceId>>;
 
-    #[rpc(name = "spaces_getPublicSpaceIdsByOwner")]
-    fn get_public_space_ids_by_owner(
+    #[method(name = "spaces_getSpaceIdByHandle")]
+    fn get_space_id_by_handle(
         &self,
+        handle: Vec<u8>,
         at: Option<BlockHash>,
-        owner: AccountId,
-    ) -> Result<Vec<SpaceId>>;
+    ) -> RpcResult<Option<SpaceId>>;
 
-    #[rpc(name = "spaces_getUnlistedSpaceIdsByOwner")]
-    fn get_unlisted_space_ids_by_owner(
+    /// Get the effective values of all registered settings in a space.
+    #[method(name = "spaces_getSpaceSettings")]
+    fn get_space_settings(
         &self,
+        space_id: SpaceId,
         at: Option<BlockHash>,
-        owner: AccountId,
-    ) -> Result<Vec<SpaceId>>;
-
-    #[rpc(name = "spaces_nextSpaceId")]
-    fn get_next_space_id(&self, at: Option<BlockHash>) -> Result<SpaceId>;
+    ) -> RpcResult<Vec<FlatSpaceSetting>>;
 }
 
-pub struct Spaces<C, M> {
+/// Provides RPC methods for spaces pallet.
+pub struct Spaces<C, P> {
+    /// Shared reference to the client.
     client: Arc<C>,
-    _marker: std::marker::PhantomData<M>,
+    _marker: std::marker::PhantomData<P>,
 }
 
-impl<C, M> Spaces<C, M> {
+impl<C, P> Spaces<C, P> {
+    /// Creates a new instance of the Spaces Rpc helper.
     pub fn new(client: Arc<C>) -> Self {
-        Self {
-            client,
-            _marker: Default::default(),
+        Self { client, _marker: Default::default() }
+    }
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+    /// The call to runtime failed.
+    RuntimeError,
+    /// The runtime does not implement the required version of the spaces API.
+    UnsupportedApiVersion,
+}
+
+impl From<Error> for i32 {
+    fn from(e: Error) -> i32 {
+        match e {
+            Error::RuntimeError => 1,
+            Error::UnsupportedApiVersion => 2,
         }
     }
 }
 
-impl<C, Block, AccountId, BlockNumber> SpacesApi<<Block as BlockT>::Hash, AccountId, BlockNumber>
-    for Spaces<C, Block>
+impl<C, Block, AccountId, BlockNumber>
+    SpacesApiServer<AccountId, BlockNumber, <Block as BlockT>::Hash> for Spaces<C, Block>
 where
     Block: BlockT,
-    AccountId: Codec,
-    BlockNumber: Codec,
-    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
     C::Api: SpacesRuntimeApi<Block, AccountId, BlockNumber>,
+    AccountId: Clone + Display + Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+    BlockNumber: Clone + Display + Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
 {
-    fn get_spaces(
+    fn get_spaces_by_ids(
         &self,
-        at: Option<<Block as BlockT>::Hash>,
-        start_id: u64,
-        limit: u64,
-    ) -> Result<Vec<FlatSpace<AccountId, BlockNumber>>> {
+        space_ids: Vec<SpaceId>,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<FlatSpace<AccountId, BlockNumber>>> {
         let api = self.client.runtime_api();
-        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version(
+            api.api_version::<dyn SpacesRuntimeApi<Block, AccountId, BlockNumber>>(at_hash),
+            SPACES_API_V2,
+        )?;
 
-        let runtime_api_result = api.get_spaces(&at, start_id, limit);
-        runtime_api_result.map_err(map_rpc_error)
+        let res = api
+            .get_spaces_by_ids(at_hash, space_ids)
+            .map_err(|e| map_err(e, "Unable to get spaces by ids."))?;
+
+        Ok(res)
     }
 
-    fn get_spaces_by_ids(
+    fn get_spaces(
         &self,
-        at: Option<<Block as BlockT>::Hash>,
-        space_ids: Vec<SpaceId>,
-    ) -> Result<Vec<FlatSpace<AccountId, BlockNumber>>> {
+        start_id: SpaceId,
+        limit: u64,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<FlatSpace<AccountId, BlockNumber>>> {
         let api = self.client.runtime_api();
-        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version(
+            api.api_version::<dyn SpacesRuntimeApi<Block, AccountId, BlockNumber>>(at_hash),
+            SPACES_API_V2,
+        )?;
+
+        let res = api
+            .get_spaces(at_hash, start_id, limit.min(MAX_SPACES_PER_CALL))
+            .map_err(|e| map_err(e, "Unable to get spaces."))?;
 
-        let runtime_api_result = api.get_spaces_by_ids(&at, space_ids);
-        runtime_api_result.map_err(map_rpc_error)
+        Ok(res)
     }
 
     fn get_public_spaces(
         &self,
-        at: Option<<Block as BlockT>::Hash>,
-        start_id: u64,
+        start_id: SpaceId,
         limit: u64,
-    ) -> Result<Vec<FlatSpace<AccountId, BlockNumber>>> {
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<FlatSpace<AccountId, BlockNumber>>> {
         let api = self.client.runtime_api();
-        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version(
+            api.api_version::<dyn SpacesRuntimeApi<Block, AccountId, BlockNumber>>(at_hash),
+            SPACES_API_V2,
+        )?;
+
+        let res = api
+            .get_public_spaces(at_hash, start_id, limit.min(MAX_SPACES_PER_CALL))
+            .map_err(|e| map_err(e, "Unable to get public spaces."))?;
 
-        let runtime_api_result = api.get_public_spaces(&at, start_id, limit);
-        runtime_api_result.map_err(map_rpc_error)
+        Ok(res)
     }
 
     fn get_unlisted_spaces(
         &self,
-        at: Option<<Block as BlockT>::Hash>,
-        start_id: u64,
+        start_id: SpaceId,
         limit: u64,
-    ) -> Result<Vec<FlatSpace<AccountId, BlockNumber>>> {
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<FlatSpace<AccountId, BlockNumber>>> {
         let api = self.client.runtime_api();
-        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version(
+            api.api_version::<dyn SpacesRuntimeApi<Block, AccountId, BlockNumber>>(at_hash),
+            SPACES_API_V2,
+        )?;
 
-        let runtime_api_result = api.get_unlisted_spaces(&at, start_id, limit);
-        runtime_api_result.map_err(map_rpc_error)
+        let res = api
+            .get_unlisted_spaces(at_hash, start_id, limit.min(MAX_SPACES_PER_CALL))
+            .map_err(|e| map_err(e, "Unable to get unlisted spaces."))?;
+
+        Ok(res)
     }
 
-    fn get_space_id_by_handle(&self, at: Option<<Block as BlockT>::Hash>, handle: Vec<u8>) -> Result<Option<u64>> {
+    fn get_space_ids_by_owner(
+        &self,
+        owner: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<SpaceId>> {
         let api = self.client.runtime_api();
-        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version(
+            api.api_version::<dyn SpacesRuntimeApi<Block, AccountId, BlockNumber>>(at_hash),
+            SPACES_API_V2,
+        )?;
+
+        let res = api
+            .get_space_ids_by_owner(at_hash, owner)
+            .map_err(|e| map_err(e, "Unable to get space ids by owner."))?;
 
-        let runtime_api_result = api.get_space_id_by_handle(&at, handle);
-        runtime_api_result.map_err(map_rpc_error)
+        Ok(res)
     }
 
-    fn get_space_by_handle(
+    fn get_space_id_by_handle(
         &self,
-        at: Option<<Block as BlockT>::Hash>,
         handle: Vec<u8>,
-    ) -> Result<Option<FlatSpace<AccountId, BlockNumber>>> {
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<SpaceId>> {
         let api = self.client.runtime_api();
-        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version(
+            api.api_version::<dyn SpacesRuntimeApi<Block, AccountId, BlockNumber>>(at_hash),
+            SPACES_API_V2,
+        )?;
+
+        let res = api
+            .get_space_id_by_handle(at_hash, handle)
+            .map_err(|e| map_err(e, "Unable to get space id by handle."))?;
 
-        let runtime_api_result = api.get_space_by_handle(&at, handle);
-        runtime_api_result.map_err(map_rpc_error)
+        Ok(res)
     }
 
-    fn get_public_space_ids_by_owner(&self, at: Option<<Block as BlockT>::Hash>, owner: AccountId) -> Result<Vec<u64>> {
+    fn get_space_settings(
+        &self,
+        space_id: SpaceId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Vec<FlatSpaceSetting>> {
         let api = self.client.runtime_api();
-        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+        ensure_api_version(
+            api.api_version::<dyn SpacesRuntimeApi<Block, AccountId, BlockNumber>>(at_hash),
+            SPACES_API_V2,
+        )?;
 
-        let runtime_api_result = api.get_public_space_ids_by_owner(&at, owner);
-        runtime_api_result.map_err(map_rpc_error)
+        let res = api
+            .get_space_settings(at_hash, space_id)
+            .map_err(|e| map_err(e, "Unable to get space settings."))?;
+
+        Ok(res)
     }
+}
 
-    fn get_unlisted_space_ids_by_owner(&self, at: Option<<Block as BlockT>::Hash>, owner: AccountId) -> Result<Vec<u64>> {
-        let api = self.client.runtime_api();
-        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+/// The first version of `SpacesApi` with the current method set and `FlatSpace` layout.
+const SPACES_API_V2: u32 = 2;
+
+fn ensure_api_version(
+    version: Result<Option<u32>, ApiError>,
+    required: u32,
+) -> Result<(), CallError> {
+    let version = version.map_err(|e| map_err(e, "Unable to get spaces API version."))?;
 
-        let runtime_api_result = api.get_unlisted_space_ids_by_owner(&at, owner);
-        runtime_api_result.map_err(map_rpc_error)
+    if version.map_or(true, |v| v < required) {
+        return Err(CallError::Custom(ErrorObject::owned(
+            Error::UnsupportedApiVersion.into(),
+            "The runtime does not support this method of the spaces API.",
+            version.map(|v| format!("Required version {}, runtime has {}", required, v)),
+        )))
     }
 
-    fn get_next_space_id(&self, at: Option<<Block as BlockT>::Hash>) -> Result<u64> {
-        let api = self.client.runtime_api();
-        let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
+    Ok(())
+}
 
-        let runtime_api_result = api.get_next_space_id(&at);
-        runtime_api_result.map_err(map_rpc_error)
-    }
+fn map_err(error: impl ToString, desc: &'static str) -> CallError {
+    CallError::Custom(ErrorObject::owned(
+        Error::RuntimeError.into(),
+        desc,
+        Some(error.to_string()),
+    ))
 }
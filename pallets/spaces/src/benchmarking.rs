@@ -6,9 +6,13 @@
 
 //! Spaces pallet benchmarking.
 
-use frame_benchmarking::{benchmarks, whitelisted_caller};
-use frame_support::{assert_ok, ensure, pallet_prelude::Get};
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{assert_ok, ensure, pallet_prelude::Get, traits::Currency};
 use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+use sp_std::vec;
+
+use subsocial_support::traits::SpaceSettingsRegistry;
 
 use crate::{types::*, Config};
 
@@ -19,6 +23,7 @@ fn dummy_space_content() -> Content {
 }
 
 fn create_dummy_space<T: Config>(caller: T::AccountId) -> Space<T> {
+    T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
     assert_ok!(Pallet::<T>::create_space(RawOrigin::Signed(caller).into(), Content::None, None));
     let id = Pallet::<T>::next_space_id() - 1;
 
@@ -60,4 +65,174 @@ benchmarks! {
         assert!(space_from_storage.edited);
         assert!(space_from_storage.permissions.is_some());
     }
+
+    create_subspace {
+        let caller = whitelisted_caller::<T::AccountId>();
+
+        let parent_space = create_dummy_space::<T>(caller.clone());
+        let new_space_id = NextSpaceId::<T>::get();
+
+        let content = dummy_space_content();
+        let permissions_opt = Some(<T as pallet_permissions::Config>::DefaultSpacePermissions::get());
+    }: _(RawOrigin::Signed(caller), parent_space.id, content, permissions_opt)
+    verify {
+        let space = SpaceById::<T>::get(new_space_id).expect("Created subspace should exist");
+        ensure!(space.parent_id == Some(parent_space.id), "Subspace should have a parent");
+    }
+
+    move_space {
+        let caller = whitelisted_caller::<T::AccountId>();
+
+        let old_parent_space = create_dummy_space::<T>(caller.clone());
+        let new_parent_space = create_dummy_space::<T>(caller.clone());
+        let space_id = NextSpaceId::<T>::get();
+
+        assert_ok!(Pallet::<T>::create_subspace(
+            RawOrigin::Signed(caller.clone()).into(),
+            old_parent_space.id,
+            Content::None,
+            None,
+        ));
+    }: _(RawOrigin::Signed(caller), space_id, Some(new_parent_space.id))
+    verify {
+        let space = SpaceById::<T>::get(space_id).expect("Moved space should exist");
+        ensure!(space.parent_id == Some(new_parent_space.id), "Space should be moved");
+        ensure!(
+            SubspaceIdsByParentId::<T>::get(old_parent_space.id).is_empty(),
+            "Space should be removed from its old parent"
+        );
+    }
+
+    set_space_handle {
+        let caller = whitelisted_caller::<T::AccountId>();
+
+        let space = create_dummy_space::<T>(caller.clone());
+        let handle = vec![b'a'; T::MaxHandleLen::get() as usize];
+    }: _(RawOrigin::Signed(caller), space.id, Some(handle.clone()))
+    verify {
+        ensure!(SpaceIdByHandle::<T>::get(handle) == Some(space.id), "Handle should be set");
+    }
+
+    delete_space {
+        let caller = whitelisted_caller::<T::AccountId>();
+
+        let parent_space = create_dummy_space::<T>(caller.clone());
+        let space_id = NextSpaceId::<T>::get();
+
+        assert_ok!(Pallet::<T>::create_subspace(
+            RawOrigin::Signed(caller.clone()).into(),
+            parent_space.id,
+            Content::None,
+            None,
+        ));
+        assert_ok!(Pallet::<T>::set_space_handle(
+            RawOrigin::Signed(caller.clone()).into(),
+            space_id,
+            Some(vec![b'a'; T::MaxHandleLen::get() as usize]),
+        ));
+
+        // Every owner of the deleted space is unlinked from it.
+        for i in 0 .. T::MaxSpaceCoOwners::get() {
+            let co_owner = account::<T::AccountId>("co_owner", i, 0);
+            Pallet::<T>::propose_space_owner(
+                RawOrigin::Signed(caller.clone()).into(),
+                space_id,
+                co_owner.clone(),
+            )?;
+            Pallet::<T>::accept_space_ownership(RawOrigin::Signed(co_owner).into(), space_id)?;
+        }
+    }: _(RawOrigin::Signed(caller), space_id)
+    verify {
+        ensure!(SpaceById::<T>::get(space_id).is_none(), "Deleted space should not exist");
+        ensure!(
+            Pallet::<T>::is_space_pending_cleanup(space_id),
+            "Deleted space should be waiting for a cleanup"
+        );
+    }
+
+    // The data of a deleted space is weighed by its `SpaceDeletionHandler`,
+    // so an empty space is deleted here to weigh only the work of this pallet.
+    continue_space_deletion {
+        let caller = whitelisted_caller::<T::AccountId>();
+
+        let space = create_dummy_space::<T>(caller.clone());
+        assert_ok!(Pallet::<T>::delete_space(RawOrigin::Signed(caller.clone()).into(), space.id));
+    }: _(RawOrigin::Signed(caller), space.id, 1)
+    verify {
+        ensure!(
+            !Pallet::<T>::is_space_pending_cleanup(space.id),
+            "Deleted space should be cleaned up"
+        );
+    }
+
+    update_space_setting {
+        let caller = whitelisted_caller::<T::AccountId>();
+
+        let space = create_dummy_space::<T>(caller.clone());
+        let setting = T::SpaceSettingsRegistry::registered_settings()
+            .pop()
+            .ok_or("There should be at least one registered space setting")?;
+    }: _(RawOrigin::Signed(caller), space.id, setting.key.clone(), Some(setting.default_value))
+    verify {
+        ensure!(
+            SpaceSettingByKey::<T>::contains_key(space.id, setting.key),
+            "Space setting should be stored"
+        );
+    }
+
+    set_space_archived {
+        let caller = whitelisted_caller::<T::AccountId>();
+
+        let space = create_dummy_space::<T>(caller.clone());
+    }: _(RawOrigin::Signed(caller), space.id, true)
+    verify {
+        let space = SpaceById::<T>::get(space.id).ok_or("Space should exist")?;
+        ensure!(space.archived, "Space should be archived");
+    }
+
+    propose_space_owner {
+        let caller = whitelisted_caller::<T::AccountId>();
+        let new_owner = account::<T::AccountId>("new_owner", 1, 0);
+
+        let space = create_dummy_space::<T>(caller.clone());
+    }: _(RawOrigin::Signed(caller.clone()), space.id, new_owner.clone())
+    verify {
+        ensure!(
+            PendingSpaceOwners::<T>::get(space.id, &new_owner) == Some(caller),
+            "Space owner proposal should be stored"
+        );
+    }
+
+    accept_space_ownership {
+        let caller = whitelisted_caller::<T::AccountId>();
+        let new_owner = account::<T::AccountId>("new_owner", 1, 0);
+
+        let space = create_dummy_space::<T>(caller.clone());
+        Pallet::<T>::propose_space_owner(
+            RawOrigin::Signed(caller).into(),
+            space.id,
+            new_owner.clone(),
+        )?;
+    }: _(RawOrigin::Signed(new_owner.clone()), space.id)
+    verify {
+        let space = SpaceById::<T>::get(space.id).ok_or("Space should exist")?;
+        ensure!(space.co_owners.contains(&new_owner), "Account should be a co-owner");
+    }
+
+    remove_space_owner {
+        let caller = whitelisted_caller::<T::AccountId>();
+        let co_owner = account::<T::AccountId>("co_owner", 1, 0);
+
+        let space = create_dummy_space::<T>(caller.clone());
+        Pallet::<T>::propose_space_owner(
+            RawOrigin::Signed(caller.clone()).into(),
+            space.id,
+            co_owner.clone(),
+        )?;
+        Pallet::<T>::accept_space_ownership(RawOrigin::Signed(co_owner.clone()).into(), space.id)?;
+    }: _(RawOrigin::Signed(caller), space.id, co_owner.clone())
+    verify {
+        let space = SpaceById::<T>::get(space.id).ok_or("Space should exist")?;
+        ensure!(!space.co_owners.contains(&co_owner), "Account should not be a co-owner");
+    }
 }
@@ -24,6 +24,39 @@
 //! - Channels on YouTube,
 //! - Servers on Discord,
 //! - Forums on Discourse.
+//!
+//! A space can be created as a subspace of another space. A subspace without its own permissions
+//! inherits the permissions of its parent, while the parent space controls who can create, update,
+//! hide and move its subspaces, as well as override their permissions.
+//!
+//! A space owner can give the space a unique handle, so that the space can be found by it.
+//! A handle is either native (letters, digits and underscores) or a domain name
+//! registered in the domains pallet and owned by the space owner. Handles are case-insensitive.
+//! A domain handle is transferred together with the space ownership.
+//!
+//! A space that has no subspaces can be deleted. The space itself is removed immediately,
+//! while its posts, roles, follows and other related data are cleaned up in weight-bounded
+//! chunks, either when blocks have spare weight or via `continue_space_deletion`.
+//!
+//! A space can be archived by an account with the `UpdateSpace` permission. An archived space
+//! is read-only: no new posts, comments, reactions, follows or role grants are accepted in it,
+//! while it can still be read and transferred. The owner can unarchive the space at any time.
+//!
+//! A space can have up to `MaxSpaceCoOwners` co-owners, who have the same rights as its owner.
+//! Any owner can propose a new co-owner, who becomes one after accepting the proposal,
+//! and can remove co-owners. The owner, who holds the storage deposit of the space, cannot be
+//! removed, but any owner can transfer their seat with the ownership pallet.
+//!
+//! Creating a space reserves `SpaceDeposit` from its owner. The deposit moves to a new owner
+//! together with the space and is returned when the space is deleted, or when root gives
+//! the space to another owner with `force_create_space`.
+//!
+//! Every space keeps track of the number of its posts, hidden posts and followers.
+//! These counters are maintained by the posts and space follows pallets.
+//!
+//! Other pallets can register typed settings of spaces, such as a comments policy,
+//! via the `SpaceSettingsRegistry` trait. The settings are stored here per space
+//! and can be updated by accounts with the `UpdateSpaceSettings` permission.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -35,12 +68,15 @@ use subsocial_support::{traits::SpaceFollowsProvider, Content, SpaceId};
 mod benchmarking;
 pub mod weights;
 
-// pub mod rpc;
+pub mod migration;
+pub mod rpc;
 pub mod types;
 
+pub const LOG_TARGET: &str = "runtime::spaces";
+
 #[frame_support::pallet]
 pub mod pallet {
-    use frame_support::pallet_prelude::*;
+    use frame_support::{pallet_prelude::*, traits::ReservableCurrency};
     use frame_system::pallet_prelude::*;
     use sp_std::vec::Vec;
 
@@ -48,9 +84,13 @@ pub mod pallet {
         Pallet as Permissions, PermissionChecker, SpacePermissionsContext, SpacePermissionsInfoOf,
     };
     use subsocial_support::{
-        ensure_content_is_valid, remove_from_bounded_vec,
-        traits::{IsAccountBlocked, IsContentBlocked, SpacePermissionsProvider, SpacesProvider},
-        ModerationError, SpacePermissionsInfo, WhoAndWhen, WhoAndWhenOf,
+        ensure_content_is_valid, remove_from_bounded_vec, remove_from_vec,
+        traits::{
+            CreatorStakingProvider, DomainsProvider, IsAccountBlocked, IsContentBlocked,
+            ProfileManager, SpaceDeletionHandler, SpacePermissionsProvider, SpaceSetting,
+            SpaceSettingsRegistry, SpacesProvider,
+        },
+        ModerationError, SpacePermissionsInfo, StorageDeposit, WhoAndWhen, WhoAndWhenOf,
     };
     use types::*;
 
@@ -76,12 +116,53 @@ pub mod pallet {
         #[pallet::constant]
         type MaxSpacesPerAccount: Get<u32>;
 
+        /// The maximum number of ancestors a subspace can have.
+        #[pallet::constant]
+        type MaxSubspaceDepth: Get<u32>;
+
+        /// Domains that can be used as space handles.
+        type DomainsProvider: DomainsProvider<Self::AccountId>;
+
+        /// The minimum length of a native space handle.
+        #[pallet::constant]
+        type MinHandleLen: Get<u32>;
+
+        /// The maximum length of a native space handle.
+        #[pallet::constant]
+        type MaxHandleLen: Get<u32>;
+
+        type ProfileManager: ProfileManager<Self::AccountId>;
+
+        /// Tells whether a space is registered as an active creator, so it cannot be deleted.
+        type CreatorStakingProvider: CreatorStakingProvider<Self::AccountId>;
+
+        /// Cleans up the data of other pallets related to a deleted space.
+        type SpaceDeletionHandler: SpaceDeletionHandler;
+
+        /// Settings of spaces registered by other pallets.
+        type SpaceSettingsRegistry: SpaceSettingsRegistry;
+
+        /// The currency in which storage deposits of spaces and related items are reserved.
+        type Currency: ReservableCurrency<Self::AccountId>;
+
+        /// The amount reserved from an owner for storing a space.
+        #[pallet::constant]
+        type SpaceDeposit: Get<BalanceOf<Self>>;
+
+        /// The maximum number of co-owners a space can have in addition to its owner.
+        #[pallet::constant]
+        type MaxSpaceCoOwners: Get<u32>;
+
         /// Weight information for extrinsics in this pallet.
         type WeightInfo: WeightInfo;
     }
 
+    /// The current storage version
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
+
     #[pallet::pallet]
     #[pallet::without_storage_info]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::event]
@@ -89,6 +170,47 @@ pub mod pallet {
     pub enum Event<T: Config> {
         SpaceCreated { account: T::AccountId, space_id: SpaceId },
         SpaceUpdated { account: T::AccountId, space_id: SpaceId },
+        SpaceMoved {
+            account: T::AccountId,
+            space_id: SpaceId,
+            from_parent: Option<SpaceId>,
+            to_parent: Option<SpaceId>,
+        },
+        SpaceHandleUpdated {
+            account: T::AccountId,
+            space_id: SpaceId,
+            handle: Option<Vec<u8>>,
+        },
+        SpaceDeleted { account: T::AccountId, space_id: SpaceId },
+        /// All data related to a deleted space has been cleaned up.
+        SpaceDeletionCompleted { space_id: SpaceId },
+        /// A space setting has been updated, or reset to its default value if `value` is `None`.
+        SpaceSettingUpdated {
+            account: T::AccountId,
+            space_id: SpaceId,
+            key: Vec<u8>,
+            value: Option<Vec<u8>>,
+        },
+        /// A space has been archived: it stays readable, but its content can no longer change.
+        SpaceArchived { account: T::AccountId, space_id: SpaceId },
+        /// An archived space has been made writable again.
+        SpaceUnarchived { account: T::AccountId, space_id: SpaceId },
+        SpaceOwnerProposed {
+            account: T::AccountId,
+            space_id: SpaceId,
+            new_owner: T::AccountId,
+        },
+        SpaceOwnerProposalCancelled {
+            account: T::AccountId,
+            space_id: SpaceId,
+            new_owner: T::AccountId,
+        },
+        SpaceOwnerAdded { account: T::AccountId, space_id: SpaceId },
+        SpaceOwnerRemoved {
+            account: T::AccountId,
+            space_id: SpaceId,
+            owner: T::AccountId,
+        },
     }
 
     #[pallet::error]
@@ -109,6 +231,58 @@ pub mod pallet {
         NoUpdatesForSpacesSettings,
         /// There are too many spaces created by this account already
         TooManySpacesPerAccount,
+        /// Not allowed to create a subspace when the parent space is hidden.
+        CannotCreateInHiddenScope,
+        /// User has no permission to update own subspaces within this space.
+        NoPermissionToUpdateOwnSubspaces,
+        /// User has no permission to hide own subspaces within this space.
+        NoPermissionToHideOwnSubspaces,
+        /// User has no permission to hide any subspace within this space.
+        NoPermissionToHideAnySubspace,
+        /// User has no permission to override permissions of subspaces within this space.
+        NoPermissionToOverrideSubspacePermissions,
+        /// The maximum depth of nested subspaces has been reached.
+        MaxSubspaceDepthReached,
+        /// Cannot move a space to the parent space it already belongs to.
+        CannotMoveToSameParent,
+        /// Cannot move a space under itself.
+        CannotMoveSpaceUnderItself,
+        /// Only a space that has no subspaces can be moved.
+        CannotMoveSpaceWithSubspaces,
+        /// Space handle is already taken by another space.
+        HandleIsNotUnique,
+        /// Only a space that has no subspaces can be deleted.
+        CannotDeleteSpaceWithSubspaces,
+        /// User has no permission to delete own subspaces within this space.
+        NoPermissionToDeleteOwnSubspaces,
+        /// User has no permission to delete any subspace within this space.
+        NoPermissionToDeleteAnySubspace,
+        /// There is no deleted space with such id waiting for a cleanup.
+        SpaceIsNotBeingDeleted,
+        /// User has no permission to update settings of this space.
+        NoPermissionToUpdateSpaceSettings,
+        /// There is no space setting registered with such key.
+        UnknownSpaceSetting,
+        /// The value is not valid for this space setting.
+        InvalidSpaceSettingValue,
+        /// Space is archived and does not accept any new content.
+        SpaceIsArchived,
+        /// Account is already an owner of this space.
+        AlreadyASpaceOwner,
+        /// Account has already been proposed as a co-owner of this space.
+        SpaceOwnerAlreadyProposed,
+        /// There is no pending proposal for this account to become a co-owner of this space.
+        NoPendingSpaceOwnerProposal,
+        /// This space has the maximum number of co-owners already.
+        TooManySpaceCoOwners,
+        /// The owner of a space, who holds its deposit, cannot be removed from the space.
+        CannotRemoveSpaceOwner,
+        /// Account is not a co-owner of this space.
+        NotASpaceCoOwner,
+        /// User has no permission to act on behalf of this space.
+        NoPermissionToRepresentSpace,
+        /// Cannot delete a space, because it is registered as an active creator.
+        ActiveCreatorCannotBeDeleted,
     }
 
     #[pallet::type_value]
@@ -132,6 +306,61 @@ pub mod pallet {
     pub type SpaceIdsByOwner<T: Config> =
         StorageMap<_, Twox64Concat, T::AccountId, SpacesByAccount<T>, ValueQuery>;
 
+    /// Get the ids of all direct subspaces of a given space.
+    #[pallet::storage]
+    #[pallet::getter(fn subspace_ids_by_parent_id)]
+    pub type SubspaceIdsByParentId<T: Config> =
+        StorageMap<_, Twox64Concat, SpaceId, Vec<SpaceId>, ValueQuery>;
+
+    /// Find the id of a space by its lowercased handle.
+    #[pallet::storage]
+    #[pallet::getter(fn space_id_by_handle)]
+    pub type SpaceIdByHandle<T: Config> = StorageMap<_, Blake2_128Concat, Vec<u8>, SpaceId>;
+
+    /// Get the lowercased handle of a space by its id.
+    #[pallet::storage]
+    #[pallet::getter(fn handle_by_space_id)]
+    pub type HandleBySpaceId<T: Config> = StorageMap<_, Twox64Concat, SpaceId, Vec<u8>>;
+
+    /// The ids of deleted spaces, whose related data is not cleaned up yet,
+    /// by their position in the queue of deleted spaces.
+    #[pallet::storage]
+    pub type SpaceIdsPendingCleanup<T: Config> = StorageMap<_, Twox64Concat, u64, SpaceId>;
+
+    /// The position of the first space in the queue of deleted spaces.
+    #[pallet::storage]
+    pub type SpaceCleanupQueueHead<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// The position of the next space added to the queue of deleted spaces.
+    #[pallet::storage]
+    pub type SpaceCleanupQueueTail<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Whether the related data of a deleted space is not cleaned up yet.
+    #[pallet::storage]
+    #[pallet::getter(fn is_space_pending_cleanup)]
+    pub type SpacePendingCleanup<T: Config> =
+        StorageMap<_, Twox64Concat, SpaceId, bool, ValueQuery>;
+
+    /// The accounts proposed to become co-owners of a given space, along with the owners
+    /// who proposed them. A proposal is removed once it is accepted or cancelled.
+    #[pallet::storage]
+    #[pallet::getter(fn pending_space_owner)]
+    pub type PendingSpaceOwners<T: Config> =
+        StorageDoubleMap<_, Twox64Concat, SpaceId, Blake2_128Concat, T::AccountId, T::AccountId>;
+
+    /// Get the value of a space setting by a space id and a setting key.
+    /// Settings that are not stored here have their default values.
+    #[pallet::storage]
+    #[pallet::getter(fn space_setting_by_key)]
+    pub type SpaceSettingByKey<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        SpaceId,
+        Blake2_128Concat,
+        Vec<u8>,
+        VersionedSettingValue,
+    >;
+
     #[derive(scale_info::TypeInfo, Debug)]
     #[pallet::genesis_config]
     pub struct GenesisConfig<T: Config> {
@@ -152,6 +381,17 @@ pub mod pallet {
         }
     }
 
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_now: BlockNumberFor<T>) -> Weight {
+            migration::v5::migrate_next_spaces::<T>()
+        }
+
+        fn on_idle(_now: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+            Self::cleanup_deleted_spaces(remaining_weight)
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::call_index(0)]
@@ -163,7 +403,7 @@ pub mod pallet {
         ) -> DispatchResult {
             let owner = ensure_signed(origin)?;
 
-            Self::do_create_space(&owner, content, permissions_opt)?;
+            Self::do_create_space(&owner, None, content, permissions_opt)?;
             Ok(())
         }
 
@@ -188,12 +428,23 @@ pub mod pallet {
                 ModerationError::AccountIsBlocked
             );
 
-            Self::ensure_account_has_space_permission(
-                owner.clone(),
-                &space,
-                SpacePermission::UpdateSpace,
-                Error::<T>::NoPermissionToUpdateSpace.into(),
-            )?;
+            let parent_opt = space.parent_id.map(Self::require_space).transpose()?;
+
+            if let Some(parent) = &parent_opt {
+                if update.content.is_some() || update.permissions.is_some() {
+                    Self::ensure_account_can_update_subspace(&owner, &space, parent)?;
+                }
+                if update.hidden.is_some() {
+                    Self::ensure_account_can_hide_subspace(&owner, &space, parent)?;
+                }
+            } else {
+                Self::ensure_account_has_space_permission(
+                    owner.clone(),
+                    &space,
+                    SpacePermission::UpdateSpace,
+                    Error::<T>::NoPermissionToUpdateSpace.into(),
+                )?;
+            }
 
             let mut is_update_applied = false;
 
@@ -221,6 +472,15 @@ pub mod pallet {
 
             if let Some(overrides_opt) = update.permissions {
                 if space.permissions != overrides_opt {
+                    if let Some(parent) = &parent_opt {
+                        Self::ensure_account_has_space_permission(
+                            owner.clone(),
+                            parent,
+                            SpacePermission::OverrideSubspacePermissions,
+                            Error::<T>::NoPermissionToOverrideSubspacePermissions.into(),
+                        )?;
+                    }
+
                     if let Some(overrides) = overrides_opt.clone() {
                         space.permissions = Some(Permissions::<T>::override_permissions(overrides));
                     } else {
@@ -240,8 +500,9 @@ pub mod pallet {
         }
 
         #[pallet::call_index(2)]
+        // Also accounts for releasing the deposit of a previous depositor of the space.
         #[pallet::weight((
-            Weight::from_parts(1_000_000, 0) + T::DbWeight::get().reads_writes(1, 3),
+            Weight::from_parts(1_000_000, 0) + T::DbWeight::get().reads_writes(5, 5),
             DispatchClass::Operational,
             Pays::Yes,
         ))]
@@ -263,14 +524,50 @@ pub mod pallet {
             let new_who_and_when =
                 WhoAndWhen { account, block: frame_system::Pallet::<T>::block_number(), time };
 
+            // Keep the space in its parent and keep its counters,
+            // so that the indexes of this and other pallets stay consistent.
+            let old_space_opt = Self::space_by_id(space_id);
+            let parent_id = old_space_opt.as_ref().and_then(|space| space.parent_id);
+            let (posts_count, hidden_posts_count, followers_count) = old_space_opt
+                .as_ref()
+                .map(|space| (space.posts_count, space.hidden_posts_count, space.followers_count))
+                .unwrap_or_default();
+            let archived = old_space_opt.as_ref().map_or(false, |space| space.archived);
+            // Co-owners stay with the space, unless the new owner is one of them.
+            let co_owners = old_space_opt
+                .as_ref()
+                .map(|space| {
+                    let mut co_owners = space.co_owners.clone();
+                    co_owners.retain(|co_owner| *co_owner != owner);
+                    co_owners
+                })
+                .unwrap_or_default();
+            // A space created by root is stored for free. An existing deposit stays with the space
+            // only while it belongs to the owner, otherwise it is returned to its depositor.
+            let deposit = match old_space_opt {
+                Some(space) if space.deposit.depositor == owner => space.deposit,
+                Some(space) => {
+                    space.deposit.release::<T::Currency>();
+                    StorageDeposit::free(owner.clone())
+                },
+                None => StorageDeposit::free(owner.clone()),
+            };
+
             let new_space = &mut Space {
                 id: space_id,
                 created: new_who_and_when,
                 edited: false,
                 owner: owner.clone(),
+                co_owners,
+                parent_id,
                 content,
                 hidden,
+                archived,
                 permissions,
+                posts_count,
+                hidden_posts_count,
+                followers_count,
+                deposit,
             };
 
             let add_new_space_id_by_owner = |owner: &T::AccountId, space_id: SpaceId| {
@@ -282,11 +579,14 @@ pub mod pallet {
             // To prevent incorrect [SpaceIdsByOwner] insertion,
             // we check if the space already exists.
             match Self::require_space(space_id) {
-                Ok(space) if !space.is_owner(&owner) => {
+                Ok(space) if space.owner != owner => {
                     SpaceIdsByOwner::<T>::mutate(&space.owner, |ids| {
                         remove_from_bounded_vec(ids, space_id)
                     });
-                    add_new_space_id_by_owner(&owner, space_id);
+                    // A co-owner has this space among their spaces already.
+                    if !space.co_owners.contains(&owner) {
+                        add_new_space_id_by_owner(&owner, space_id);
+                    }
                 },
                 Err(_) => add_new_space_id_by_owner(&owner, space_id),
                 _ => (),
@@ -313,6 +613,453 @@ pub mod pallet {
             NextSpaceId::<T>::put(space_id);
             Ok(Pays::No.into())
         }
+
+        /// Create a subspace within a given parent space.
+        /// Requires the `CreateSubspaces` permission in the parent space.
+        #[pallet::call_index(4)]
+        #[pallet::weight(< T as Config >::WeightInfo::create_subspace())]
+        pub fn create_subspace(
+            origin: OriginFor<T>,
+            parent_id: SpaceId,
+            content: Content,
+            permissions_opt: Option<SpacePermissions>,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+
+            Self::do_create_space(&owner, Some(parent_id), content, permissions_opt)?;
+            Ok(())
+        }
+
+        /// Move a space under another parent space, or to the root level if `new_parent_id`
+        /// is `None`. Only a space that has no subspaces can be moved.
+        #[pallet::call_index(5)]
+        #[pallet::weight(< T as Config >::WeightInfo::move_space())]
+        pub fn move_space(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            new_parent_id: Option<SpaceId>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut space = Self::require_space(space_id)?;
+            let old_parent_id = space.parent_id;
+
+            ensure!(old_parent_id.is_some() || new_parent_id.is_some(), Error::<T>::SpaceIsAtRoot);
+            ensure!(new_parent_id != old_parent_id, Error::<T>::CannotMoveToSameParent);
+            ensure!(new_parent_id != Some(space_id), Error::<T>::CannotMoveSpaceUnderItself);
+            ensure!(
+                Self::subspace_ids_by_parent_id(space_id).is_empty(),
+                Error::<T>::CannotMoveSpaceWithSubspaces
+            );
+
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(who.clone(), space_id),
+                ModerationError::AccountIsBlocked
+            );
+
+            if let Some(old_parent_id) = old_parent_id {
+                let old_parent = Self::require_space(old_parent_id)?;
+                Self::ensure_account_can_update_subspace(&who, &space, &old_parent)?;
+            } else {
+                Self::ensure_account_has_space_permission(
+                    who.clone(),
+                    &space,
+                    SpacePermission::UpdateSpace,
+                    Error::<T>::NoPermissionToUpdateSpace.into(),
+                )?;
+            }
+
+            if let Some(new_parent_id) = new_parent_id {
+                let new_parent = Self::require_space(new_parent_id)?;
+                Self::ensure_can_create_subspace(
+                    &who,
+                    &new_parent,
+                    &space.content,
+                    space.permissions.is_some(),
+                )?;
+                SubspaceIdsByParentId::<T>::mutate(new_parent_id, |ids| ids.push(space_id));
+            }
+
+            if let Some(old_parent_id) = old_parent_id {
+                SubspaceIdsByParentId::<T>::mutate(old_parent_id, |ids| {
+                    remove_from_vec(ids, space_id)
+                });
+            }
+
+            space.parent_id = new_parent_id;
+            SpaceById::<T>::insert(space_id, space);
+
+            Self::deposit_event(Event::SpaceMoved {
+                account: who,
+                space_id,
+                from_parent: old_parent_id,
+                to_parent: new_parent_id,
+            });
+            Ok(())
+        }
+
+        /// Set a unique handle of a space, or remove it if `handle_opt` is `None`.
+        /// A handle that contains a dot is treated as a domain name,
+        /// which must be owned by the space owner.
+        #[pallet::call_index(6)]
+        #[pallet::weight(< T as Config >::WeightInfo::set_space_handle())]
+        pub fn set_space_handle(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            handle_opt: Option<Vec<u8>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let space = Self::require_space(space_id)?;
+            ensure!(space.is_owner(&who), Error::<T>::NotASpaceOwner);
+
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(who.clone(), space_id),
+                ModerationError::AccountIsBlocked
+            );
+
+            let old_handle_opt = Self::handle_by_space_id(space_id);
+            let new_handle_opt =
+                handle_opt.map(|handle| Self::ensure_valid_handle(&who, handle)).transpose()?;
+
+            ensure!(new_handle_opt != old_handle_opt, Error::<T>::NoUpdatesForSpace);
+
+            if let Some(new_handle) = &new_handle_opt {
+                if let Some(holder_id) = Self::space_id_by_handle(new_handle) {
+                    // A domain handle can be taken over from a space,
+                    // whose owner no longer owns the domain.
+                    ensure!(
+                        Self::get_space_id_by_handle(new_handle.clone()).is_none(),
+                        Error::<T>::HandleIsNotUnique
+                    );
+                    HandleBySpaceId::<T>::remove(holder_id);
+                }
+
+                SpaceIdByHandle::<T>::insert(new_handle, space_id);
+                HandleBySpaceId::<T>::insert(space_id, new_handle);
+            } else {
+                HandleBySpaceId::<T>::remove(space_id);
+            }
+
+            if let Some(old_handle) = old_handle_opt {
+                SpaceIdByHandle::<T>::remove(old_handle);
+            }
+
+            Self::deposit_event(Event::SpaceHandleUpdated {
+                account: who,
+                space_id,
+                handle: new_handle_opt,
+            });
+            Ok(())
+        }
+
+        /// Delete a space that has no subspaces. The space is removed at once,
+        /// while its posts, roles, follows and other related data are cleaned up later.
+        ///
+        /// A root space can be deleted only by its owner. A subspace can be deleted by its owner
+        /// with the `DeleteOwnSubspaces` permission in the parent space,
+        /// or by anyone with the `DeleteAnySubspace` permission in the parent space.
+        ///
+        /// A space registered as an active creator has to be unregistered before deletion.
+        #[pallet::call_index(7)]
+        #[pallet::weight(< T as Config >::WeightInfo::delete_space())]
+        pub fn delete_space(origin: OriginFor<T>, space_id: SpaceId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let space = Self::require_space(space_id)?;
+
+            ensure!(
+                Self::subspace_ids_by_parent_id(space_id).is_empty(),
+                Error::<T>::CannotDeleteSpaceWithSubspaces
+            );
+            ensure!(
+                !T::CreatorStakingProvider::is_creator_active(space_id),
+                Error::<T>::ActiveCreatorCannotBeDeleted
+            );
+
+            if let Some(parent_id) = space.parent_id {
+                let parent = Self::require_space(parent_id)?;
+
+                ensure!(
+                    T::IsAccountBlocked::is_allowed_account(who.clone(), parent_id),
+                    ModerationError::AccountIsBlocked
+                );
+
+                let (permission, error) = if space.is_owner(&who) {
+                    (
+                        SpacePermission::DeleteOwnSubspaces,
+                        Error::<T>::NoPermissionToDeleteOwnSubspaces,
+                    )
+                } else {
+                    (
+                        SpacePermission::DeleteAnySubspace,
+                        Error::<T>::NoPermissionToDeleteAnySubspace,
+                    )
+                };
+
+                Self::ensure_account_has_space_permission(
+                    who.clone(),
+                    &parent,
+                    permission,
+                    error.into(),
+                )?;
+
+                SubspaceIdsByParentId::<T>::mutate(parent_id, |ids| remove_from_vec(ids, space_id));
+            } else {
+                ensure!(space.is_owner(&who), Error::<T>::NotASpaceOwner);
+            }
+
+            if let Some(handle) = HandleBySpaceId::<T>::take(space_id) {
+                SpaceIdByHandle::<T>::remove(handle);
+            }
+
+            for owner in space.owners() {
+                SpaceIdsByOwner::<T>::mutate(&owner, |ids| remove_from_bounded_vec(ids, space_id));
+                T::ProfileManager::unlink_space_from_profile(&owner, space_id);
+            }
+
+            space.deposit.release::<T::Currency>();
+
+            SubspaceIdsByParentId::<T>::remove(space_id);
+            // There are at most as many settings per space as there are registered settings.
+            let _ = SpaceSettingByKey::<T>::clear_prefix(space_id, u32::MAX, None);
+            // There are at most `MaxSpaceCoOwners` proposals per space.
+            let _ =
+                PendingSpaceOwners::<T>::clear_prefix(space_id, T::MaxSpaceCoOwners::get(), None);
+            SpaceById::<T>::remove(space_id);
+            Self::enqueue_space_cleanup(space_id);
+
+            Self::deposit_event(Event::SpaceDeleted { account: who, space_id });
+            Ok(())
+        }
+
+        /// Clean up at most `limit` storage items related to a deleted space.
+        /// Anyone can call this to speed up the cleanup of a space with lots of data.
+        #[pallet::call_index(8)]
+        #[pallet::weight(Pallet::<T>::continue_space_deletion_weight(*limit))]
+        pub fn continue_space_deletion(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            limit: u32,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            ensure!(Self::is_space_pending_cleanup(space_id), Error::<T>::SpaceIsNotBeingDeleted);
+
+            let removed = Self::do_continue_space_deletion(space_id, limit);
+
+            Ok(Some(Self::continue_space_deletion_weight(removed.min(limit))).into())
+        }
+
+        /// Set the encoded value of a space setting registered by another pallet,
+        /// or reset the setting to its default value if `value_opt` is `None`.
+        #[pallet::call_index(9)]
+        #[pallet::weight(< T as Config >::WeightInfo::update_space_setting())]
+        pub fn update_space_setting(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            key: Vec<u8>,
+            value_opt: Option<Vec<u8>>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let space = Self::require_space(space_id)?;
+
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(who.clone(), space_id),
+                ModerationError::AccountIsBlocked
+            );
+
+            Self::ensure_account_has_space_permission(
+                who.clone(),
+                &space,
+                SpacePermission::UpdateSpaceSettings,
+                Error::<T>::NoPermissionToUpdateSpaceSettings.into(),
+            )?;
+
+            let setting = T::SpaceSettingsRegistry::registered_settings()
+                .into_iter()
+                .find(|setting| setting.key == key)
+                .ok_or(Error::<T>::UnknownSpaceSetting)?;
+
+            match &value_opt {
+                Some(value) => {
+                    ensure!(
+                        T::SpaceSettingsRegistry::check_setting_value(&key, value).unwrap_or(false),
+                        Error::<T>::InvalidSpaceSettingValue
+                    );
+                    SpaceSettingByKey::<T>::insert(
+                        space_id,
+                        &key,
+                        VersionedSettingValue { version: setting.version, value: value.clone() },
+                    );
+                },
+                None => SpaceSettingByKey::<T>::remove(space_id, &key),
+            }
+
+            Self::deposit_event(Event::SpaceSettingUpdated {
+                account: who,
+                space_id,
+                key,
+                value: value_opt,
+            });
+            Ok(())
+        }
+
+        /// Archive a space, making it read-only, or unarchive it.
+        /// Requires the `UpdateSpace` permission, except that the owner can always unarchive.
+        #[pallet::call_index(10)]
+        #[pallet::weight(< T as Config >::WeightInfo::set_space_archived())]
+        pub fn set_space_archived(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            archived: bool,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut space = Self::require_space(space_id)?;
+            ensure!(space.archived != archived, Error::<T>::NoUpdatesForSpace);
+
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(who.clone(), space_id),
+                ModerationError::AccountIsBlocked
+            );
+
+            if archived || !space.is_owner(&who) {
+                Self::ensure_account_has_space_permission(
+                    who.clone(),
+                    &space,
+                    SpacePermission::UpdateSpace,
+                    Error::<T>::NoPermissionToUpdateSpace.into(),
+                )?;
+            }
+
+            space.archived = archived;
+            SpaceById::<T>::insert(space_id, space);
+
+            if archived {
+                Self::deposit_event(Event::SpaceArchived { account: who, space_id });
+            } else {
+                Self::deposit_event(Event::SpaceUnarchived { account: who, space_id });
+            }
+            Ok(())
+        }
+
+        /// Propose an account to become a co-owner of a space. Any owner of the space can do
+        /// this, and the account becomes a co-owner once it accepts the proposal.
+        #[pallet::call_index(11)]
+        #[pallet::weight(< T as Config >::WeightInfo::propose_space_owner())]
+        pub fn propose_space_owner(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            new_owner: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let space = Self::require_space(space_id)?;
+            ensure!(space.is_owner(&who), Error::<T>::NotASpaceOwner);
+            ensure!(!space.is_owner(&new_owner), Error::<T>::AlreadyASpaceOwner);
+
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(who.clone(), space_id),
+                ModerationError::AccountIsBlocked
+            );
+
+            ensure!(
+                !PendingSpaceOwners::<T>::contains_key(space_id, &new_owner),
+                Error::<T>::SpaceOwnerAlreadyProposed
+            );
+
+            // Pending proposals count towards the limit, so that all of them can be accepted.
+            let proposals_count = PendingSpaceOwners::<T>::iter_prefix(space_id).count();
+            ensure!(
+                space.co_owners.len() + proposals_count < T::MaxSpaceCoOwners::get() as usize,
+                Error::<T>::TooManySpaceCoOwners
+            );
+
+            PendingSpaceOwners::<T>::insert(space_id, &new_owner, who.clone());
+
+            Self::deposit_event(Event::SpaceOwnerProposed { account: who, space_id, new_owner });
+            Ok(())
+        }
+
+        /// Accept a proposal to become a co-owner of a space.
+        #[pallet::call_index(12)]
+        #[pallet::weight(< T as Config >::WeightInfo::accept_space_ownership())]
+        pub fn accept_space_ownership(origin: OriginFor<T>, space_id: SpaceId) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                PendingSpaceOwners::<T>::contains_key(space_id, &who),
+                Error::<T>::NoPendingSpaceOwnerProposal
+            );
+
+            let mut space = Self::require_space(space_id)?;
+
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(who.clone(), space_id),
+                ModerationError::AccountIsBlocked
+            );
+
+            Self::ensure_space_limit_not_reached(&who)?;
+
+            space
+                .co_owners
+                .try_push(who.clone())
+                .map_err(|_| Error::<T>::TooManySpaceCoOwners)?;
+
+            PendingSpaceOwners::<T>::remove(space_id, &who);
+            SpaceIdsByOwner::<T>::mutate(&who, |ids| {
+                ids.try_push(space_id).expect("qed; too many spaces per account")
+            });
+            SpaceById::<T>::insert(space_id, space);
+
+            Self::deposit_event(Event::SpaceOwnerAdded { account: who, space_id });
+            Ok(())
+        }
+
+        /// Remove a co-owner from a space, or cancel a proposal for an account to become one.
+        /// Any owner of the space can do this, including a co-owner removing themselves.
+        #[pallet::call_index(13)]
+        #[pallet::weight(< T as Config >::WeightInfo::remove_space_owner())]
+        pub fn remove_space_owner(
+            origin: OriginFor<T>,
+            space_id: SpaceId,
+            owner: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut space = Self::require_space(space_id)?;
+            ensure!(space.is_owner(&who), Error::<T>::NotASpaceOwner);
+
+            if who != owner {
+                ensure!(
+                    T::IsAccountBlocked::is_allowed_account(who.clone(), space_id),
+                    ModerationError::AccountIsBlocked
+                );
+            }
+
+            if PendingSpaceOwners::<T>::take(space_id, &owner).is_some() {
+                Self::deposit_event(Event::SpaceOwnerProposalCancelled {
+                    account: who,
+                    space_id,
+                    new_owner: owner,
+                });
+                return Ok(())
+            }
+
+            ensure!(space.owner != owner, Error::<T>::CannotRemoveSpaceOwner);
+            ensure!(space.co_owners.contains(&owner), Error::<T>::NotASpaceCoOwner);
+
+            space.co_owners.retain(|co_owner| *co_owner != owner);
+
+            SpaceIdsByOwner::<T>::mutate(&owner, |ids| remove_from_bounded_vec(ids, space_id));
+            T::ProfileManager::unlink_space_from_profile(&owner, space_id);
+            SpaceById::<T>::insert(space_id, space);
+
+            Self::deposit_event(Event::SpaceOwnerRemoved { account: who, space_id, owner });
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -324,7 +1071,14 @@ pub mod pallet {
                 for id in FIRST_SPACE_ID..=RESERVED_SPACE_COUNT {
                     spaces.push((
                         id,
-                        Space::<T>::new(id, endowed_account.clone(), Content::None, None),
+                        Space::<T>::new(
+                            id,
+                            None,
+                            endowed_account.clone(),
+                            Content::None,
+                            None,
+                            StorageDeposit::free(endowed_account.clone()),
+                        ),
                     ));
                 }
                 spaces.iter().for_each(|(space_id, space)| {
@@ -335,28 +1089,216 @@ pub mod pallet {
 
         fn do_create_space(
             owner: &T::AccountId,
+            parent_id_opt: Option<SpaceId>,
             content: Content,
             permissions_opt: Option<SpacePermissions>,
         ) -> Result<SpaceId, DispatchError> {
             ensure_content_is_valid(content.clone())?;
             Self::ensure_space_limit_not_reached(owner)?;
 
+            if let Some(parent_id) = parent_id_opt {
+                let parent = Self::require_space(parent_id)?;
+                Self::ensure_can_create_subspace(
+                    owner,
+                    &parent,
+                    &content,
+                    permissions_opt.is_some(),
+                )?;
+            }
+
             let permissions =
                 permissions_opt.map(|perms| Permissions::<T>::override_permissions(perms));
 
             let space_id = Self::next_space_id();
-            let new_space = &mut Space::new(space_id, owner.clone(), content, permissions);
+
+            // A new space has no moderation statuses of its own yet,
+            // so these checks reject only globally blocked accounts and content.
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(owner.clone(), space_id),
+                ModerationError::AccountIsBlocked
+            );
+            ensure!(
+                T::IsContentBlocked::is_allowed_content(content.clone(), space_id),
+                ModerationError::ContentIsBlocked
+            );
+
+            let deposit =
+                StorageDeposit::reserve::<T::Currency>(owner.clone(), T::SpaceDeposit::get())?;
+
+            let new_space = &mut Space::new(
+                space_id,
+                parent_id_opt,
+                owner.clone(),
+                content,
+                permissions,
+                deposit,
+            );
 
             SpaceById::<T>::insert(space_id, new_space);
             SpaceIdsByOwner::<T>::mutate(owner, |ids| {
                 ids.try_push(space_id).expect("qed; too many spaces per account")
             });
+            if let Some(parent_id) = parent_id_opt {
+                SubspaceIdsByParentId::<T>::mutate(parent_id, |ids| ids.push(space_id));
+            }
             NextSpaceId::<T>::mutate(|n| *n += 1);
 
             Self::deposit_event(Event::SpaceCreated { account: owner.clone(), space_id });
             Ok(space_id)
         }
 
+        fn is_domain_handle(handle: &[u8]) -> bool {
+            handle.contains(&b'.')
+        }
+
+        /// Check that `handle` can be used by a space of `owner` and return it lowercased.
+        /// A native handle must consist of letters, digits and underscores,
+        /// while a domain handle must be a domain owned by `owner`.
+        pub fn ensure_valid_handle(
+            owner: &T::AccountId,
+            handle: Vec<u8>,
+        ) -> Result<Vec<u8>, DispatchError> {
+            let handle = handle.to_ascii_lowercase();
+
+            if Self::is_domain_handle(&handle) {
+                T::DomainsProvider::ensure_domain_owner(&handle, owner)?;
+                return Ok(handle);
+            }
+
+            ensure!(
+                handle.len() >= T::MinHandleLen::get() as usize,
+                ModerationError::HandleIsTooShort
+            );
+            ensure!(
+                handle.len() <= T::MaxHandleLen::get() as usize,
+                ModerationError::HandleIsTooLong
+            );
+            ensure!(
+                handle.iter().all(|c| c.is_ascii_alphanumeric() || *c == b'_'),
+                ModerationError::HandleContainsInvalidChars
+            );
+
+            Ok(handle)
+        }
+
+        /// Resolve a handle to a space id. A domain handle resolves only while the domain
+        /// is owned by the space owner.
+        pub fn get_space_id_by_handle(handle: Vec<u8>) -> Option<SpaceId> {
+            let handle = handle.to_ascii_lowercase();
+            let space_id = Self::space_id_by_handle(&handle)?;
+
+            if Self::is_domain_handle(&handle) {
+                let space = Self::space_by_id(space_id)?;
+                let domain_owner = T::DomainsProvider::get_domain_owner(&handle).ok()?;
+                if !space.is_owner(&domain_owner) {
+                    return None;
+                }
+            }
+
+            Some(space_id)
+        }
+
+        /// Get the effective value of a typed setting in a space:
+        /// either the stored value, or the default one.
+        pub fn space_setting<S: SpaceSetting>(space_id: SpaceId) -> S::Value {
+            Self::space_setting_by_key(space_id, S::KEY)
+                .and_then(|setting| S::decode_value(setting.version, &setting.value))
+                .unwrap_or_default()
+        }
+
+        /// Clean up at most `limit` storage items related to a deleted space
+        /// and return the number of removed items.
+        fn do_continue_space_deletion(space_id: SpaceId, limit: u32) -> u32 {
+            let removed = T::SpaceDeletionHandler::cleanup_deleted_space(space_id, limit);
+
+            if removed < limit {
+                SpacePendingCleanup::<T>::remove(space_id);
+                Self::deposit_event(Event::SpaceDeletionCompleted { space_id });
+            }
+
+            removed
+        }
+
+        /// The worst-case weight of cleaning up at most `limit` storage items of a deleted space.
+        pub(crate) fn continue_space_deletion_weight(limit: u32) -> Weight {
+            T::WeightInfo::continue_space_deletion()
+                .saturating_add(T::SpaceDeletionHandler::cleanup_deleted_space_weight(limit))
+        }
+
+        /// Add a deleted space to the end of the queue of deleted spaces.
+        fn enqueue_space_cleanup(space_id: SpaceId) {
+            let tail = SpaceCleanupQueueTail::<T>::get();
+            SpaceIdsPendingCleanup::<T>::insert(tail, space_id);
+            SpaceCleanupQueueTail::<T>::put(tail.saturating_add(1));
+            SpacePendingCleanup::<T>::insert(space_id, true);
+        }
+
+        /// Clean up deleted spaces in the order of deletion, as long as `max_weight` allows.
+        /// Spaces already cleaned up with `continue_space_deletion` are skipped.
+        pub(crate) fn cleanup_deleted_spaces(max_weight: Weight) -> Weight {
+            let mut used_weight = T::DbWeight::get().reads(2);
+
+            let base_weight = Self::continue_space_deletion_weight(0);
+            let item_weight = Self::continue_space_deletion_weight(1).saturating_sub(base_weight);
+            let queue_item_weight = T::DbWeight::get().reads_writes(2, 1);
+
+            let first = SpaceCleanupQueueHead::<T>::get();
+            let tail = SpaceCleanupQueueTail::<T>::get();
+            let mut head = first;
+
+            while head < tail {
+                let required_weight =
+                    used_weight.saturating_add(queue_item_weight).saturating_add(base_weight);
+                if max_weight.any_lt(required_weight) {
+                    break;
+                }
+
+                used_weight.saturating_accrue(T::DbWeight::get().reads(1));
+                let space_id = match SpaceIdsPendingCleanup::<T>::get(head) {
+                    Some(space_id) => space_id,
+                    None => {
+                        head += 1;
+                        continue
+                    },
+                };
+
+                used_weight.saturating_accrue(T::DbWeight::get().reads(1));
+                if Self::is_space_pending_cleanup(space_id) {
+                    let remaining_weight = max_weight
+                        .saturating_sub(used_weight)
+                        .saturating_sub(T::DbWeight::get().writes(1))
+                        .saturating_sub(base_weight);
+                    // Without any handlers, removing items costs nothing but the base weight.
+                    let limit = remaining_weight
+                        .checked_div_per_component(&item_weight)
+                        .unwrap_or(u32::MAX as u64)
+                        .min(u32::MAX as u64) as u32;
+
+                    if limit == 0 {
+                        break;
+                    }
+
+                    let removed = Self::do_continue_space_deletion(space_id, limit);
+                    used_weight.saturating_accrue(Self::continue_space_deletion_weight(removed));
+
+                    if removed >= limit {
+                        break;
+                    }
+                }
+
+                SpaceIdsPendingCleanup::<T>::remove(head);
+                used_weight.saturating_accrue(T::DbWeight::get().writes(1));
+                head += 1;
+            }
+
+            if head != first {
+                SpaceCleanupQueueHead::<T>::put(head);
+                used_weight.saturating_accrue(T::DbWeight::get().writes(1));
+            }
+
+            used_weight
+        }
+
         /// Check that there is a `Space` with such `space_id` in the storage
         /// or return`SpaceNotFound` error.
         pub fn ensure_space_exists(space_id: SpaceId) -> DispatchResult {
@@ -382,12 +1324,172 @@ pub mod pallet {
                 space_id: space.id,
                 is_space_owner: is_owner,
                 is_space_follower: is_follower,
-                space_perms: space.permissions.clone(),
+                space_perms: Self::resolve_space_permissions(space),
             };
 
             T::Roles::ensure_account_has_space_permission(account, ctx, permission, error)
         }
 
+        /// Ensure that an account can act on behalf of a space in a given scope space.
+        /// Acting within the represented space itself requires the `RepresentSpaceInternally`
+        /// permission, while acting in any other space requires `RepresentSpaceExternally`.
+        pub fn ensure_can_represent_space(
+            account: &T::AccountId,
+            space_id: SpaceId,
+            scope_space_id: SpaceId,
+        ) -> DispatchResult {
+            let space = Self::require_space(space_id)?;
+            space.ensure_not_archived()?;
+
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(account.clone(), space_id),
+                ModerationError::AccountIsBlocked
+            );
+
+            let permission = if space_id == scope_space_id {
+                SpacePermission::RepresentSpaceInternally
+            } else {
+                SpacePermission::RepresentSpaceExternally
+            };
+
+            Self::ensure_account_has_space_permission(
+                account.clone(),
+                &space,
+                permission,
+                Error::<T>::NoPermissionToRepresentSpace.into(),
+            )
+        }
+
+        /// Get the permissions of a space. A subspace that has no permissions of its own
+        /// inherits them from the closest ancestor that has them.
+        pub fn resolve_space_permissions(space: &Space<T>) -> Option<SpacePermissions> {
+            let mut permissions = space.permissions.clone();
+            let mut parent_id_opt = space.parent_id;
+
+            while permissions.is_none() {
+                match parent_id_opt.and_then(Self::space_by_id) {
+                    Some(parent) => {
+                        permissions = parent.permissions;
+                        parent_id_opt = parent.parent_id;
+                    },
+                    None => break,
+                }
+            }
+
+            permissions
+        }
+
+        /// Get the number of ancestors of a space, up to `MaxSubspaceDepth`.
+        pub fn space_depth(space: &Space<T>) -> u32 {
+            let mut depth = 0;
+            let mut parent_id_opt = space.parent_id;
+
+            while let Some(parent_id) = parent_id_opt {
+                depth += 1;
+                if depth >= T::MaxSubspaceDepth::get() {
+                    break
+                }
+                parent_id_opt = Self::space_by_id(parent_id).and_then(|parent| parent.parent_id);
+            }
+
+            depth
+        }
+
+        /// Check that `account` can create a subspace with a given content in a parent space.
+        fn ensure_can_create_subspace(
+            account: &T::AccountId,
+            parent: &Space<T>,
+            content: &Content,
+            overrides_permissions: bool,
+        ) -> DispatchResult {
+            ensure!(!parent.hidden, Error::<T>::CannotCreateInHiddenScope);
+            parent.ensure_not_archived()?;
+            ensure!(
+                T::IsAccountBlocked::is_allowed_account(account.clone(), parent.id),
+                ModerationError::AccountIsBlocked
+            );
+            ensure!(
+                T::IsContentBlocked::is_allowed_content(content.clone(), parent.id),
+                ModerationError::ContentIsBlocked
+            );
+            ensure!(
+                Self::space_depth(parent) < T::MaxSubspaceDepth::get(),
+                Error::<T>::MaxSubspaceDepthReached
+            );
+
+            Self::ensure_account_has_space_permission(
+                account.clone(),
+                parent,
+                SpacePermission::CreateSubspaces,
+                Error::<T>::NoPermissionToCreateSubspaces.into(),
+            )?;
+
+            if overrides_permissions {
+                Self::ensure_account_has_space_permission(
+                    account.clone(),
+                    parent,
+                    SpacePermission::OverrideSubspacePermissions,
+                    Error::<T>::NoPermissionToOverrideSubspacePermissions.into(),
+                )?;
+            }
+
+            Ok(())
+        }
+
+        /// Check that `account` can update a subspace. The owner of a subspace needs
+        /// the `UpdateOwnSubspaces` permission in the parent space, while anyone else needs
+        /// either the `UpdateSpace` permission in the subspace
+        /// or the `UpdateAnySubspace` permission in the parent space.
+        fn ensure_account_can_update_subspace(
+            account: &T::AccountId,
+            space: &Space<T>,
+            parent: &Space<T>,
+        ) -> DispatchResult {
+            if space.is_owner(account) {
+                return Self::ensure_account_has_space_permission(
+                    account.clone(),
+                    parent,
+                    SpacePermission::UpdateOwnSubspaces,
+                    Error::<T>::NoPermissionToUpdateOwnSubspaces.into(),
+                )
+            }
+
+            Self::ensure_account_has_space_permission(
+                account.clone(),
+                space,
+                SpacePermission::UpdateSpace,
+                Error::<T>::NoPermissionToUpdateSpace.into(),
+            )
+            .or_else(|_| {
+                Self::ensure_account_has_space_permission(
+                    account.clone(),
+                    parent,
+                    SpacePermission::UpdateAnySubspace,
+                    Error::<T>::NoPermissionToUpdateSpace.into(),
+                )
+            })
+        }
+
+        /// Check that `account` can hide or unhide a subspace within its parent space.
+        fn ensure_account_can_hide_subspace(
+            account: &T::AccountId,
+            space: &Space<T>,
+            parent: &Space<T>,
+        ) -> DispatchResult {
+            let (permission, error) = if space.is_owner(account) {
+                (SpacePermission::HideOwnSubspaces, Error::<T>::NoPermissionToHideOwnSubspaces)
+            } else {
+                (SpacePermission::HideAnySubspace, Error::<T>::NoPermissionToHideAnySubspace)
+            };
+
+            Self::ensure_account_has_space_permission(
+                account.clone(),
+                parent,
+                permission,
+                error.into(),
+            )
+        }
+
         pub fn mutate_space_by_id<F: FnOnce(&mut Space<T>)>(
             space_id: SpaceId,
             f: F,
@@ -416,8 +1518,14 @@ pub mod pallet {
     impl<T: Config> SpacePermissionsProvider<T::AccountId, SpacePermissionsInfoOf<T>> for Pallet<T> {
         fn space_permissions_info(id: SpaceId) -> Result<SpacePermissionsInfoOf<T>, DispatchError> {
             let space = Pallet::<T>::require_space(id)?;
+            let permissions = Pallet::<T>::resolve_space_permissions(&space);
 
-            Ok(SpacePermissionsInfo { owner: space.owner, permissions: space.permissions })
+            Ok(SpacePermissionsInfo {
+                owner: space.owner,
+                co_owners: space.co_owners.into_inner(),
+                permissions,
+                archived: space.archived,
+            })
         }
 
         fn ensure_space_owner(id: SpaceId, account: &T::AccountId) -> DispatchResult {
@@ -433,11 +1541,18 @@ pub mod pallet {
             Ok(space.owner)
         }
         
-        fn do_update_space_owner(space_id: SpaceId, new_owner: T::AccountId) -> DispatchResult {
-            let space = Pallet::<T>::require_space(space_id)?;
-            if space.is_owner(&new_owner) {
+        fn do_update_space_owner(
+            space_id: SpaceId,
+            old_owner: &T::AccountId,
+            new_owner: T::AccountId,
+        ) -> DispatchResult {
+            let mut space = Pallet::<T>::require_space(space_id)?;
+            if *old_owner == new_owner {
                 return Ok(());
             }
+
+            ensure!(space.is_owner(old_owner), Error::<T>::NotASpaceOwner);
+            ensure!(!space.is_owner(&new_owner), Error::<T>::AlreadyASpaceOwner);
             
             Self::ensure_space_limit_not_reached(&new_owner)?;
 
@@ -446,24 +1561,40 @@ pub mod pallet {
                 ModerationError::AccountIsBlocked
             );
 
-            SpaceIdsByOwner::<T>::mutate(&space.owner, |ids| {
-                remove_from_bounded_vec(ids, space_id)
-            });
+            if space.owner == *old_owner {
+                // A domain handle goes along with the space,
+                // unless the domain has been transferred separately.
+                if let Some(handle) = Self::handle_by_space_id(space_id) {
+                    let owns_domain = Self::is_domain_handle(&handle)
+                        && T::DomainsProvider::ensure_domain_owner(&handle, &space.owner).is_ok();
+
+                    if owns_domain {
+                        T::DomainsProvider::do_update_domain_owner(&handle, &new_owner)?;
+                    }
+                }
+
+                space.deposit.transfer::<T::Currency>(new_owner.clone())?;
+                space.owner = new_owner.clone();
+            } else if let Some(co_owner) =
+                space.co_owners.iter_mut().find(|co_owner| *co_owner == old_owner)
+            {
+                // A co-owner's seat is taken by the new owner.
+                *co_owner = new_owner.clone();
+            }
+
+            SpaceIdsByOwner::<T>::mutate(old_owner, |ids| remove_from_bounded_vec(ids, space_id));
 
             SpaceIdsByOwner::<T>::mutate(&new_owner, |ids| {
                 ids.try_push(space_id).expect("qed; too many spaces per account")
             });
-            
-            SpaceById::<T>::mutate(space_id, |stored_space_opt| {
-                if let Some(stored_space) = stored_space_opt {
-                    stored_space.owner = new_owner;
-                }
-            });
+
+            PendingSpaceOwners::<T>::remove(space_id, &new_owner);
+            SpaceById::<T>::insert(space_id, space);
             Ok(())
         }
 
         fn create_space(owner: &T::AccountId, content: Content) -> Result<SpaceId, DispatchError> {
-            Self::do_create_space(owner, content, None)
+            Self::do_create_space(owner, None, content, None)
         }
     }
 }
@@ -0,0 +1,164 @@
+// Copyright (C) DAPPFORCE PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
+//
+// Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
+// Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
+
+use frame_support::{log, traits::OnRuntimeUpgrade};
+use sp_runtime::Saturating;
+use sp_std::vec::Vec;
+
+use super::*;
+
+pub mod v5 {
+    use frame_support::{pallet_prelude::*, storage::StoragePrefixedMap, weights::Weight};
+    use subsocial_support::{StorageDeposit, WhoAndWhenOf};
+
+    use crate::types::*;
+
+    use super::*;
+
+    /// The maximum number of spaces migrated in a single block.
+    pub const SPACES_PER_BLOCK: u64 = 300;
+
+    // Old space, before subspaces, counters, storage deposits, archiving and co-owners
+    // were introduced
+    #[derive(Encode, Decode)]
+    pub struct OldSpace<T: Config> {
+        pub id: SpaceId,
+        pub created: WhoAndWhenOf<T>,
+        pub edited: bool,
+        pub owner: T::AccountId,
+        pub content: Content,
+        pub hidden: bool,
+        pub permissions: Option<SpacePermissions>,
+    }
+
+    #[frame_support::storage_alias]
+    pub type OldSpaceById<T: Config> = StorageMap<Pallet<T>, Twox64Concat, SpaceId, OldSpace<T>>;
+
+    /// The raw key of the last migrated space, or the prefix of the spaces map if no space
+    /// has been migrated yet. It exists only while the migration is in progress.
+    #[frame_support::storage_alias]
+    type MigrationCursor<T: Config> = StorageValue<Pallet<T>, Vec<u8>>;
+
+    impl<T: Config> OldSpace<T> {
+        fn migrate_to_v5(self) -> Space<T> {
+            Space {
+                id: self.id,
+                created: self.created,
+                edited: self.edited,
+                owner: self.owner.clone(),
+                co_owners: Default::default(),
+                parent_id: None,
+                content: self.content,
+                hidden: self.hidden,
+                archived: false,
+                permissions: self.permissions,
+                // Counted by the posts pallet migration.
+                posts_count: 0,
+                hidden_posts_count: 0,
+                followers_count: T::SpaceFollows::space_followers_count(self.id),
+                // Existing spaces are grandfathered and keep being stored for free.
+                deposit: StorageDeposit::free(self.owner),
+            }
+        }
+    }
+
+    /// Whether spaces are being migrated. Calls that use spaces should not be dispatched
+    /// until the migration is over.
+    pub fn is_migration_in_progress<T: Config>() -> bool {
+        MigrationCursor::<T>::exists()
+    }
+
+    /// Migrate up to `SPACES_PER_BLOCK` spaces, if the migration is in progress,
+    /// and put the new storage version once every space is migrated.
+    pub(crate) fn migrate_next_spaces<T: Config>() -> Weight {
+        let cursor = match MigrationCursor::<T>::get() {
+            Some(cursor) => cursor,
+            None => return T::DbWeight::get().reads(1),
+        };
+
+        let mut old_spaces = OldSpaceById::<T>::iter_from(cursor);
+        let mut migrated = 0u64;
+        let mut is_finished = true;
+
+        for (space_id, old_space) in old_spaces.by_ref() {
+            SpaceById::<T>::insert(space_id, old_space.migrate_to_v5());
+            migrated.saturating_inc();
+
+            if migrated >= SPACES_PER_BLOCK {
+                is_finished = false;
+                break;
+            }
+        }
+
+        if is_finished {
+            MigrationCursor::<T>::kill();
+            let current_version = Pallet::<T>::current_storage_version();
+            current_version.put::<Pallet<T>>();
+            log::info!(
+                target: LOG_TARGET,
+                "Upgraded the last {} spaces, storage to version {:?}",
+                migrated,
+                current_version
+            );
+        } else {
+            MigrationCursor::<T>::put(old_spaces.last_raw_key().to_vec());
+        }
+
+        // Each space also reads its followers.
+        T::DbWeight::get().reads_writes(migrated * 2 + 1, migrated + 1)
+    }
+
+    /// Starts a migration of spaces from the storage version 0 to 5, which translates
+    /// up to `SPACES_PER_BLOCK` spaces in `on_initialize` of every block.
+    ///
+    /// The posts pallet migration waits for this migration to be over.
+    pub struct MigrateToV5<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV5<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let current_version = Pallet::<T>::current_storage_version();
+            let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+            log::info!(
+                target: LOG_TARGET,
+                "Running migration with current storage version {:?} / onchain {:?}",
+                current_version,
+                onchain_version
+            );
+
+            if onchain_version == 0 && current_version == 5 && !is_migration_in_progress::<T>() {
+                MigrationCursor::<T>::put(SpaceById::<T>::final_prefix().to_vec());
+
+                log::info!(
+                    target: LOG_TARGET,
+                    "Started migrating spaces to storage version {:?}",
+                    current_version
+                );
+                T::DbWeight::get().reads_writes(2, 1)
+            } else {
+                log::info!(
+                    target: LOG_TARGET,
+                    "Migration did not execute. This probably should be removed"
+                );
+                T::DbWeight::get().reads(2)
+            }
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, DispatchError> {
+            let current_version = Pallet::<T>::current_storage_version();
+            let onchain_version = Pallet::<T>::on_chain_storage_version();
+            ensure!(onchain_version == 0 && current_version == 5, "migration from version 0 to 5.");
+            Ok(Vec::new())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(_state: Vec<u8>) -> Result<(), DispatchError> {
+            ensure!(is_migration_in_progress::<T>(), "spaces should be migrated in next blocks");
+            Ok(())
+        }
+    }
+}
@@ -5,36 +5,40 @@
 // Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
 
 use codec::{Decode, Encode};
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
+use sp_runtime::{RuntimeDebug, SaturatedConversion};
 use sp_std::prelude::*;
 
-use pallet_utils::{bool_to_option, SpaceId, rpc::{FlatContent, FlatWhoAndWhen, ShouldSkip}};
+use subsocial_support::{traits::SpaceSettingsRegistry, Content, SpaceId};
 
-use crate::{Config, Pallet, Space, FIRST_SPACE_ID};
+use crate::{types::*, Config, Pallet, SpaceSettingByKey};
 
-#[derive(Eq, PartialEq, Encode, Decode, Default)]
-#[cfg_attr(feature = "std", derive(Debug, Serialize, Deserialize))]
+/// The maximum number of spaces returned by a single call to the spaces RPC.
+pub const MAX_SPACES_PER_CALL: u64 = 100;
+
+/// A space as it is returned by the spaces RPC.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub struct FlatSpace<AccountId, BlockNumber> {
     pub id: SpaceId,
 
-    #[cfg_attr(feature = "std", serde(flatten))]
-    pub who_and_when: FlatWhoAndWhen<AccountId, BlockNumber>,
+    pub created_by_account: AccountId,
+    pub created_at_block: BlockNumber,
+    pub created_at_time: u64,
+    pub is_edited: bool,
 
     pub owner_id: AccountId,
-
-    #[cfg_attr(feature = "std", serde(skip_serializing_if = "ShouldSkip::should_skip"))]
+    pub co_owner_ids: Vec<AccountId>,
     pub parent_id: Option<SpaceId>,
 
-    #[cfg_attr(feature = "std", serde(skip_serializing_if = "ShouldSkip::should_skip", serialize_with = "bytes_to_string"))]
     pub handle: Option<Vec<u8>>,
-
-    #[cfg_attr(feature = "std", serde(flatten))]
-    pub content: FlatContent,
-
-    #[cfg_attr(feature = "std", serde(skip_serializing_if = "ShouldSkip::should_skip"))]
-    pub is_hidden: Option<bool>,
+    pub content: Content,
+    pub is_hidden: bool,
+    pub is_archived: bool,
 
     pub posts_count: u32,
     pub hidden_posts_count: u32,
@@ -42,31 +46,50 @@ pub struct FlatSpace<AccountId, BlockNumber> {
     pub followers_count: u32,
 }
 
-#[cfg(feature = "std")]
-fn bytes_to_string<S>(field: &Option<Vec<u8>>, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer {
-    let field_unwrapped = field.clone().unwrap_or_default();
-    // If Bytes slice is invalid, then empty string will be returned
-    serializer.serialize_str(
-        std::str::from_utf8(&field_unwrapped).unwrap_or_default()
-    )
+/// The effective value of a space setting as it is returned by the spaces RPC.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct FlatSpaceSetting {
+    pub key: Vec<u8>,
+    pub version: u16,
+    pub value: Vec<u8>,
+    pub is_default: bool,
 }
 
-impl<T: Config> From<Space<T>> for FlatSpace<T::AccountId, T::BlockNumber> {
+pub type FlatSpaceOf<T> = FlatSpace<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
+impl<T: Config> From<Space<T>> for FlatSpaceOf<T> {
     fn from(from: Space<T>) -> Self {
         let Space {
-            id, created, updated, owner,
-            parent_id, handle, content, hidden, posts_count,
-            hidden_posts_count, followers_count, ..
+            id,
+            created,
+            edited,
+            owner,
+            co_owners,
+            parent_id,
+            content,
+            hidden,
+            archived,
+            posts_count,
+            hidden_posts_count,
+            followers_count,
+            ..
         } = from;
 
         Self {
             id,
-            who_and_when: (created, updated).into(),
+            created_by_account: created.account,
+            created_at_block: created.block,
+            created_at_time: created.time.saturated_into::<u64>(),
+            is_edited: edited,
             owner_id: owner,
+            co_owner_ids: co_owners.into_inner(),
             parent_id,
-            handle,
-            content: content.into(),
-            is_hidden: bool_to_option(hidden),
+            handle: Pallet::<T>::handle_by_space_id(id),
+            content,
+            is_hidden: hidden,
+            is_archived: archived,
             posts_count,
             hidden_posts_count,
             visible_posts_count: posts_count.saturating_sub(hidden_posts_count),
@@ -75,23 +98,30 @@ impl<T: Config> From<Space<T>> for FlatSpace<T::AccountId, T::BlockNumber> {
     }
 }
 
-impl<T: Config> Module<T> {
-    pub fn get_spaces_by_ids(space_ids: Vec<SpaceId>) -> Vec<FlatSpace<T::AccountId, T::BlockNumber>> {
-        space_ids.iter()
+impl<T: Config> Pallet<T> {
+    pub fn get_spaces_by_ids(space_ids: Vec<SpaceId>) -> Vec<FlatSpaceOf<T>> {
+        space_ids
+            .iter()
             .filter_map(|id| Self::require_space(*id).ok())
             .map(|space| space.into())
             .collect()
     }
 
+    /// Get up to `limit` spaces that satisfy a given filter,
+    /// starting from `start_id` and going down to the first space id.
+    ///
+    /// `limit` is capped at `MAX_SPACES_PER_CALL`, and `start_id` at the last created space,
+    /// so that only ids of existing spaces are scanned.
     fn get_spaces_slice<F: FnMut(&Space<T>) -> bool>(
-        start_id: u64,
+        start_id: SpaceId,
         limit: u64,
         mut filter: F,
-    ) -> Vec<FlatSpace<T::AccountId, T::BlockNumber>> {
-        let mut space_id = start_id;
+    ) -> Vec<FlatSpaceOf<T>> {
+        let limit = limit.min(MAX_SPACES_PER_CALL) as usize;
+        let mut space_id = start_id.min(Self::next_space_id().saturating_sub(1));
         let mut spaces = Vec::new();
 
-        while spaces.len() < limit as usize && space_id >= FIRST_SPACE_ID {
+        while spaces.len() < limit && space_id >= FIRST_SPACE_ID {
             if let Ok(space) = Self::require_space(space_id) {
                 if filter(&space) {
                     spaces.push(space.into());
@@ -103,46 +133,39 @@ impl<T: Config> Module<T> {
         spaces
     }
 
-    pub fn get_spaces(start_id: u64, limit: u64) -> Vec<FlatSpace<T::AccountId, T::BlockNumber>> {
+    pub fn get_spaces(start_id: SpaceId, limit: u64) -> Vec<FlatSpaceOf<T>> {
         Self::get_spaces_slice(start_id, limit, |_| true)
     }
 
-    pub fn get_public_spaces(start_id: u64, limit: u64) -> Vec<FlatSpace<T::AccountId, T::BlockNumber>> {
+    pub fn get_public_spaces(start_id: SpaceId, limit: u64) -> Vec<FlatSpaceOf<T>> {
         Self::get_spaces_slice(start_id, limit, |space| space.is_public())
     }
 
-    pub fn get_unlisted_spaces(start_id: u64, limit: u64) -> Vec<FlatSpace<T::AccountId, T::BlockNumber>> {
+    pub fn get_unlisted_spaces(start_id: SpaceId, limit: u64) -> Vec<FlatSpaceOf<T>> {
         Self::get_spaces_slice(start_id, limit, |space| space.is_unlisted())
     }
 
-    pub fn get_space_id_by_handle(handle: Vec<u8>) -> Option<SpaceId> {
-        Self::space_id_by_handle(handle)
+    pub fn get_space_ids_by_owner(owner: T::AccountId) -> Vec<SpaceId> {
+        Self::space_ids_by_owner(owner).into_inner()
     }
 
-    pub fn get_space_by_handle(handle: Vec<u8>) -> Option<FlatSpace<T::AccountId, T::BlockNumber>> {
-        Self::space_id_by_handle(handle)
-            .and_then(|space_id| Self::require_space(space_id).ok())
-            .map(|space| space.into())
-    }
-
-    fn get_space_ids_by_owner<F: FnMut(&Space<T>) -> bool>(owner: T::AccountId, mut compare_fn: F) -> Vec<SpaceId> {
-        Self::space_ids_by_owner(owner)
-            .iter()
-            .filter_map(|space_id| Self::require_space(*space_id).ok())
-            .filter(|space| compare_fn(space))
-            .map(|space| space.id)
+    /// Get the effective values of all registered settings in a space.
+    /// Stored values of an outdated version are replaced by the defaults.
+    pub fn get_space_settings(space_id: SpaceId) -> Vec<FlatSpaceSetting> {
+        T::SpaceSettingsRegistry::registered_settings()
+            .into_iter()
+            .map(|setting| {
+                let stored_value = SpaceSettingByKey::<T>::get(space_id, &setting.key)
+                    .filter(|stored| stored.version == setting.version)
+                    .map(|stored| stored.value);
+
+                FlatSpaceSetting {
+                    is_default: stored_value.is_none(),
+                    value: stored_value.unwrap_or(setting.default_value),
+                    key: setting.key,
+                    version: setting.version,
+                }
+            })
             .collect()
     }
-
-    pub fn get_public_space_ids_by_owner(owner: T::AccountId) -> Vec<SpaceId> {
-        Self::get_space_ids_by_owner(owner, |space| !space.hidden)
-    }
-
-    pub fn get_unlisted_space_ids_by_owner(owner: T::AccountId) -> Vec<SpaceId> {
-        Self::get_space_ids_by_owner(owner, |space| space.hidden)
-    }
-
-    pub fn get_next_space_id() -> SpaceId {
-        Self::next_space_id()
-    }
-}
\ No newline at end of file
+}
@@ -4,9 +4,11 @@
 // Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
 // Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
 
-use frame_support::pallet_prelude::*;
+use frame_support::{pallet_prelude::*, traits::Currency};
+use sp_runtime::traits::Saturating;
+use sp_std::vec::Vec;
 
-use subsocial_support::{new_who_and_when, WhoAndWhenOf};
+use subsocial_support::{new_who_and_when, StorageDeposit, WhoAndWhenOf};
 
 use super::*;
 
@@ -15,6 +17,14 @@ pub const RESERVED_SPACE_COUNT: u64 = 1000;
 
 pub(crate) type SpacesByAccount<T> = BoundedVec<SpaceId, <T as Config>::MaxSpacesPerAccount>;
 
+pub type SpaceCoOwners<T> =
+    BoundedVec<<T as frame_system::Config>::AccountId, <T as Config>::MaxSpaceCoOwners>;
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+pub type StorageDepositOf<T> = StorageDeposit<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
 /// Information about a space's owner, its' content, visibility and custom permissions.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 #[scale_info(skip_type_params(T))]
@@ -26,9 +36,15 @@ pub struct Space<T: Config> {
     /// True, if the content of this space was edited.
     pub edited: bool,
 
-    /// The current owner of a given space.
+    /// The current owner of a given space, who holds the storage deposit of the space.
     pub owner: T::AccountId,
 
+    /// Accounts that own a given space together with its owner and have the same rights.
+    pub co_owners: SpaceCoOwners<T>,
+
+    /// An id of a parent space, if a given space is a subspace.
+    pub parent_id: Option<SpaceId>,
+
     // The next fields can be updated by the owner:
     pub content: Content,
 
@@ -36,9 +52,25 @@ pub struct Space<T: Config> {
     /// space and its' posts should not be shown.
     pub hidden: bool,
 
+    /// Archived space is read-only: no new posts, comments, reactions or role grants are allowed
+    /// in it, while its history remains visible.
+    pub archived: bool,
+
     /// This allows you to override Subsocial's default permissions by enabling or disabling role
     /// permissions.
     pub permissions: Option<SpacePermissions>,
+
+    /// The number of root posts (regular and shared) in a given space.
+    pub posts_count: u32,
+
+    /// The number of hidden root posts in a given space.
+    pub hidden_posts_count: u32,
+
+    /// The number of accounts that follow a given space.
+    pub followers_count: u32,
+
+    /// The amount reserved for storing this space, returned when the space is deleted.
+    pub deposit: StorageDepositOf<T>,
 }
 
 #[derive(Encode, Decode, Clone, Eq, PartialEq, Default, RuntimeDebug, TypeInfo)]
@@ -48,26 +80,51 @@ pub struct SpaceUpdate {
     pub permissions: Option<Option<SpacePermissions>>,
 }
 
+/// An encoded value of a space setting along with the version of its encoding.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct VersionedSettingValue {
+    pub version: u16,
+    pub value: Vec<u8>,
+}
+
 impl<T: Config> Space<T> {
     pub fn new(
         id: SpaceId,
+        parent_id: Option<SpaceId>,
         created_by: T::AccountId,
         content: Content,
         permissions: Option<SpacePermissions>,
+        deposit: StorageDepositOf<T>,
     ) -> Self {
         Space {
             id,
             created: new_who_and_when::<T>(created_by.clone()),
             edited: false,
             owner: created_by,
+            co_owners: Default::default(),
+            parent_id,
             content,
             hidden: false,
+            archived: false,
             permissions,
+            posts_count: 0,
+            hidden_posts_count: 0,
+            followers_count: 0,
+            deposit,
         }
     }
 
+    /// Check whether an account is either the owner or a co-owner of this space.
     pub fn is_owner(&self, account: &T::AccountId) -> bool {
-        self.owner == *account
+        self.owner == *account || self.co_owners.contains(account)
+    }
+
+    /// Get the owner and all co-owners of this space.
+    pub fn owners(&self) -> Vec<T::AccountId> {
+        let mut owners = Vec::with_capacity(self.co_owners.len() + 1);
+        owners.push(self.owner.clone());
+        owners.extend(self.co_owners.iter().cloned());
+        owners
     }
 
     pub fn is_follower(&self, account: &T::AccountId) -> bool {
@@ -79,6 +136,35 @@ impl<T: Config> Space<T> {
         Ok(())
     }
 
+    pub fn ensure_not_archived(&self) -> DispatchResult {
+        ensure!(!self.archived, Error::<T>::SpaceIsArchived);
+        Ok(())
+    }
+
+    pub fn inc_posts(&mut self) {
+        self.posts_count.saturating_inc();
+    }
+
+    pub fn dec_posts(&mut self) {
+        self.posts_count.saturating_dec();
+    }
+
+    pub fn inc_hidden_posts(&mut self) {
+        self.hidden_posts_count.saturating_inc();
+    }
+
+    pub fn dec_hidden_posts(&mut self) {
+        self.hidden_posts_count.saturating_dec();
+    }
+
+    pub fn inc_followers(&mut self) {
+        self.followers_count.saturating_inc();
+    }
+
+    pub fn dec_followers(&mut self) {
+        self.followers_count.saturating_dec();
+    }
+
     pub fn is_public(&self) -> bool {
         !self.hidden && self.content.is_some()
     }
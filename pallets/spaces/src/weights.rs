@@ -49,6 +49,16 @@ use sp_std::marker::PhantomData;
 pub trait WeightInfo {
     fn create_space() -> Weight;
     fn update_space() -> Weight;
+    fn create_subspace() -> Weight;
+    fn move_space() -> Weight;
+    fn set_space_handle() -> Weight;
+    fn delete_space() -> Weight;
+    fn continue_space_deletion() -> Weight;
+    fn update_space_setting() -> Weight;
+    fn set_space_archived() -> Weight;
+    fn propose_space_owner() -> Weight;
+    fn accept_space_ownership() -> Weight;
+    fn remove_space_owner() -> Weight;
 }
 
 /// Weights for pallet_spaces using the Substrate node and recommended hardware.
@@ -72,6 +82,109 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
             .saturating_add(T::DbWeight::get().reads(2))
             .saturating_add(T::DbWeight::get().writes(1))
         }
+            // Storage: Spaces SpaceIdsByOwner (r:1 w:1)
+            // Storage: Spaces SpaceById (r:1 w:1)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Spaces NextSpaceId (r:1 w:1)
+            // Storage: Timestamp Now (r:1 w:0)
+            // Storage: Spaces SubspaceIdsByParentId (r:1 w:1)
+        fn create_subspace() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(60_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(4))
+        }
+            // Storage: Spaces SpaceById (r:3 w:1)
+            // Storage: Spaces SubspaceIdsByParentId (r:3 w:2)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:2 w:0)
+        fn move_space() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(64_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().writes(3))
+        }
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Spaces HandleBySpaceId (r:1 w:1)
+            // Storage: Spaces SpaceIdByHandle (r:1 w:2)
+            // Storage: Domains RegisteredDomains (r:1 w:0)
+        fn set_space_handle() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(32_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(3))
+        }
+            // Storage: Spaces SpaceById (r:2 w:1)
+            // Storage: Spaces SubspaceIdsByParentId (r:2 w:2)
+            // Storage: Spaces HandleBySpaceId (r:1 w:1)
+            // Storage: Spaces SpaceIdByHandle (r:0 w:1)
+            // Storage: CreatorStaking RegisteredCreators (r:1 w:0)
+            // Storage: Spaces SpaceIdsByOwner (r:11 w:11)
+            // Storage: Profiles ProfileSpaceIdByAccount (r:11 w:11)
+            // Storage: Spaces SpaceSettingByKey (r:0 w:1)
+            // Storage: Spaces PendingSpaceOwners (r:1 w:1)
+            // Storage: Spaces SpaceCleanupQueueTail (r:1 w:1)
+            // Storage: Spaces SpaceIdsPendingCleanup (r:0 w:1)
+            // Storage: Spaces SpacePendingCleanup (r:0 w:1)
+        fn delete_space() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(160_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(30))
+            .saturating_add(T::DbWeight::get().writes(32))
+        }
+            // Storage: Spaces SpacePendingCleanup (r:1 w:1)
+        fn continue_space_deletion() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+        }
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Spaces SpaceSettingByKey (r:0 w:1)
+        fn update_space_setting() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(31_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+        }
+            // Storage: Spaces SpaceById (r:1 w:1)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+        fn set_space_archived() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(29_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(1))
+        }
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Moderation StatusByAccountInSpace (r:1 w:0)
+            // Storage: Spaces PendingSpaceOwners (r:2 w:1)
+        fn propose_space_owner() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(35_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(1))
+        }
+            // Storage: Spaces PendingSpaceOwners (r:1 w:1)
+            // Storage: Spaces SpaceById (r:1 w:1)
+            // Storage: Moderation StatusByAccountInSpace (r:1 w:0)
+            // Storage: Spaces SpaceIdsByOwner (r:1 w:1)
+        fn accept_space_ownership() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(42_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(3))
+        }
+            // Storage: Spaces SpaceById (r:1 w:1)
+            // Storage: Moderation StatusByAccountInSpace (r:1 w:0)
+            // Storage: Spaces PendingSpaceOwners (r:1 w:1)
+            // Storage: Spaces SpaceIdsByOwner (r:1 w:1)
+            // Storage: Profiles ProfileSpaceIdByAccount (r:1 w:1)
+        fn remove_space_owner() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(45_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(4))
+        }
     }
 
     // For backwards compatibility and tests
@@ -94,4 +207,107 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
             .saturating_add(RocksDbWeight::get().reads(2))
             .saturating_add(RocksDbWeight::get().writes(1))
         }
+            // Storage: Spaces SpaceIdsByOwner (r:1 w:1)
+            // Storage: Spaces SpaceById (r:1 w:1)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Spaces NextSpaceId (r:1 w:1)
+            // Storage: Timestamp Now (r:1 w:0)
+            // Storage: Spaces SubspaceIdsByParentId (r:1 w:1)
+        fn create_subspace() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(60_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().writes(4))
+        }
+            // Storage: Spaces SpaceById (r:3 w:1)
+            // Storage: Spaces SubspaceIdsByParentId (r:3 w:2)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:2 w:0)
+        fn move_space() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(64_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(8))
+            .saturating_add(RocksDbWeight::get().writes(3))
+        }
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Spaces HandleBySpaceId (r:1 w:1)
+            // Storage: Spaces SpaceIdByHandle (r:1 w:2)
+            // Storage: Domains RegisteredDomains (r:1 w:0)
+        fn set_space_handle() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(32_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(3))
+        }
+            // Storage: Spaces SpaceById (r:2 w:1)
+            // Storage: Spaces SubspaceIdsByParentId (r:2 w:2)
+            // Storage: Spaces HandleBySpaceId (r:1 w:1)
+            // Storage: Spaces SpaceIdByHandle (r:0 w:1)
+            // Storage: CreatorStaking RegisteredCreators (r:1 w:0)
+            // Storage: Spaces SpaceIdsByOwner (r:11 w:11)
+            // Storage: Profiles ProfileSpaceIdByAccount (r:11 w:11)
+            // Storage: Spaces SpaceSettingByKey (r:0 w:1)
+            // Storage: Spaces PendingSpaceOwners (r:1 w:1)
+            // Storage: Spaces SpaceCleanupQueueTail (r:1 w:1)
+            // Storage: Spaces SpaceIdsPendingCleanup (r:0 w:1)
+            // Storage: Spaces SpacePendingCleanup (r:0 w:1)
+        fn delete_space() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(160_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(30))
+            .saturating_add(RocksDbWeight::get().writes(32))
+        }
+            // Storage: Spaces SpacePendingCleanup (r:1 w:1)
+        fn continue_space_deletion() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(15_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+        }
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+            // Storage: Spaces SpaceSettingByKey (r:0 w:1)
+        fn update_space_setting() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(31_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(1))
+        }
+            // Storage: Spaces SpaceById (r:1 w:1)
+            // Storage: SpaceFollows SpaceFollowedByAccount (r:1 w:0)
+        fn set_space_archived() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(29_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(1))
+        }
+            // Storage: Spaces SpaceById (r:1 w:0)
+            // Storage: Moderation StatusByAccountInSpace (r:1 w:0)
+            // Storage: Spaces PendingSpaceOwners (r:2 w:1)
+        fn propose_space_owner() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(35_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(1))
+        }
+            // Storage: Spaces PendingSpaceOwners (r:1 w:1)
+            // Storage: Spaces SpaceById (r:1 w:1)
+            // Storage: Moderation StatusByAccountInSpace (r:1 w:0)
+            // Storage: Spaces SpaceIdsByOwner (r:1 w:1)
+        fn accept_space_ownership() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(42_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(3))
+        }
+            // Storage: Spaces SpaceById (r:1 w:1)
+            // Storage: Moderation StatusByAccountInSpace (r:1 w:0)
+            // Storage: Spaces PendingSpaceOwners (r:1 w:1)
+            // Storage: Spaces SpaceIdsByOwner (r:1 w:1)
+            // Storage: Profiles ProfileSpaceIdByAccount (r:1 w:1)
+        fn remove_space_owner() -> Weight {
+        // PLACEHOLDER weights: hand-written estimate, not benchmark output.
+        Weight::from_parts(45_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(4))
+        }
     }
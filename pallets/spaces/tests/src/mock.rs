@@ -93,6 +93,13 @@ impl pallet_balances::Config for Test {
     type MaxHolds = ();
 }
 
+parameter_types! {
+    pub static SpaceDeposit: Balance = 0;
+    pub static PostDeposit: Balance = 0;
+    pub static RoleDeposit: Balance = 0;
+    pub static FollowDeposit: Balance = 0;
+}
+
 parameter_types! {
     pub const MaxCommentDepth: u32 = 10;
 }
@@ -101,6 +108,14 @@ impl pallet_posts::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type MaxCommentDepth = MaxCommentDepth;
     type IsPostBlocked = MockModeration;
+    type PostDeletionHandler = ();
+    type PostVisibilityHandler = ();
+    type PostDeposit = PostDeposit;
+    type MaxPinnedPostsPerSpace = frame_support::traits::ConstU32<3>;
+    type MaxPostRevisions = frame_support::traits::ConstU32<3>;
+    type MaxScheduledPostsPerBlock = frame_support::traits::ConstU32<3>;
+    type MaxPollOptions = frame_support::traits::ConstU32<3>;
+    type BackerStakesProvider = ();
     type WeightInfo = ();
 }
 
@@ -115,6 +130,8 @@ parameter_types! {
 impl pallet_roles::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type MaxUsersToProcessPerDeleteRole = MaxUsersToProcessPerDeleteRole;
+    type Currency = Balances;
+    type RoleDeposit = RoleDeposit;
     type SpacePermissionsProvider = Spaces;
     type SpaceFollows = SpaceFollows;
     type IsAccountBlocked = MockModeration;
@@ -127,6 +144,8 @@ impl pallet_profiles::Config for Test {
     type SpacePermissionsProvider = Spaces;
     type SpacesProvider = Spaces;
     type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
 }
 
 impl pallet_spaces::Config for Test {
@@ -136,10 +155,23 @@ impl pallet_spaces::Config for Test {
     type IsAccountBlocked = MockModeration;
     type IsContentBlocked = MockModeration;
     type MaxSpacesPerAccount = ConstU32<100>;
+    type MaxSubspaceDepth = ConstU32<10>;
+    type DomainsProvider = ();
+    type MinHandleLen = ConstU32<5>;
+    type MaxHandleLen = ConstU32<50>;
+    type ProfileManager = Profiles;
+    type CreatorStakingProvider = ();
+    type SpaceDeletionHandler = (Posts, Roles, SpaceFollows);
+    type SpaceSettingsRegistry = Posts;
+    type Currency = Balances;
+    type SpaceDeposit = SpaceDeposit;
+    type MaxSpaceCoOwners = frame_support::traits::ConstU32<3>;
     type WeightInfo = ();
 }
 
 impl pallet_space_follows::Config for Test {
     type RuntimeEvent = RuntimeEvent;
+    type FollowDeposit = FollowDeposit;
+    type InvitationDeposit = frame_support::traits::ConstU64<0>;
     type WeightInfo = ();
 }
@@ -4,11 +4,27 @@
 // Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
 // Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
 
-use frame_support::{assert_noop, assert_ok};
+use codec::Encode;
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Get, Hooks},
+    weights::Weight,
+};
 
 use pallet_permissions::SpacePermission as SP;
-use pallet_spaces::Error as SpacesError;
-use subsocial_support::{mock_functions::*, ContentError, ModerationError};
+use pallet_posts::{
+    CommentsPolicy, CommentsPolicySetting, Error as PostsError, PostAccessPolicy,
+    PostRevisionsRetentionSetting, PostUpdate,
+};
+use pallet_roles::Error as RolesError;
+use pallet_spaces::{
+    rpc::FlatSpace, Error as SpacesError, SpaceCleanupQueueHead, SpaceCleanupQueueTail,
+    SpaceIdsPendingCleanup,
+};
+use subsocial_support::{
+    mock_functions::*, new_who_and_when, traits::SpaceSetting, ContentError, ModerationError,
+    SpaceId, User,
+};
 
 use crate::{mock::*, tests_utils::*};
 
@@ -196,6 +212,1004 @@ fn update_space_should_fail_when_no_right_permission_in_account_roles() {
     );
 }
 
+#[test]
+fn create_subspace_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_subspace()); // SpaceId 2
+
+        let subspace = Spaces::space_by_id(SPACE2).unwrap();
+        assert_eq!(subspace.parent_id, Some(SPACE1));
+        assert_eq!(subspace.owner, ACCOUNT1);
+        assert_eq!(Spaces::subspace_ids_by_parent_id(SPACE1), vec![SPACE2]);
+        assert_eq!(Spaces::space_depth(&subspace), 1);
+    });
+}
+
+#[test]
+fn create_subspace_should_fail_when_account_has_no_permission() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _create_subspace(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None),
+            SpacesError::<Test>::NoPermissionToCreateSubspaces
+        );
+    });
+}
+
+#[test]
+fn create_subspace_should_fail_when_parent_space_is_hidden() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_update_space(None, None, Some(space_update(None, Some(true)))));
+
+        assert_noop!(_create_default_subspace(), SpacesError::<Test>::CannotCreateInHiddenScope);
+    });
+}
+
+#[test]
+fn create_subspace_should_fail_when_max_depth_reached() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        let max_depth = <Test as pallet_spaces::Config>::MaxSubspaceDepth::get();
+
+        let mut parent_id = SPACE1;
+        for _ in 0..max_depth {
+            let space_id = Spaces::next_space_id();
+            assert_ok!(_create_subspace(None, Some(parent_id), None));
+            parent_id = space_id;
+        }
+
+        assert_noop!(
+            _create_subspace(None, Some(parent_id), None),
+            SpacesError::<Test>::MaxSubspaceDepthReached
+        );
+    });
+}
+
+#[test]
+fn create_subspace_should_fail_when_overriding_permissions_without_permission() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::CreateSubspaces]).execute_with(
+        || {
+            assert_noop!(
+                _create_subspace(
+                    Some(RuntimeOrigin::signed(ACCOUNT2)),
+                    None,
+                    Some(Some(permissions_where_everyone_can_create_post())),
+                ),
+                SpacesError::<Test>::NoPermissionToOverrideSubspacePermissions
+            );
+        },
+    );
+}
+
+#[test]
+fn subspace_should_inherit_parent_permissions() {
+    ExtBuilder::build_with_space_and_custom_permissions(
+        permissions_where_everyone_can_create_post(),
+    )
+    .execute_with(|| {
+        assert_ok!(_create_default_subspace()); // SpaceId 2
+
+        assert_ok!(_create_post(
+            Some(RuntimeOrigin::signed(ACCOUNT2)),
+            Some(Some(SPACE2)),
+            None,
+            None
+        ));
+    });
+}
+
+#[test]
+fn subspace_permissions_should_override_parent_permissions() {
+    ExtBuilder::build_with_space_and_custom_permissions(
+        permissions_where_everyone_can_create_post(),
+    )
+    .execute_with(|| {
+        let default_permissions =
+            <Test as pallet_permissions::Config>::DefaultSpacePermissions::get();
+        assert_ok!(_create_subspace(None, None, Some(Some(default_permissions)))); // SpaceId 2
+
+        assert_noop!(
+            _create_post(Some(RuntimeOrigin::signed(ACCOUNT2)), Some(Some(SPACE2)), None, None),
+            pallet_posts::Error::<Test>::NoPermissionToCreatePosts
+        );
+    });
+}
+
+#[test]
+fn update_space_should_hide_subspace_with_parent_permissions() {
+    ExtBuilder::build_with_subspace_of_account2().execute_with(|| {
+        let hide = space_update(None, Some(true));
+        let unhide = space_update(None, Some(false));
+
+        // The owner of a subspace can hide it.
+        assert_ok!(_update_space(
+            Some(RuntimeOrigin::signed(ACCOUNT2)),
+            Some(SPACE2),
+            Some(hide.clone())
+        ));
+
+        // The owner of a parent space can hide any subspace.
+        assert_ok!(_update_space(None, Some(SPACE2), Some(unhide)));
+
+        assert_noop!(
+            _update_space(Some(RuntimeOrigin::signed(ACCOUNT3)), Some(SPACE2), Some(hide)),
+            SpacesError::<Test>::NoPermissionToHideAnySubspace
+        );
+    });
+}
+
+#[test]
+fn update_space_should_update_subspace_with_parent_permissions() {
+    ExtBuilder::build_with_subspace_of_account2().execute_with(|| {
+        let update = update_for_space_content(updated_space_content());
+
+        assert_ok!(_update_space(None, Some(SPACE2), Some(update.clone())));
+        assert_eq!(Spaces::space_by_id(SPACE2).unwrap().content, updated_space_content());
+
+        assert_noop!(
+            _update_space(Some(RuntimeOrigin::signed(ACCOUNT3)), Some(SPACE2), Some(update)),
+            SpacesError::<Test>::NoPermissionToUpdateSpace
+        );
+    });
+}
+
+#[test]
+fn move_space_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_space()); // SpaceId 2
+
+        assert_ok!(_move_space(None, None, None));
+
+        assert_eq!(Spaces::space_by_id(SPACE2).unwrap().parent_id, Some(SPACE1));
+        assert_eq!(Spaces::subspace_ids_by_parent_id(SPACE1), vec![SPACE2]);
+        System::assert_last_event(
+            pallet_spaces::Event::SpaceMoved {
+                account: ACCOUNT1,
+                space_id: SPACE2,
+                from_parent: None,
+                to_parent: Some(SPACE1),
+            }
+            .into(),
+        );
+
+        assert_ok!(_move_space(None, None, Some(None)));
+
+        assert_eq!(Spaces::space_by_id(SPACE2).unwrap().parent_id, None);
+        assert!(Spaces::subspace_ids_by_parent_id(SPACE1).is_empty());
+    });
+}
+
+#[test]
+fn move_space_should_fail_for_invalid_moves() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_subspace()); // SpaceId 2
+        assert_ok!(_create_default_space()); // SpaceId 3
+
+        assert_noop!(
+            _move_space(None, Some(SPACE3), Some(None)),
+            SpacesError::<Test>::SpaceIsAtRoot
+        );
+        assert_noop!(_move_space(None, None, None), SpacesError::<Test>::CannotMoveToSameParent);
+        assert_noop!(
+            _move_space(None, None, Some(Some(SPACE2))),
+            SpacesError::<Test>::CannotMoveSpaceUnderItself
+        );
+        assert_noop!(
+            _move_space(None, Some(SPACE1), Some(Some(SPACE3))),
+            SpacesError::<Test>::CannotMoveSpaceWithSubspaces
+        );
+    });
+}
+
+#[test]
+fn move_space_should_fail_when_account_has_no_permission_in_new_parent() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_space(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None)); // SpaceId 2
+
+        assert_noop!(
+            _move_space(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None),
+            SpacesError::<Test>::NoPermissionToCreateSubspaces
+        );
+    });
+}
+
+#[test]
+fn set_space_handle_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_set_default_space_handle());
+
+        let handle_lc = space_handle().to_ascii_lowercase();
+        assert_eq!(Spaces::handle_by_space_id(SPACE1), Some(handle_lc.clone()));
+        assert_eq!(Spaces::space_id_by_handle(handle_lc), Some(SPACE1));
+        assert_eq!(Spaces::get_space_id_by_handle(b"SPACE_HANDLE".to_vec()), Some(SPACE1));
+    });
+}
+
+#[test]
+fn set_space_handle_should_replace_and_remove_handle() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        let new_handle = b"new_handle".to_vec();
+
+        assert_ok!(_set_default_space_handle());
+        assert_ok!(_set_space_handle(None, None, Some(Some(new_handle.clone()))));
+
+        assert_eq!(Spaces::get_space_id_by_handle(space_handle()), None);
+        assert_eq!(Spaces::get_space_id_by_handle(new_handle.clone()), Some(SPACE1));
+
+        assert_ok!(_set_space_handle(None, None, Some(None)));
+
+        assert_eq!(Spaces::handle_by_space_id(SPACE1), None);
+        assert_eq!(Spaces::get_space_id_by_handle(new_handle), None);
+    });
+}
+
+#[test]
+fn set_space_handle_should_fail_when_handle_is_not_unique() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_space()); // SpaceId 2
+        assert_ok!(_set_default_space_handle());
+
+        assert_noop!(
+            _set_space_handle(None, Some(SPACE2), Some(Some(b"SPACE_handle".to_vec()))),
+            SpacesError::<Test>::HandleIsNotUnique
+        );
+    });
+}
+
+#[test]
+fn set_space_handle_should_fail_when_handle_is_invalid() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        let max_len = <Test as pallet_spaces::Config>::MaxHandleLen::get() as usize;
+
+        assert_noop!(
+            _set_space_handle(None, None, Some(Some(b"abc".to_vec()))),
+            ModerationError::HandleIsTooShort
+        );
+        assert_noop!(
+            _set_space_handle(None, None, Some(Some(vec![b'a'; max_len + 1]))),
+            ModerationError::HandleIsTooLong
+        );
+        assert_noop!(
+            _set_space_handle(None, None, Some(Some(b"space-handle".to_vec()))),
+            ModerationError::HandleContainsInvalidChars
+        );
+    });
+}
+
+#[test]
+fn set_space_handle_should_fail_when_account_is_not_space_owner() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _set_space_handle(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None),
+            SpacesError::<Test>::NotASpaceOwner
+        );
+    });
+}
+
+#[test]
+fn delete_space_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_set_default_space_handle());
+        assert_ok!(Profiles::set_profile(RuntimeOrigin::signed(ACCOUNT1), SPACE1));
+
+        assert_ok!(_delete_space(None, None));
+
+        assert!(Spaces::space_by_id(SPACE1).is_none());
+        assert!(!Spaces::space_ids_by_owner(ACCOUNT1).contains(&SPACE1));
+        assert_eq!(Spaces::get_space_id_by_handle(space_handle()), None);
+        assert_eq!(Profiles::profile_space_id_by_account(ACCOUNT1), None);
+        assert!(Spaces::is_space_pending_cleanup(SPACE1));
+        System::assert_last_event(
+            pallet_spaces::Event::SpaceDeleted { account: ACCOUNT1, space_id: SPACE1 }.into(),
+        );
+
+        assert_noop!(_create_default_post(), SpacesError::<Test>::SpaceNotFound);
+    });
+}
+
+#[test]
+fn continue_space_deletion_should_clean_up_space_data() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::CreatePosts]).execute_with(
+        || {
+            let post_id = Posts::next_post_id();
+            assert_ok!(_create_default_post());
+            assert_ok!(_create_post(None, None, Some(extension_comment(None, post_id)), None));
+            assert_ok!(_default_follow_space());
+
+            assert_ok!(_delete_space(None, None));
+
+            // 2 posts, 2 roles, 2 role grants and 1 follower
+            assert_ok!(_continue_space_deletion(None, 6));
+            assert!(Spaces::is_space_pending_cleanup(SPACE1));
+
+            assert_ok!(_continue_space_deletion(None, 6));
+            assert!(!Spaces::is_space_pending_cleanup(SPACE1));
+            System::assert_last_event(
+                pallet_spaces::Event::SpaceDeletionCompleted { space_id: SPACE1 }.into(),
+            );
+
+            assert!(Posts::post_by_id(post_id).is_none());
+            assert!(Posts::post_by_id(post_id + 1).is_none());
+            assert!(Posts::post_ids_by_space_id(SPACE1).is_empty());
+            assert!(Posts::reply_ids_by_post_id(post_id).is_empty());
+            assert!(Roles::role_by_id(ROLE1).is_none());
+            assert!(Roles::role_ids_by_space_id(SPACE1).is_empty());
+            assert!(Roles::role_ids_by_user_in_space(User::Account(ACCOUNT2), SPACE1).is_empty());
+            assert!(SpaceFollows::space_followers(SPACE1).is_empty());
+            assert!(!SpaceFollows::space_followed_by_account((ACCOUNT2, SPACE1)));
+            assert!(!SpaceFollows::spaces_followed_by_account(ACCOUNT2).contains(&SPACE1));
+
+            assert_noop!(
+                _continue_space_deletion(None, 6),
+                SpacesError::<Test>::SpaceIsNotBeingDeleted
+            );
+        },
+    );
+}
+
+#[test]
+fn on_idle_should_clean_up_deleted_spaces() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_post());
+        assert_ok!(_default_follow_space());
+        assert_ok!(_delete_space(None, None));
+
+        Spaces::on_idle(System::block_number(), Weight::MAX);
+
+        assert!(!Spaces::is_space_pending_cleanup(SPACE1));
+        assert!(Posts::post_ids_by_space_id(SPACE1).is_empty());
+        assert!(SpaceFollows::space_followers(SPACE1).is_empty());
+    });
+}
+
+#[test]
+fn on_idle_should_skip_spaces_cleaned_up_with_continue_space_deletion() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_delete_space(None, None));
+        assert_ok!(_continue_space_deletion(None, 10));
+        assert!(!Spaces::is_space_pending_cleanup(SPACE1));
+        System::reset_events();
+
+        Spaces::on_idle(System::block_number(), Weight::MAX);
+
+        assert!(SpaceIdsPendingCleanup::<Test>::get(0).is_none());
+        assert_eq!(SpaceCleanupQueueHead::<Test>::get(), SpaceCleanupQueueTail::<Test>::get());
+        assert!(System::events().is_empty());
+    });
+}
+
+#[test]
+fn create_space_should_reserve_deposit() {
+    SpaceDeposit::set(10);
+    ExtBuilder::build().execute_with(|| {
+        assert_ok!(_create_default_space());
+
+        let space = Spaces::space_by_id(SPACE1).unwrap();
+        assert_eq!(space.deposit.depositor, ACCOUNT1);
+        assert_eq!(space.deposit.deposit, 10);
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), 10);
+    });
+}
+
+#[test]
+fn force_create_space_should_release_deposit_when_owner_changes() {
+    SpaceDeposit::set(10);
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), 10);
+
+        assert_ok!(Spaces::force_create_space(
+            RuntimeOrigin::root(),
+            SPACE1,
+            new_who_and_when::<Test>(ACCOUNT2),
+            ACCOUNT2,
+            space_content_ipfs(),
+            false,
+            None,
+        ));
+
+        let space = Spaces::space_by_id(SPACE1).unwrap();
+        assert_eq!(space.owner, ACCOUNT2);
+        assert_eq!(space.deposit.depositor, ACCOUNT2);
+        assert_eq!(space.deposit.deposit, 0);
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), 0);
+        assert_eq!(Balances::reserved_balance(ACCOUNT2), 0);
+    });
+}
+
+#[test]
+fn create_space_should_fail_when_balance_is_too_low_for_deposit() {
+    SpaceDeposit::set(1_000);
+    ExtBuilder::build().execute_with(|| {
+        assert_noop!(_create_default_space(), pallet_balances::Error::<Test>::InsufficientBalance);
+    });
+}
+
+#[test]
+fn unfollow_space_should_release_follow_deposit() {
+    FollowDeposit::set(2);
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_default_follow_space());
+        assert_eq!(SpaceFollows::space_follow_deposit((ACCOUNT2, SPACE1)), 2);
+        assert_eq!(Balances::reserved_balance(ACCOUNT2), 2);
+
+        assert_ok!(SpaceFollows::unfollow_space(RuntimeOrigin::signed(ACCOUNT2), SPACE1));
+        assert_eq!(SpaceFollows::space_follow_deposit((ACCOUNT2, SPACE1)), 0);
+        assert_eq!(Balances::reserved_balance(ACCOUNT2), 0);
+    });
+}
+
+#[test]
+fn space_deletion_should_release_deposits_of_space_and_its_data() {
+    SpaceDeposit::set(10);
+    PostDeposit::set(5);
+    RoleDeposit::set(3);
+    FollowDeposit::set(2);
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_post());
+        assert_ok!(_create_default_role());
+        assert_ok!(_default_follow_space());
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), 10 + 5 + 3);
+        assert_eq!(Balances::reserved_balance(ACCOUNT2), 2);
+
+        assert_ok!(_delete_space(None, None));
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), 5 + 3);
+
+        assert_ok!(_continue_space_deletion(None, 10));
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), 0);
+        assert_eq!(Balances::reserved_balance(ACCOUNT2), 0);
+    });
+}
+
+#[test]
+fn space_deletion_should_remove_replies_of_deleted_root_post() {
+    PostDeposit::set(5);
+    ExtBuilder::build_with_space().execute_with(|| {
+        let post_id = Posts::next_post_id();
+        assert_ok!(_create_default_post());
+        assert_ok!(_create_post(None, None, Some(extension_comment(None, post_id)), None));
+        assert_ok!(Posts::delete_post(RuntimeOrigin::signed(ACCOUNT1), post_id));
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), 5);
+
+        // The deleted root post stays indexed, so that its replies can be reached.
+        assert_eq!(Posts::post_ids_by_space_id(SPACE1), vec![post_id]);
+        assert_eq!(Posts::post_by_id(post_id + 1).unwrap().try_get_space_id(), Some(SPACE1));
+
+        assert_ok!(_delete_space(None, None));
+        assert_ok!(_continue_space_deletion(None, 10));
+
+        assert!(!Spaces::is_space_pending_cleanup(SPACE1));
+        assert!(Posts::post_by_id(post_id + 1).is_none());
+        assert!(Posts::post_ids_by_space_id(SPACE1).is_empty());
+        assert!(Posts::reply_ids_by_post_id(post_id).is_empty());
+        assert!(Posts::post_tombstone_by_id(post_id).is_none());
+        assert_eq!(Balances::reserved_balance(ACCOUNT1), 0);
+    });
+}
+
+#[test]
+fn delete_space_should_fail_when_space_has_subspaces() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_subspace()); // SpaceId 2
+
+        assert_noop!(
+            _delete_space(None, None),
+            SpacesError::<Test>::CannotDeleteSpaceWithSubspaces
+        );
+    });
+}
+
+#[test]
+fn delete_space_should_fail_when_account_is_not_space_owner() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _delete_space(Some(RuntimeOrigin::signed(ACCOUNT2)), None),
+            SpacesError::<Test>::NotASpaceOwner
+        );
+    });
+}
+
+#[test]
+fn delete_space_should_delete_subspace_with_parent_permissions() {
+    ExtBuilder::build_with_subspace_of_account2().execute_with(|| {
+        assert_noop!(
+            _delete_space(Some(RuntimeOrigin::signed(ACCOUNT3)), Some(SPACE2)),
+            SpacesError::<Test>::NoPermissionToDeleteAnySubspace
+        );
+
+        // The owner of a parent space can delete any subspace.
+        assert_ok!(_delete_space(None, Some(SPACE2)));
+
+        assert!(Spaces::space_by_id(SPACE2).is_none());
+        assert!(Spaces::subspace_ids_by_parent_id(SPACE1).is_empty());
+    });
+}
+
+#[test]
+fn update_space_setting_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        let post_id = Posts::next_post_id();
+        assert_ok!(_create_default_post());
+
+        assert_ok!(_set_comments_policy(None, CommentsPolicy::Closed));
+
+        assert_eq!(Spaces::space_setting::<CommentsPolicySetting>(SPACE1), CommentsPolicy::Closed);
+        System::assert_last_event(
+            pallet_spaces::Event::SpaceSettingUpdated {
+                account: ACCOUNT1,
+                space_id: SPACE1,
+                key: CommentsPolicySetting::KEY.to_vec(),
+                value: Some(CommentsPolicy::Closed.encode()),
+            }
+            .into(),
+        );
+        assert_noop!(
+            _create_post(None, None, Some(extension_comment(None, post_id)), None),
+            PostsError::<Test>::CommentsAreClosed
+        );
+
+        // Reset the setting to its default value.
+        assert_ok!(_update_space_setting(None, None, CommentsPolicySetting::KEY.to_vec(), None));
+
+        assert!(Spaces::space_setting_by_key(SPACE1, CommentsPolicySetting::KEY).is_none());
+        assert_eq!(Spaces::space_setting::<CommentsPolicySetting>(SPACE1), CommentsPolicy::Open);
+        assert_ok!(_create_post(None, None, Some(extension_comment(None, post_id)), None));
+    });
+}
+
+#[test]
+fn update_space_setting_should_work_with_update_space_settings_permission() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::UpdateSpaceSettings])
+        .execute_with(|| {
+            assert_ok!(_set_comments_policy(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                CommentsPolicy::FollowersOnly
+            ));
+
+            assert_eq!(
+                Spaces::space_setting::<CommentsPolicySetting>(SPACE1),
+                CommentsPolicy::FollowersOnly
+            );
+        });
+}
+
+#[test]
+fn update_space_setting_should_fail_when_no_permission() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _set_comments_policy(Some(RuntimeOrigin::signed(ACCOUNT2)), CommentsPolicy::Closed),
+            SpacesError::<Test>::NoPermissionToUpdateSpaceSettings
+        );
+    });
+}
+
+#[test]
+fn update_space_setting_should_fail_when_setting_is_unknown() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _update_space_setting(None, None, b"posts:unknown".to_vec(), Some(vec![0])),
+            SpacesError::<Test>::UnknownSpaceSetting
+        );
+    });
+}
+
+#[test]
+fn update_space_setting_should_fail_when_value_is_invalid() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        let key = CommentsPolicySetting::KEY.to_vec();
+
+        assert_noop!(
+            _update_space_setting(None, None, key.clone(), Some(vec![])),
+            SpacesError::<Test>::InvalidSpaceSettingValue
+        );
+        assert_noop!(
+            _update_space_setting(None, None, key, Some(vec![0, 0])),
+            SpacesError::<Test>::InvalidSpaceSettingValue
+        );
+    });
+}
+
+#[test]
+fn delete_space_should_remove_space_settings() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_set_comments_policy(None, CommentsPolicy::Closed));
+
+        assert_ok!(_delete_space(None, None));
+
+        assert!(Spaces::space_setting_by_key(SPACE1, CommentsPolicySetting::KEY).is_none());
+    });
+}
+
+#[test]
+fn set_space_archived_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        let post_id = Posts::next_post_id();
+        assert_ok!(_create_default_post());
+
+        assert_ok!(_set_space_archived(None, None, true));
+
+        assert!(Spaces::space_by_id(SPACE1).unwrap().archived);
+        System::assert_last_event(
+            pallet_spaces::Event::SpaceArchived { account: ACCOUNT1, space_id: SPACE1 }.into(),
+        );
+
+        // Archived space can still be read.
+        assert!(Posts::post_by_id(post_id).is_some());
+        assert!(Spaces::get_spaces_by_ids(vec![SPACE1])[0].is_archived);
+
+        // Unarchive the space.
+        assert_ok!(_set_space_archived(None, None, false));
+
+        assert!(!Spaces::space_by_id(SPACE1).unwrap().archived);
+        System::assert_last_event(
+            pallet_spaces::Event::SpaceUnarchived { account: ACCOUNT1, space_id: SPACE1 }.into(),
+        );
+        assert_ok!(_create_default_post());
+    });
+}
+
+#[test]
+fn set_space_archived_should_fail_when_nothing_changes() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _set_space_archived(None, None, false),
+            SpacesError::<Test>::NoUpdatesForSpace
+        );
+    });
+}
+
+#[test]
+fn set_space_archived_should_fail_when_no_permission() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _set_space_archived(Some(RuntimeOrigin::signed(ACCOUNT2)), None, true),
+            SpacesError::<Test>::NoPermissionToUpdateSpace
+        );
+    });
+}
+
+#[test]
+fn set_space_archived_should_work_with_update_space_permission() {
+    ExtBuilder::build_with_a_few_roles_granted_to_account2(vec![SP::UpdateSpace]).execute_with(
+        || {
+            assert_ok!(_set_space_archived(Some(RuntimeOrigin::signed(ACCOUNT2)), None, true));
+            assert!(Spaces::space_by_id(SPACE1).unwrap().archived);
+
+            // The owner can unarchive the space.
+            assert_ok!(_set_space_archived(None, None, false));
+            assert!(!Spaces::space_by_id(SPACE1).unwrap().archived);
+        },
+    );
+}
+
+#[test]
+fn archived_space_should_not_accept_new_content() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        let post_id = Posts::next_post_id();
+        assert_ok!(_create_default_post());
+        assert_ok!(_create_default_role());
+
+        assert_ok!(_set_space_archived(None, None, true));
+
+        assert_noop!(_create_default_post(), SpacesError::<Test>::SpaceIsArchived);
+        assert_noop!(
+            _create_post(None, None, Some(extension_comment(None, post_id)), None),
+            SpacesError::<Test>::SpaceIsArchived
+        );
+        assert_noop!(_create_default_subspace(), SpacesError::<Test>::SpaceIsArchived);
+        assert_noop!(_default_follow_space(), SpacesError::<Test>::SpaceIsArchived);
+        assert_noop!(_create_default_role(), RolesError::<Test>::SpaceIsArchived);
+        assert_noop!(_grant_role(None, None, None), RolesError::<Test>::SpaceIsArchived);
+    });
+}
+
+#[test]
+fn archived_space_should_not_accept_post_updates() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        let post_id = Posts::next_post_id();
+        assert_ok!(_create_default_post());
+        assert_ok!(_create_default_space()); // SpaceId 2
+
+        assert_ok!(_set_space_archived(None, None, true));
+
+        let update_post = |content, hidden| {
+            Posts::update_post(
+                RuntimeOrigin::signed(ACCOUNT1),
+                post_id,
+                PostUpdate { space_id: None, content, hidden },
+            )
+        };
+        assert_noop!(
+            update_post(Some(valid_content_ipfs()), None),
+            SpacesError::<Test>::SpaceIsArchived
+        );
+        assert_noop!(update_post(None, Some(true)), SpacesError::<Test>::SpaceIsArchived);
+        assert_noop!(
+            Posts::move_post(RuntimeOrigin::signed(ACCOUNT1), post_id, Some(SPACE2)),
+            SpacesError::<Test>::SpaceIsArchived
+        );
+        assert_noop!(
+            Posts::set_post_access_policy(
+                RuntimeOrigin::signed(ACCOUNT1),
+                post_id,
+                Some(PostAccessPolicy::CreatorBackers { min_stake: 1 }),
+            ),
+            SpacesError::<Test>::SpaceIsArchived
+        );
+    });
+}
+
+#[test]
+fn rpc_get_space_settings_should_return_effective_values() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        let settings = Spaces::get_space_settings(SPACE1);
+        assert_eq!(settings.len(), 2);
+        assert_eq!(settings[0].key, CommentsPolicySetting::KEY.to_vec());
+        assert_eq!(settings[1].key, PostRevisionsRetentionSetting::KEY.to_vec());
+        assert_eq!(settings[0].value, CommentsPolicy::Open.encode());
+        assert!(settings[0].is_default);
+
+        assert_ok!(_set_comments_policy(None, CommentsPolicy::FollowersOnly));
+
+        let settings = Spaces::get_space_settings(SPACE1);
+        assert_eq!(settings[0].value, CommentsPolicy::FollowersOnly.encode());
+        assert!(!settings[0].is_default);
+    });
+}
+
+fn flat_space_ids(spaces: Vec<FlatSpace<AccountId, BlockNumber>>) -> Vec<SpaceId> {
+    spaces.into_iter().map(|space| space.id).collect()
+}
+
+#[test]
+fn rpc_get_spaces_should_paginate_and_filter_spaces() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_space(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None)); // SpaceId 2
+        assert_ok!(_create_default_space()); // SpaceId 3
+        assert_ok!(_update_space(
+            Some(RuntimeOrigin::signed(ACCOUNT2)),
+            Some(SPACE2),
+            Some(space_update(None, Some(true)))
+        ));
+
+        assert_eq!(flat_space_ids(Spaces::get_spaces(SPACE3, 2)), vec![SPACE3, SPACE2]);
+        assert_eq!(flat_space_ids(Spaces::get_spaces(SPACE1, 10)), vec![SPACE1]);
+        assert_eq!(flat_space_ids(Spaces::get_public_spaces(SPACE3, 10)), vec![SPACE3, SPACE1]);
+        assert_eq!(flat_space_ids(Spaces::get_unlisted_spaces(SPACE3, 10)), vec![SPACE2]);
+
+        assert_eq!(Spaces::get_space_ids_by_owner(ACCOUNT1), vec![SPACE1, SPACE3]);
+    });
+}
+
+#[test]
+fn rpc_get_spaces_should_start_from_last_space_when_start_id_is_too_big() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_create_default_space()); // SpaceId 2
+
+        assert_eq!(
+            flat_space_ids(Spaces::get_spaces(SpaceId::MAX, u64::MAX)),
+            vec![SPACE2, SPACE1]
+        );
+        assert!(Spaces::get_unlisted_spaces(SpaceId::MAX, u64::MAX).is_empty());
+    });
+}
+
+#[test]
+fn rpc_get_spaces_by_ids_should_skip_unknown_spaces() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_set_default_space_handle());
+        assert_ok!(_default_follow_space());
+
+        let spaces = Spaces::get_spaces_by_ids(vec![SPACE1, SPACE2]);
+        assert_eq!(spaces.len(), 1);
+
+        let space = &spaces[0];
+        assert_eq!(space.id, SPACE1);
+        assert_eq!(space.owner_id, ACCOUNT1);
+        assert_eq!(space.handle, Some(space_handle().to_ascii_lowercase()));
+        assert_eq!(space.content, space_content_ipfs());
+        assert!(!space.is_hidden);
+        assert_eq!(space.followers_count, 1);
+    });
+}
+
+#[test]
+fn propose_space_owner_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_propose_space_owner(None, None, ACCOUNT2));
+
+        assert_eq!(Spaces::pending_space_owner(SPACE1, ACCOUNT2), Some(ACCOUNT1));
+        // The account is not an owner until it accepts the proposal.
+        assert!(Spaces::space_by_id(SPACE1).unwrap().co_owners.is_empty());
+        System::assert_last_event(
+            pallet_spaces::Event::SpaceOwnerProposed {
+                account: ACCOUNT1,
+                space_id: SPACE1,
+                new_owner: ACCOUNT2,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn propose_space_owner_should_fail_when_not_an_owner() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _propose_space_owner(Some(RuntimeOrigin::signed(ACCOUNT2)), None, ACCOUNT3),
+            SpacesError::<Test>::NotASpaceOwner
+        );
+    });
+}
+
+#[test]
+fn propose_space_owner_should_fail_when_account_is_already_an_owner_or_proposed() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _propose_space_owner(None, None, ACCOUNT1),
+            SpacesError::<Test>::AlreadyASpaceOwner
+        );
+
+        assert_ok!(_propose_space_owner(None, None, ACCOUNT2));
+        assert_noop!(
+            _propose_space_owner(None, None, ACCOUNT2),
+            SpacesError::<Test>::SpaceOwnerAlreadyProposed
+        );
+    });
+}
+
+#[test]
+fn propose_space_owner_should_fail_when_too_many_co_owners() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        // Pending proposals count towards the limit of co-owners.
+        let max_co_owners: u32 = <Test as pallet_spaces::Config>::MaxSpaceCoOwners::get();
+        for new_owner in 0..max_co_owners as AccountId {
+            assert_ok!(_propose_space_owner(None, None, 100 + new_owner));
+        }
+
+        assert_noop!(
+            _propose_space_owner(None, None, ACCOUNT2),
+            SpacesError::<Test>::TooManySpaceCoOwners
+        );
+    });
+}
+
+#[test]
+fn accept_space_ownership_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_propose_space_owner(None, None, ACCOUNT2));
+        assert_ok!(_accept_space_ownership(RuntimeOrigin::signed(ACCOUNT2), None));
+
+        let space = Spaces::space_by_id(SPACE1).unwrap();
+        assert_eq!(space.owner, ACCOUNT1);
+        assert_eq!(space.co_owners.into_inner(), vec![ACCOUNT2]);
+        assert_eq!(Spaces::pending_space_owner(SPACE1, ACCOUNT2), None);
+        assert_eq!(Spaces::get_space_ids_by_owner(ACCOUNT2), vec![SPACE1]);
+        assert_eq!(Spaces::get_spaces_by_ids(vec![SPACE1])[0].co_owner_ids, vec![ACCOUNT2]);
+        System::assert_last_event(
+            pallet_spaces::Event::SpaceOwnerAdded { account: ACCOUNT2, space_id: SPACE1 }.into(),
+        );
+    });
+}
+
+#[test]
+fn accept_space_ownership_should_fail_when_not_proposed() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_noop!(
+            _accept_space_ownership(RuntimeOrigin::signed(ACCOUNT2), None),
+            SpacesError::<Test>::NoPendingSpaceOwnerProposal
+        );
+    });
+}
+
+#[test]
+fn co_owner_should_have_the_same_rights_as_owner() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        _add_account2_as_space_co_owner();
+
+        assert_ok!(_update_space(
+            Some(RuntimeOrigin::signed(ACCOUNT2)),
+            None,
+            Some(update_for_space_content(updated_space_content()))
+        ));
+        assert_ok!(_set_space_archived(Some(RuntimeOrigin::signed(ACCOUNT2)), None, true));
+
+        // A co-owner can propose new co-owners too.
+        assert_ok!(_propose_space_owner(Some(RuntimeOrigin::signed(ACCOUNT2)), None, ACCOUNT3));
+        assert_eq!(Spaces::pending_space_owner(SPACE1, ACCOUNT3), Some(ACCOUNT2));
+    });
+}
+
+#[test]
+fn remove_space_owner_should_work() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        _add_account2_as_space_co_owner();
+
+        assert_ok!(_remove_space_owner(None, None, ACCOUNT2));
+
+        assert!(Spaces::space_by_id(SPACE1).unwrap().co_owners.is_empty());
+        assert!(Spaces::get_space_ids_by_owner(ACCOUNT2).is_empty());
+        System::assert_last_event(
+            pallet_spaces::Event::SpaceOwnerRemoved {
+                account: ACCOUNT1,
+                space_id: SPACE1,
+                owner: ACCOUNT2,
+            }
+            .into(),
+        );
+        assert_noop!(
+            _update_space(
+                Some(RuntimeOrigin::signed(ACCOUNT2)),
+                None,
+                Some(update_for_space_content(updated_space_content()))
+            ),
+            SpacesError::<Test>::NoPermissionToUpdateSpace
+        );
+    });
+}
+
+#[test]
+fn remove_space_owner_should_allow_co_owner_to_leave() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        _add_account2_as_space_co_owner();
+
+        assert_ok!(_remove_space_owner(Some(RuntimeOrigin::signed(ACCOUNT2)), None, ACCOUNT2));
+        assert!(Spaces::space_by_id(SPACE1).unwrap().co_owners.is_empty());
+    });
+}
+
+#[test]
+fn remove_space_owner_should_cancel_pending_proposal() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        assert_ok!(_propose_space_owner(None, None, ACCOUNT2));
+        assert_ok!(_remove_space_owner(None, None, ACCOUNT2));
+
+        assert_eq!(Spaces::pending_space_owner(SPACE1, ACCOUNT2), None);
+        System::assert_last_event(
+            pallet_spaces::Event::SpaceOwnerProposalCancelled {
+                account: ACCOUNT1,
+                space_id: SPACE1,
+                new_owner: ACCOUNT2,
+            }
+            .into(),
+        );
+        assert_noop!(
+            _accept_space_ownership(RuntimeOrigin::signed(ACCOUNT2), None),
+            SpacesError::<Test>::NoPendingSpaceOwnerProposal
+        );
+    });
+}
+
+#[test]
+fn remove_space_owner_should_fail_for_the_owner_or_not_a_co_owner() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        _add_account2_as_space_co_owner();
+
+        assert_noop!(
+            _remove_space_owner(Some(RuntimeOrigin::signed(ACCOUNT2)), None, ACCOUNT1),
+            SpacesError::<Test>::CannotRemoveSpaceOwner
+        );
+        assert_noop!(
+            _remove_space_owner(None, None, ACCOUNT3),
+            SpacesError::<Test>::NotASpaceCoOwner
+        );
+        assert_noop!(
+            _remove_space_owner(Some(RuntimeOrigin::signed(ACCOUNT3)), None, ACCOUNT2),
+            SpacesError::<Test>::NotASpaceOwner
+        );
+    });
+}
+
+#[test]
+fn delete_space_should_remove_space_from_co_owners() {
+    ExtBuilder::build_with_space().execute_with(|| {
+        _add_account2_as_space_co_owner();
+        assert_ok!(_propose_space_owner(None, None, ACCOUNT3));
+
+        assert_ok!(_delete_space(None, None));
+
+        assert!(Spaces::get_space_ids_by_owner(ACCOUNT2).is_empty());
+        assert_eq!(Spaces::pending_space_owner(SPACE1, ACCOUNT3), None);
+    });
+}
+
 // TODO: refactor or remove. Deprecated tests
 // Find public space ids tests
 // --------------------------------------------------------------------------------------------
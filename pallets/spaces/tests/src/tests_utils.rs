@@ -18,11 +18,11 @@ use pallet_permissions::{
     default_permissions::DefaultSpacePermissions, SpacePermission as SP, SpacePermission,
     SpacePermissions,
 };
-use pallet_posts::PostExtension;
+use pallet_posts::{Comment, CommentsPolicy, CommentsPolicySetting, PostExtension};
 use pallet_spaces::types::SpaceUpdate;
 use subsocial_support::{
     mock_functions::valid_content_ipfs,
-    traits::{IsAccountBlocked, IsContentBlocked, IsPostBlocked, IsSpaceBlocked},
+    traits::{IsAccountBlocked, IsContentBlocked, IsPostBlocked, IsSpaceBlocked, SpaceSetting},
     Content, PostId, SpaceId, User,
 };
 
@@ -88,6 +88,15 @@ impl ExtBuilder {
         ext
     }
 
+    /// Custom ext configuration with SpaceId 1 and its subspace SpaceId 2 owned by ACCOUNT2
+    pub fn build_with_subspace_of_account2() -> TestExternalities {
+        let mut ext = Self::build_with_a_few_roles_granted_to_account2(vec![SP::CreateSubspaces]);
+        ext.execute_with(|| {
+            assert_ok!(_create_subspace(Some(RuntimeOrigin::signed(ACCOUNT2)), None, None));
+        });
+        ext
+    }
+
     /// Custom ext configuration with a space and override the space permissions
     pub fn build_with_space_and_custom_permissions(
         permissions: SpacePermissions,
@@ -106,6 +115,7 @@ pub(crate) const ACCOUNT3: AccountId = 3;
 
 pub(crate) const SPACE1: SpaceId = 1001;
 pub(crate) const SPACE2: SpaceId = 1002;
+pub(crate) const SPACE3: SpaceId = 1003;
 
 type RoleId = u64;
 
@@ -280,6 +290,145 @@ pub(crate) fn _update_space(
     )
 }
 
+pub(crate) fn _create_default_subspace() -> DispatchResult {
+    _create_subspace(None, None, None)
+}
+
+pub(crate) fn _create_subspace(
+    origin: Option<RuntimeOrigin>,
+    parent_id: Option<SpaceId>,
+    permissions: Option<Option<SpacePermissions>>,
+) -> DispatchResult {
+    Spaces::create_subspace(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        parent_id.unwrap_or(SPACE1),
+        space_content_ipfs(),
+        permissions.unwrap_or_default(),
+    )
+}
+
+pub(crate) fn _move_space(
+    origin: Option<RuntimeOrigin>,
+    space_id: Option<SpaceId>,
+    new_parent_id: Option<Option<SpaceId>>,
+) -> DispatchResult {
+    Spaces::move_space(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        space_id.unwrap_or(SPACE2),
+        new_parent_id.unwrap_or(Some(SPACE1)),
+    )
+}
+
+pub(crate) fn space_handle() -> Vec<u8> {
+    b"Space_Handle".to_vec()
+}
+
+pub(crate) fn _set_default_space_handle() -> DispatchResult {
+    _set_space_handle(None, None, None)
+}
+
+pub(crate) fn _set_space_handle(
+    origin: Option<RuntimeOrigin>,
+    space_id: Option<SpaceId>,
+    handle_opt: Option<Option<Vec<u8>>>,
+) -> DispatchResult {
+    Spaces::set_space_handle(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        space_id.unwrap_or(SPACE1),
+        handle_opt.unwrap_or_else(|| Some(space_handle())),
+    )
+}
+
+pub(crate) fn _delete_space(
+    origin: Option<RuntimeOrigin>,
+    space_id: Option<SpaceId>,
+) -> DispatchResult {
+    Spaces::delete_space(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        space_id.unwrap_or(SPACE1),
+    )
+}
+
+pub(crate) fn _continue_space_deletion(space_id: Option<SpaceId>, limit: u32) -> DispatchResult {
+    Spaces::continue_space_deletion(
+        RuntimeOrigin::signed(ACCOUNT3),
+        space_id.unwrap_or(SPACE1),
+        limit,
+    )
+    .map(|_| ())
+    .map_err(|err| err.error)
+}
+
+pub(crate) fn _update_space_setting(
+    origin: Option<RuntimeOrigin>,
+    space_id: Option<SpaceId>,
+    key: Vec<u8>,
+    value_opt: Option<Vec<u8>>,
+) -> DispatchResult {
+    Spaces::update_space_setting(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        space_id.unwrap_or(SPACE1),
+        key,
+        value_opt,
+    )
+}
+
+pub(crate) fn _set_space_archived(
+    origin: Option<RuntimeOrigin>,
+    space_id: Option<SpaceId>,
+    archived: bool,
+) -> DispatchResult {
+    Spaces::set_space_archived(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        space_id.unwrap_or(SPACE1),
+        archived,
+    )
+}
+
+pub(crate) fn _propose_space_owner(
+    origin: Option<RuntimeOrigin>,
+    space_id: Option<SpaceId>,
+    new_owner: AccountId,
+) -> DispatchResult {
+    Spaces::propose_space_owner(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        space_id.unwrap_or(SPACE1),
+        new_owner,
+    )
+}
+
+pub(crate) fn _accept_space_ownership(
+    origin: RuntimeOrigin,
+    space_id: Option<SpaceId>,
+) -> DispatchResult {
+    Spaces::accept_space_ownership(origin, space_id.unwrap_or(SPACE1))
+}
+
+pub(crate) fn _remove_space_owner(
+    origin: Option<RuntimeOrigin>,
+    space_id: Option<SpaceId>,
+    owner: AccountId,
+) -> DispatchResult {
+    Spaces::remove_space_owner(
+        origin.unwrap_or_else(|| RuntimeOrigin::signed(ACCOUNT1)),
+        space_id.unwrap_or(SPACE1),
+        owner,
+    )
+}
+
+/// Make `ACCOUNT2` a co-owner of the default space.
+pub(crate) fn _add_account2_as_space_co_owner() {
+    assert_ok!(_propose_space_owner(None, None, ACCOUNT2));
+    assert_ok!(_accept_space_ownership(RuntimeOrigin::signed(ACCOUNT2), None));
+}
+
+pub(crate) fn _set_comments_policy(
+    origin: Option<RuntimeOrigin>,
+    policy: CommentsPolicy,
+) -> DispatchResult {
+    _update_space_setting(origin, None, CommentsPolicySetting::KEY.to_vec(), Some(policy.encode()))
+}
+
 ///////////// Post Utils
 
 pub(crate) fn post_content_ipfs() -> Content {
@@ -308,6 +457,10 @@ pub(crate) fn _create_post(
     )
 }
 
+pub(crate) fn extension_comment(parent_id: Option<PostId>, root_post_id: PostId) -> PostExtension {
+    PostExtension::Comment(Comment { parent_id, root_post_id })
+}
+
 //// Space follows utils
 
 pub(crate) fn _default_follow_space() -> DispatchResult {
@@ -13,7 +13,7 @@ use codec::{Decode, Encode};
 use scale_info::TypeInfo;
 use serde::{Serialize, Deserialize};
 
-use frame_support::pallet_prelude::*;
+use frame_support::{pallet_prelude::*, traits::ReservableCurrency};
 use frame_system::pallet_prelude::BlockNumberFor;
 use sp_std::{collections::btree_set::BTreeSet, vec, vec::Vec};
 
@@ -48,6 +48,49 @@ where
     }
 }
 
+/// An amount reserved from a depositor for storing an item, such as a space or a post.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct StorageDeposit<AccountId, Balance> {
+    pub depositor: AccountId,
+    pub deposit: Balance,
+}
+
+impl<AccountId, Balance: Copy + Default> StorageDeposit<AccountId, Balance> {
+    /// Reserve `deposit` from the balance of `depositor`.
+    pub fn reserve<C>(depositor: AccountId, deposit: Balance) -> Result<Self, DispatchError>
+    where
+        C: ReservableCurrency<AccountId, Balance = Balance>,
+    {
+        C::reserve(&depositor, deposit)?;
+        Ok(Self { depositor, deposit })
+    }
+
+    /// An empty deposit of an item that is stored for free, e.g. an item created by root
+    /// or before storage deposits were introduced.
+    pub fn free(depositor: AccountId) -> Self {
+        Self { depositor, deposit: Default::default() }
+    }
+
+    /// Return the deposit to the depositor.
+    pub fn release<C>(&self)
+    where
+        C: ReservableCurrency<AccountId, Balance = Balance>,
+    {
+        C::unreserve(&self.depositor, self.deposit);
+    }
+
+    /// Move the deposit to a new depositor, when the item changes its owner.
+    pub fn transfer<C>(&mut self, new_depositor: AccountId) -> DispatchResult
+    where
+        C: ReservableCurrency<AccountId, Balance = Balance>,
+    {
+        C::reserve(&new_depositor, self.deposit)?;
+        C::unreserve(&self.depositor, self.deposit);
+        self.depositor = new_depositor;
+        Ok(())
+    }
+}
+
 #[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum Content {
@@ -166,7 +209,18 @@ impl From<ContentError> for DispatchError {
 /// Minimal set of fields from Space struct that are required by roles pallet.
 pub struct SpacePermissionsInfo<AccountId, SpacePermissions> {
     pub owner: AccountId,
+    /// Accounts that own a space together with its owner.
+    pub co_owners: Vec<AccountId>,
     pub permissions: Option<SpacePermissions>,
+    /// Whether a space is archived and does not accept new roles and role grants.
+    pub archived: bool,
+}
+
+impl<AccountId: PartialEq, SpacePermissions> SpacePermissionsInfo<AccountId, SpacePermissions> {
+    /// Check whether an account is either the owner or a co-owner of a space.
+    pub fn is_owner(&self, account: &AccountId) -> bool {
+        self.owner == *account || self.co_owners.contains(account)
+    }
 }
 
 pub fn ensure_content_is_valid(content: Content) -> DispatchResult {
@@ -5,10 +5,13 @@
 // Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
 
 pub use common::{
-    CreatorStakingProvider, DomainsProvider, PostFollowsProvider, PostsProvider, ProfileManager,
-    SpaceFollowsProvider, SpacePermissionsProvider, SpacesProvider,
+    BackerStakesProvider, CreatorStakingProvider, DomainsProvider, PostDeletionHandler,
+    PostFollowsProvider, PostVisibilityHandler, PostsProvider, ProfileManager,
+    SpaceDeletionHandler, SpaceFollowsProvider, SpacePermissionsProvider, SpacesProvider,
 };
 pub use moderation::{IsAccountBlocked, IsContentBlocked, IsPostBlocked, IsSpaceBlocked};
+pub use settings::{SpaceSetting, SpaceSettingInfo, SpaceSettingsRegistry};
 
 mod common;
 mod moderation;
+mod settings;
@@ -6,7 +6,10 @@
 // Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
 
 
-use frame_support::dispatch::{DispatchError, DispatchResult};
+use frame_support::{
+    dispatch::{DispatchError, DispatchResult},
+    weights::Weight,
+};
 
 use crate::{Content, PostId, SpaceId};
 
@@ -20,6 +23,8 @@ pub trait SpaceFollowsProvider {
     type AccountId;
 
     fn is_space_follower(account: Self::AccountId, space_id: SpaceId) -> bool;
+
+    fn space_followers_count(space_id: SpaceId) -> u32;
 }
 
 pub trait PostFollowsProvider {
@@ -32,11 +37,93 @@ pub trait ProfileManager<AccountId> {
     fn unlink_space_from_profile(account: &AccountId, space_id: SpaceId);
 }
 
+impl<AccountId> ProfileManager<AccountId> for () {
+    fn unlink_space_from_profile(_account: &AccountId, _space_id: SpaceId) {}
+}
+
+/// Cleans up the storage of a pallet after a space has been deleted.
+pub trait SpaceDeletionHandler {
+    /// Remove at most `limit` storage items related to a deleted space and return
+    /// the number of removed items. Returning less than `limit` means that
+    /// there is nothing left to remove.
+    fn cleanup_deleted_space(space_id: SpaceId, limit: u32) -> u32;
+
+    /// The worst-case weight of `cleanup_deleted_space` with a given `limit`.
+    fn cleanup_deleted_space_weight(limit: u32) -> Weight;
+}
+
+/// Handlers are run in order, each one only after the previous ones have nothing left to remove.
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+impl SpaceDeletionHandler for Tuple {
+    #[allow(unused_mut, unused_variables)]
+    fn cleanup_deleted_space(space_id: SpaceId, limit: u32) -> u32 {
+        let mut removed = 0u32;
+        for_tuples!( #(
+            if removed < limit {
+                removed = removed
+                    .saturating_add(Tuple::cleanup_deleted_space(space_id, limit - removed));
+            }
+        )* );
+        removed
+    }
+
+    /// Every handler may be run, but all of them together remove at most `limit` items.
+    #[allow(unused_mut)]
+    fn cleanup_deleted_space_weight(limit: u32) -> Weight {
+        let mut base_weight = Weight::zero();
+        let mut item_weight = Weight::zero();
+        for_tuples!( #(
+            let handler_base_weight = Tuple::cleanup_deleted_space_weight(0);
+            base_weight.saturating_accrue(handler_base_weight);
+            item_weight = item_weight.max(
+                Tuple::cleanup_deleted_space_weight(1).saturating_sub(handler_base_weight),
+            );
+        )* );
+        base_weight.saturating_add(item_weight.saturating_mul(limit as u64))
+    }
+}
+
+/// Cleans up the storage of a pallet after a post has been deleted.
+pub trait PostDeletionHandler {
+    /// Remove at most `limit` storage items related to a deleted post and return
+    /// the number of removed items. Returning less than `limit` means that
+    /// there is nothing left to remove.
+    fn cleanup_deleted_post(post_id: PostId, limit: u32) -> u32;
+}
+
+/// Handlers are run in order, each one only after the previous ones have nothing left to remove.
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+impl PostDeletionHandler for Tuple {
+    #[allow(unused_mut, unused_variables)]
+    fn cleanup_deleted_post(post_id: PostId, limit: u32) -> u32 {
+        let mut removed = 0u32;
+        for_tuples!( #(
+            if removed < limit {
+                removed = removed
+                    .saturating_add(Tuple::cleanup_deleted_post(post_id, limit - removed));
+            }
+        )* );
+        removed
+    }
+}
+
+/// Updates the storage of a pallet after a hidden post has been made visible.
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+pub trait PostVisibilityHandler {
+    fn on_post_unhidden(post_id: PostId);
+}
+
 pub trait SpacesProvider<AccountId, SpaceId> {
     
     fn get_space_owner(space_id: SpaceId) -> Result<AccountId, DispatchError>;
     
-    fn do_update_space_owner(space_id: SpaceId, new_owner: AccountId) -> DispatchResult;
+    /// Give the seat of `old_owner`, who is either the owner or a co-owner of a space,
+    /// to `new_owner`.
+    fn do_update_space_owner(
+        space_id: SpaceId,
+        old_owner: &AccountId,
+        new_owner: AccountId,
+    ) -> DispatchResult;
 
     fn create_space(owner: &AccountId, content: Content) -> Result<SpaceId, DispatchError>;
 }
@@ -55,6 +142,18 @@ impl<AccountId> CreatorStakingProvider<AccountId> for () {
     }
 }
 
+/// Provides the amounts that backers currently have staked on creators.
+pub trait BackerStakesProvider<AccountId, Balance> {
+    /// Get the amount that `backer` currently has staked on a given creator.
+    fn get_backer_stake(backer: &AccountId, creator_id: SpaceId) -> Balance;
+}
+
+impl<AccountId, Balance: Default> BackerStakesProvider<AccountId, Balance> for () {
+    fn get_backer_stake(_backer: &AccountId, _creator_id: SpaceId) -> Balance {
+        Default::default()
+    }
+}
+
 pub trait DomainsProvider<AccountId> {
     type MaxDomainLength: frame_support::traits::Get<u32>;
     
@@ -68,6 +167,27 @@ pub trait DomainsProvider<AccountId> {
     fn register_domain(owner: &AccountId, domain: &[u8]) -> Result<sp_std::vec::Vec<u8>, DispatchError>;
 }
 
+impl<AccountId> DomainsProvider<AccountId> for () {
+    type MaxDomainLength = frame_support::traits::ConstU32<0>;
+
+    fn get_domain_owner(_domain: &[u8]) -> Result<AccountId, DispatchError> {
+        Err(DispatchError::Other("DomainsNotSupported"))
+    }
+
+    fn ensure_domain_owner(_domain: &[u8], _account: &AccountId) -> DispatchResult {
+        Err(DispatchError::Other("DomainsNotSupported"))
+    }
+
+    fn do_update_domain_owner(_domain: &[u8], _new_owner: &AccountId) -> DispatchResult {
+        Err(DispatchError::Other("DomainsNotSupported"))
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn register_domain(_owner: &AccountId, _domain: &[u8]) -> Result<sp_std::vec::Vec<u8>, DispatchError> {
+        Err(DispatchError::Other("DomainsNotSupported"))
+    }
+}
+
 pub trait PostsProvider<AccountId> {
     fn get_post_owner(post_id: PostId) -> Result<AccountId, DispatchError>;
     
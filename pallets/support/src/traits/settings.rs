@@ -0,0 +1,91 @@
+// Copyright (C) DAPPFORCE PTE. LTD.
+// SPDX-License-Identifier: GPL-3.0-or-later WITH Classpath-exception-2.0.
+//
+// Full notice is available at https://github.com/dappforce/subsocial-parachain/blob/main/COPYRIGHT
+// Full license is available at https://github.com/dappforce/subsocial-parachain/blob/main/LICENSE
+
+use codec::{Decode, DecodeAll, Encode};
+use frame_support::RuntimeDebug;
+use scale_info::TypeInfo;
+use sp_std::{vec, vec::Vec};
+
+/// A typed setting of a space, that a pallet registers via `SpaceSettingsRegistry`.
+pub trait SpaceSetting {
+    /// A unique key of the setting, prefixed with the name of the pallet, e.g. `b"posts:..."`.
+    const KEY: &'static [u8];
+
+    /// The version of the value encoding. Values stored with another version are ignored,
+    /// and the default value is used instead, so bump it only when the encoding changes.
+    const VERSION: u16;
+
+    type Value: Encode + Decode + Default;
+
+    /// Check whether a value is acceptable for this setting.
+    fn is_valid(_value: &Self::Value) -> bool {
+        true
+    }
+
+    /// Decode a value stored with a given version, if the version is the current one.
+    fn decode_value(version: u16, mut encoded: &[u8]) -> Option<Self::Value> {
+        if version != Self::VERSION {
+            return None
+        }
+        Self::Value::decode_all(&mut encoded).ok()
+    }
+
+    /// Check an encoded value of this setting, or return `None` if `key` is not its key.
+    fn check_encoded_value(key: &[u8], value: &[u8]) -> Option<bool> {
+        if key != Self::KEY {
+            return None
+        }
+        Some(Self::decode_value(Self::VERSION, value).map_or(false, |v| Self::is_valid(&v)))
+    }
+}
+
+/// Describes a registered space setting.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct SpaceSettingInfo {
+    pub key: Vec<u8>,
+    pub version: u16,
+    pub default_value: Vec<u8>,
+}
+
+impl SpaceSettingInfo {
+    pub fn of<S: SpaceSetting>() -> Self {
+        Self {
+            key: S::KEY.to_vec(),
+            version: S::VERSION,
+            default_value: S::Value::default().encode(),
+        }
+    }
+}
+
+/// Space settings registered by a pallet, so that they can be updated by their keys.
+pub trait SpaceSettingsRegistry {
+    /// Get all settings registered by this pallet.
+    fn registered_settings() -> Vec<SpaceSettingInfo>;
+
+    /// Check whether an encoded value is valid for a setting with a given key,
+    /// or return `None` if there is no such setting registered by this pallet.
+    fn check_setting_value(key: &[u8], value: &[u8]) -> Option<bool>;
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+impl SpaceSettingsRegistry for Tuple {
+    #[allow(unused_mut)]
+    fn registered_settings() -> Vec<SpaceSettingInfo> {
+        let mut settings = vec![];
+        for_tuples!( #( settings.extend(Tuple::registered_settings()); )* );
+        settings
+    }
+
+    #[allow(unused_variables)]
+    fn check_setting_value(key: &[u8], value: &[u8]) -> Option<bool> {
+        for_tuples!( #(
+            if let Some(is_valid) = Tuple::check_setting_value(key, value) {
+                return Some(is_valid)
+            }
+        )* );
+        None
+    }
+}
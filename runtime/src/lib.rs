@@ -47,6 +47,8 @@ use xcm_config::{XcmConfig, XcmOriginToTransactDispatchOrigin};
 
 use pallet_creator_staking::{CreatorId, EraIndex};
 use pallet_domains::types::PricesConfigVec;
+use pallet_posts::rpc::{FlatPost, FlatPostRevision, PostsFilter};
+use pallet_spaces::rpc::{FlatSpace, FlatSpaceSetting};
 
 use subsocial_support::{Content, PostId, SpaceId};
 
@@ -81,6 +83,9 @@ pub type Hash = sp_core::H256;
 /// An index to a block.
 pub type BlockNumber = u32;
 
+/// A timestamp: milliseconds since the unix epoch.
+pub type Moment = u64;
+
 /// The address format for describing accounts.
 pub type Address = MultiAddress<AccountId, ()>;
 
@@ -126,6 +131,10 @@ pub type Executive = frame_executive::Executive<
 		pallet_multisig::migrations::v1::MigrateToV1<Runtime>,
 		pallet_xcm::migration::v1::MigrateToV1<Runtime>,
 		pallet_balances::migration::MigrateToTrackInactive<Runtime, xcm_config::CheckAccount>,
+		pallet_spaces::migration::v5::MigrateToV5<Runtime>,
+		pallet_posts::migration::v2::MigrateToV2<Runtime>,
+		pallet_reactions::migration::v1::MigrateToV1<Runtime>,
+		pallet_roles::migration::v1::MigrateToV1<Runtime>,
 	),
 >;
 
@@ -183,10 +192,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("subsocial-parachain"),
 	impl_name: create_runtime_str!("subsocial-parachain"),
 	authoring_version: 1,
-	spec_version: 45,
+	spec_version: 46,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 10,
+	transaction_version: 11,
 	state_version: 0,
 };
 
@@ -277,6 +286,14 @@ parameter_types! {
 
 // Configure FRAME pallets to include in runtime.
 
+/// Whether the storage of spaces, posts, reactions or roles is being migrated across blocks.
+fn is_social_storage_migrating() -> bool {
+	pallet_spaces::migration::v5::is_migration_in_progress::<Runtime>()
+		|| pallet_posts::migration::v2::is_migration_in_progress::<Runtime>()
+		|| pallet_reactions::migration::v1::is_migration_in_progress::<Runtime>()
+		|| pallet_roles::migration::v1::is_migration_in_progress::<Runtime>()
+}
+
 pub struct BaseFilter;
 impl Contains<RuntimeCall> for BaseFilter {
 	fn contains(c: &RuntimeCall) -> bool {
@@ -293,9 +310,27 @@ impl Contains<RuntimeCall> for BaseFilter {
 		let is_remove_treasury_approval =
 			matches!(c, RuntimeCall::Treasury(pallet_treasury::Call::remove_approval { .. }));
 
+		// Calls of pallets that use spaces, posts, reactions or roles are paused
+		// until these are migrated.
+		let is_social_call = matches!(
+			c,
+			RuntimeCall::Spaces(..)
+			| RuntimeCall::Posts(..)
+			| RuntimeCall::Reactions(..)
+			| RuntimeCall::Roles(..)
+			| RuntimeCall::SpaceFollows(..)
+			| RuntimeCall::PostFollows(..)
+			| RuntimeCall::Ownership(..)
+			| RuntimeCall::Profiles(..)
+			| RuntimeCall::Moderation(..)
+			| RuntimeCall::CreatorStaking(..)
+			| RuntimeCall::ResourceDiscussions(..)
+		);
+
 		match *c {
 			RuntimeCall::Balances(..) if is_set_balance || is_force_transfer => false,
 			RuntimeCall::Treasury(..) if !is_treasury_spend && !is_remove_treasury_approval => false,
+			_ if is_social_call && is_social_storage_migrating() => false,
 			_ => true,
 		}
 	}
@@ -350,8 +385,7 @@ impl frame_system::Config for Runtime {
 }
 
 impl pallet_timestamp::Config for Runtime {
-	/// A timestamp: milliseconds since the unix epoch.
-	type Moment = u64;
+	type Moment = Moment;
 	type OnTimestampSet = (Aura, CreatorStaking);
 	type MinimumPeriod = ConstU64<{ SLOT_DURATION / 2 }>;
 	type WeightInfo = ();
@@ -598,6 +632,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			c,
 			RuntimeCall::AccountFollows(..)
 			| RuntimeCall::Domains(..)
+			| RuntimeCall::Moderation(..)
 			| RuntimeCall::PostFollows(..)
 			| RuntimeCall::Posts(..)
 			| RuntimeCall::Profiles(..)
@@ -723,17 +758,35 @@ impl pallet_post_follows::Config for Runtime {
 
 parameter_types! {
   pub const MaxCommentDepth: u32 = 10;
+  pub const PostDeposit: Balance = 100 * MILLIUNIT;
+  pub const MaxPinnedPostsPerSpace: u32 = 10;
+  pub const MaxPostRevisions: u32 = 20;
+  pub const MaxScheduledPostsPerBlock: u32 = 20;
+  pub const MaxPollOptions: u32 = 10;
 }
 
 impl pallet_posts::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxCommentDepth = MaxCommentDepth;
-	type IsPostBlocked = ()/*Moderation*/;
+	type PostDeposit = PostDeposit;
+	type MaxPinnedPostsPerSpace = MaxPinnedPostsPerSpace;
+	type MaxPostRevisions = MaxPostRevisions;
+	type MaxScheduledPostsPerBlock = MaxScheduledPostsPerBlock;
+	type MaxPollOptions = MaxPollOptions;
+	type BackerStakesProvider = CreatorStaking;
+	type IsPostBlocked = Moderation;
+	type PostDeletionHandler = (Reactions, PostFollows);
+	type PostVisibilityHandler = Moderation;
 	type WeightInfo = pallet_posts::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const ReactionDeposit: Balance = 10 * MILLIUNIT;
+}
+
 impl pallet_reactions::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type ReactionDeposit = ReactionDeposit;
 	type WeightInfo = pallet_reactions::weights::SubstrateWeight<Runtime>;
 }
 
@@ -742,38 +795,76 @@ impl pallet_profiles::Config for Runtime {
 	type SpacePermissionsProvider = Spaces;
 	type SpacesProvider = Spaces;
 	type WeightInfo = pallet_profiles::weights::SubstrateWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ProfilesBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct ProfilesBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_profiles::BenchmarkHelper<AccountId> for ProfilesBenchmarkHelper {
+	fn fund_account(account: &AccountId) {
+		Balances::make_free_balance_be(account, 1_000 * UNIT);
+	}
 }
 
 parameter_types! {
   pub const MaxUsersToProcessPerDeleteRole: u16 = 40;
+  pub const RoleDeposit: Balance = 100 * MILLIUNIT;
 }
 
 impl pallet_roles::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type MaxUsersToProcessPerDeleteRole = MaxUsersToProcessPerDeleteRole;
+	type Currency = Balances;
+	type RoleDeposit = RoleDeposit;
 	type SpacePermissionsProvider = Spaces;
 	type SpaceFollows = SpaceFollows;
-	type IsAccountBlocked = ()/*Moderation*/;
-	type IsContentBlocked = ()/*Moderation*/;
+	type IsAccountBlocked = Moderation;
+	type IsContentBlocked = Moderation;
 	type WeightInfo = pallet_roles::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const FollowDeposit: Balance = 10 * MILLIUNIT;
+	pub const InvitationDeposit: Balance = 10 * MILLIUNIT;
+}
+
 impl pallet_space_follows::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
+	type FollowDeposit = FollowDeposit;
+	type InvitationDeposit = InvitationDeposit;
 	type WeightInfo = pallet_space_follows::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
 	pub const MaxSpacesPerAccount: u32 = 4096;
+	pub const MaxSubspaceDepth: u32 = 10;
+	pub const MinHandleLen: u32 = 5;
+	pub const MaxHandleLen: u32 = 50;
+	pub const SpaceDeposit: Balance = 1 * UNIT;
+	pub const MaxSpaceCoOwners: u32 = 10;
 }
 
 impl pallet_spaces::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Roles = Roles;
 	type SpaceFollows = SpaceFollows;
-	type IsAccountBlocked = ()/*Moderation*/;
-	type IsContentBlocked = ()/*Moderation*/;
+	type IsAccountBlocked = Moderation;
+	type IsContentBlocked = Moderation;
 	type MaxSpacesPerAccount = MaxSpacesPerAccount;
+	type MaxSubspaceDepth = MaxSubspaceDepth;
+	type DomainsProvider = Domains;
+	type MinHandleLen = MinHandleLen;
+	type MaxHandleLen = MaxHandleLen;
+	type ProfileManager = Profiles;
+	type CreatorStakingProvider = CreatorStaking;
+	type SpaceDeletionHandler = (Posts, Roles, SpaceFollows, Moderation, Ownership);
+	type SpaceSettingsRegistry = (Posts, SpaceFollows);
+	type Currency = Balances;
+	type SpaceDeposit = SpaceDeposit;
+	type MaxSpaceCoOwners = MaxSpaceCoOwners;
 	type WeightInfo = pallet_spaces::weights::SubstrateWeight<Runtime>;
 }
 
@@ -869,6 +960,31 @@ impl pallet_resource_discussions::Config for Runtime {
 	type WeightInfo = pallet_resource_discussions::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const ReportDeposit: Balance = 1 * UNIT;
+	pub const ReportingAgeDeposit: Balance = 100 * MILLIUNIT;
+	pub const MaxOpenReportsPerSpace: u32 = 1_000;
+	pub const MaxSubscribedListsPerSpace: u32 = 4;
+	pub const AppealDeposit: Balance = 1 * UNIT;
+	pub const MaxOpenAppealsPerSpace: u32 = 1_000;
+	pub const MaxModerationHistoryLength: u32 = 32;
+}
+
+impl pallet_moderation::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type GlobalModerationOrigin = EnsureRoot<AccountId>;
+	type Currency = Balances;
+	type ReportDeposit = ReportDeposit;
+	type ReportingAgeDeposit = ReportingAgeDeposit;
+	type MaxOpenReportsPerSpace = MaxOpenReportsPerSpace;
+	type MaxSubscribedListsPerSpace = MaxSubscribedListsPerSpace;
+	type AppealDeposit = AppealDeposit;
+	type MaxOpenAppealsPerSpace = MaxOpenAppealsPerSpace;
+	type MaxModerationHistoryLength = MaxModerationHistoryLength;
+	// FIXME: placeholder weights, regenerate them with benchmarks before this runtime is released.
+	type WeightInfo = pallet_moderation::weights::SubstrateWeight<Runtime>;
+}
+
 // Create the runtime by composing the FRAME pallets that were previously configured.
 construct_runtime!(
 	pub enum Runtime {
@@ -920,6 +1036,7 @@ construct_runtime!(
 		PostFollows: pallet_post_follows = 77,
 		Posts: pallet_posts = 78,
 		Reactions: pallet_reactions = 79,
+		Moderation: pallet_moderation = 80,
 
 		// Temporary
 		Sudo: pallet_sudo = 255,
@@ -951,6 +1068,7 @@ mod benches {
 		[pallet_post_follows, PostFollows]
 		[pallet_posts, Posts]
 		[pallet_resource_discussions, ResourceDiscussions]
+		[pallet_moderation, Moderation]
 		[pallet_free_proxy, FreeProxy]
 	);
 }
@@ -1140,7 +1258,7 @@ impl_runtime_apis! {
 		}
 	}
 
-	impl pallet_posts_rpc_runtime_api::PostsApi<Block, AccountId> for Runtime {
+	impl pallet_posts_rpc_runtime_api::PostsApi<Block, AccountId, BlockNumber, Balance> for Runtime {
 		fn can_create_post(
 			account: AccountId,
 			space_id: SpaceId,
@@ -1157,6 +1275,94 @@ impl_runtime_apis! {
 		) -> DispatchResult {
 			Posts::can_create_comment(account, root_post_id, parent_id_opt, content_opt)
 		}
+
+		fn get_pinned_post_ids(space_id: SpaceId) -> Vec<PostId> {
+			Posts::get_pinned_post_ids(space_id)
+		}
+
+		fn get_post_revisions(post_id: PostId) -> Vec<FlatPostRevision<AccountId, BlockNumber>> {
+			Posts::get_post_revisions(post_id)
+		}
+
+		fn get_top_tipped_posts(space_id: SpaceId, limit: u32) -> Vec<(PostId, Balance)> {
+			Posts::get_top_tipped_posts(space_id, limit)
+		}
+
+		fn can_access_post(account: AccountId, post_id: PostId) -> bool {
+			Posts::can_access_post(account, post_id)
+		}
+
+		fn get_posts_by_ids(post_ids: Vec<PostId>) -> Vec<FlatPost<AccountId, BlockNumber>> {
+			Posts::get_posts_by_ids(post_ids)
+		}
+
+		fn get_posts_by_space(
+			space_id: SpaceId,
+			filter: PostsFilter,
+			offset: u32,
+			limit: u32,
+		) -> Vec<FlatPost<AccountId, BlockNumber>> {
+			Posts::get_posts_by_space(space_id, filter, offset, limit)
+		}
+
+		fn get_reply_tree(post_id: PostId) -> Vec<FlatPost<AccountId, BlockNumber>> {
+			Posts::get_reply_tree(post_id)
+		}
+
+		fn get_shared_posts(
+			original_post_id: PostId,
+			offset: u32,
+			limit: u32,
+		) -> Vec<FlatPost<AccountId, BlockNumber>> {
+			Posts::get_shared_posts(original_post_id, offset, limit)
+		}
+	}
+
+	impl pallet_moderation_rpc_runtime_api::ModerationApi<Block, AccountId, BlockNumber, Moment, Balance>
+		for Runtime
+	{
+		fn get_open_reports(
+			space_id: SpaceId,
+		) -> Vec<pallet_moderation::Report<AccountId, BlockNumber, Moment, Balance>> {
+			Moderation::get_open_reports(space_id)
+		}
+
+		fn get_moderation_history(
+			entity: pallet_moderation::EntityId<AccountId>,
+			scope: SpaceId,
+		) -> Vec<pallet_moderation::ModerationRecord<AccountId, BlockNumber, Moment>> {
+			Moderation::get_moderation_history(entity, scope)
+		}
+	}
+
+	impl pallet_spaces_rpc_runtime_api::SpacesApi<Block, AccountId, BlockNumber> for Runtime {
+		fn get_spaces_by_ids(space_ids: Vec<SpaceId>) -> Vec<FlatSpace<AccountId, BlockNumber>> {
+			Spaces::get_spaces_by_ids(space_ids)
+		}
+
+		fn get_spaces(start_id: SpaceId, limit: u64) -> Vec<FlatSpace<AccountId, BlockNumber>> {
+			Spaces::get_spaces(start_id, limit)
+		}
+
+		fn get_public_spaces(start_id: SpaceId, limit: u64) -> Vec<FlatSpace<AccountId, BlockNumber>> {
+			Spaces::get_public_spaces(start_id, limit)
+		}
+
+		fn get_unlisted_spaces(start_id: SpaceId, limit: u64) -> Vec<FlatSpace<AccountId, BlockNumber>> {
+			Spaces::get_unlisted_spaces(start_id, limit)
+		}
+
+		fn get_space_ids_by_owner(owner: AccountId) -> Vec<SpaceId> {
+			Spaces::get_space_ids_by_owner(owner)
+		}
+
+		fn get_space_id_by_handle(handle: Vec<u8>) -> Option<SpaceId> {
+			Spaces::get_space_id_by_handle(handle)
+		}
+
+		fn get_space_settings(space_id: SpaceId) -> Vec<FlatSpaceSetting> {
+			Spaces::get_space_settings(space_id)
+		}
 	}
 
 	#[cfg(feature = "try-runtime")]
@@ -1,3 +1,13 @@
 #!/usr/bin/env bash
 
+set -e
+
+# Placeholder weights are not benchmark output and must never be shipped in a release runtime.
+PLACEHOLDER_WEIGHTS=$(grep -l "PLACEHOLDER weights" pallets/*/src/weights.rs || true)
+if [[ -n $PLACEHOLDER_WEIGHTS ]]; then
+  echo "Regenerate the placeholder weights with ./scripts/run-benchmark-on.sh first:"
+  echo "$PLACEHOLDER_WEIGHTS"
+  exit 1
+fi
+
 cargo build --release -p subsocial-parachain-runtime